@@ -0,0 +1,224 @@
+// #[derive(Vector)] for user structs with named f32 fields, e.g. struct Rgb { r: f32, g: f32, b: f32 }
+// Parses the struct by hand instead of pulling in syn/quote to keep compile times down
+// The derived code refers to gdmx by its absolute path (::gdmx)
+
+use proc_macro::{
+    Delimiter,
+    TokenStream,
+    TokenTree,
+};
+
+
+/// Implements `gdmx::VecExt<N>`, the arithmetic operators, and array conversions for a struct
+/// whose fields are all f32
+///
+/// Requires: the struct must be `#[repr(C)]` or `#[repr(transparent)]` and derive `Clone, Copy, Default, PartialEq`
+/// `packed` and `align` are rejected, they break the cast to [f32; N]
+#[proc_macro_derive(Vector)]
+pub fn derive_vector(input: TokenStream) -> TokenStream {
+    match parse_struct(input) {
+        Ok(s) => expand(&s).parse().unwrap(),
+        Err(msg) => format!("compile_error!({msg:?});").parse().unwrap(),
+    }
+}
+
+
+struct VectorStruct {
+    name: String,
+    fields: Vec<String>,
+}
+
+fn parse_struct(input: TokenStream) -> Result<VectorStruct, String> {
+    let mut tokens = input.into_iter().peekable();
+    let mut is_repr_c = false;
+    let mut name = None;
+
+    while let Some(tt) = tokens.next() {
+        match tt {
+            // outer attributes, only #[repr(...)] matters
+            TokenTree::Punct(p) if p.as_char() == '#' => {
+                if let Some(TokenTree::Group(g)) = tokens.next()
+                    && parse_repr(g.stream())?
+                {
+                    is_repr_c = true;
+                }
+            }
+            TokenTree::Ident(i) if i.to_string() == "struct" => {
+                match tokens.next() {
+                    Some(TokenTree::Ident(n)) => name = Some(n.to_string()),
+                    _ => return Err("expected a struct name".into()),
+                }
+            }
+            TokenTree::Ident(i) if i.to_string() == "enum" || i.to_string() == "union" => {
+                return Err("#[derive(Vector)] only supports structs".into());
+            }
+            TokenTree::Punct(p) if p.as_char() == '<' && name.is_some() => {
+                return Err("#[derive(Vector)] does not support generic structs".into());
+            }
+            TokenTree::Group(g) if name.is_some() => {
+                if g.delimiter() != Delimiter::Brace {
+                    return Err("#[derive(Vector)] requires a struct with named fields".into());
+                }
+                if !is_repr_c {
+                    return Err("#[derive(Vector)] requires #[repr(C)] or #[repr(transparent)]".into());
+                }
+                let fields = parse_fields(g.stream())?;
+                if fields.is_empty() {
+                    return Err("#[derive(Vector)] requires at least one field".into());
+                }
+                return Ok(VectorStruct { name: name.unwrap(), fields });
+            }
+            _ => {}
+        }
+    }
+    Err("#[derive(Vector)] requires a struct with named fields".into())
+}
+
+// Whether an attribute body is a repr with C or transparent layout
+// packed and align change the field offsets, which the AsRef/AsMut casts rely on
+fn parse_repr(attr: TokenStream) -> Result<bool, String> {
+    let mut tokens = attr.into_iter();
+    match tokens.next() {
+        Some(TokenTree::Ident(i)) if i.to_string() == "repr" => {}
+        _ => return Ok(false),
+    }
+    let Some(TokenTree::Group(hints)) = tokens.next() else {
+        return Ok(false);
+    };
+    let mut is_repr_c = false;
+    for tt in hints.stream() {
+        if let TokenTree::Ident(i) = tt {
+            match i.to_string().as_str() {
+                "C" | "transparent" => is_repr_c = true,
+                hint @ ("packed" | "align") => {
+                    return Err(format!("#[derive(Vector)] does not support #[repr({hint})]"));
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(is_repr_c)
+}
+
+fn parse_fields(body: TokenStream) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut tokens = body.into_iter().peekable();
+
+    while tokens.peek().is_some() {
+        // skip attributes and visibility up to the field name
+        let field = loop {
+            match tokens.next() {
+                Some(TokenTree::Punct(p)) if p.as_char() == '#' => {
+                    tokens.next();
+                }
+                Some(TokenTree::Ident(i)) if i.to_string() == "pub" => {
+                    if let Some(TokenTree::Group(g)) = tokens.peek()
+                        && g.delimiter() == Delimiter::Parenthesis
+                    {
+                        tokens.next();
+                    }
+                }
+                Some(TokenTree::Ident(i)) => break i.to_string(),
+                _ => return Err("expected a field name".into()),
+            }
+        };
+        match tokens.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == ':' => {}
+            _ => return Err(format!("expected `:` after field `{field}`")),
+        }
+        let mut ty = String::new();
+        for tt in tokens.by_ref() {
+            if let TokenTree::Punct(p) = &tt
+                && p.as_char() == ','
+            {
+                break;
+            }
+            ty.push_str(&tt.to_string());
+        }
+        if ty != "f32" {
+            return Err(format!("field `{field}` must be f32 for #[derive(Vector)], found `{ty}`"));
+        }
+        fields.push(field);
+    }
+    Ok(fields)
+}
+
+
+fn expand(s: &VectorStruct) -> String {
+    let name = &s.name;
+    let n = s.fields.len();
+    // builds `name { f0: <expr>, f1: <expr>, ... }` with {f} substituted per field
+    let build = |expr: &str| {
+        let inits: Vec<String> = s.fields
+            .iter()
+            .map(|f| format!("{f}: {}", expr.replace("{f}", f)))
+            .collect();
+        format!("{name} {{ {} }}", inits.join(", "))
+    };
+    let stmts = |stmt: &str| {
+        s.fields
+            .iter()
+            .map(|f| stmt.replace("{f}", f))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let from_arr: Vec<String> = s.fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| format!("{f}: arr[{i}]"))
+        .collect();
+    let to_arr: Vec<String> = s.fields.iter().map(|f| format!("v.{f}")).collect();
+
+    let mut out = String::new();
+    out += &format!("impl ::gdmx::VecExt<{n}> for {name} {{}}\n");
+
+    // vec op vec
+    for (tr, method, op) in [("Add", "add", "+"), ("Sub", "sub", "-"), ("Mul", "mul", "*"), ("Div", "div", "/")] {
+        out += &format!(
+            "impl ::core::ops::{tr} for {name} {{ type Output = {name}; #[inline] fn {method}(self, rhs: {name}) -> {name} {{ {} }} }}\n",
+            build(&format!("self.{{f}} {op} rhs.{{f}}")),
+        );
+        out += &format!(
+            "impl ::core::ops::{tr}Assign for {name} {{ #[inline] fn {method}_assign(&mut self, rhs: {name}) {{ {} }} }}\n",
+            stmts(&format!("self.{{f}} {op}= rhs.{{f}};")),
+        );
+    }
+    // vec op f32
+    for (tr, method, op) in [("Mul", "mul", "*"), ("Div", "div", "/")] {
+        out += &format!(
+            "impl ::core::ops::{tr}<f32> for {name} {{ type Output = {name}; #[inline] fn {method}(self, rhs: f32) -> {name} {{ {} }} }}\n",
+            build(&format!("self.{{f}} {op} rhs")),
+        );
+        out += &format!(
+            "impl ::core::ops::{tr}Assign<f32> for {name} {{ #[inline] fn {method}_assign(&mut self, rhs: f32) {{ {} }} }}\n",
+            stmts(&format!("self.{{f}} {op}= rhs;")),
+        );
+    }
+    // f32 * vec
+    out += &format!(
+        "impl ::core::ops::Mul<{name}> for f32 {{ type Output = {name}; #[inline] fn mul(self, rhs: {name}) -> {name} {{ {} }} }}\n",
+        build("self * rhs.{f}"),
+    );
+    out += &format!(
+        "impl ::core::ops::Neg for {name} {{ type Output = {name}; #[inline] fn neg(self) -> {name} {{ {} }} }}\n",
+        build("-self.{f}"),
+    );
+
+    // conversions
+    out += &format!(
+        "impl ::core::convert::From<[f32; {n}]> for {name} {{ #[inline] fn from(arr: [f32; {n}]) -> {name} {{ {name} {{ {} }} }} }}\n",
+        from_arr.join(", "),
+    );
+    out += &format!(
+        "impl ::core::convert::From<{name}> for [f32; {n}] {{ #[inline] fn from(v: {name}) -> [f32; {n}] {{ [{}] }} }}\n",
+        to_arr.join(", "),
+    );
+    // repr(C) or transparent with only f32 fields has the same layout as [f32; N]
+    out += &format!(
+        "impl ::core::convert::AsRef<[f32; {n}]> for {name} {{ #[inline] fn as_ref(&self) -> &[f32; {n}] {{ unsafe {{ &*(self as *const {name} as *const [f32; {n}]) }} }} }}\n",
+    );
+    out += &format!(
+        "impl ::core::convert::AsMut<[f32; {n}]> for {name} {{ #[inline] fn as_mut(&mut self) -> &mut [f32; {n}] {{ unsafe {{ &mut *(self as *mut {name} as *mut [f32; {n}]) }} }} }}\n",
+    );
+    out
+}
@@ -0,0 +1,11 @@
+use gdmx::Vector;
+
+#[derive(Vector, Clone, Copy, Default, PartialEq)]
+#[repr(C, packed)]
+struct Rgb {
+    r: f32,
+    g: f32,
+    b: f32,
+}
+
+fn main() {}
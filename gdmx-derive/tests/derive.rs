@@ -0,0 +1,27 @@
+use gdmx::{
+    Vector,
+    VecExt,
+};
+
+#[derive(Vector, Clone, Copy, Default, PartialEq, Debug)]
+#[repr(C)]
+struct Rgb {
+    r: f32,
+    g: f32,
+    b: f32,
+}
+
+#[test]
+fn derived_struct_gets_vector_math() {
+    let red = Rgb { r: 1.0, g: 0.0, b: 0.0 };
+    let blue = Rgb::from([0.0, 0.0, 1.0]);
+    let purple = red.lerp(blue, 0.5);
+    assert_eq!(purple, Rgb { r: 0.5, g: 0.0, b: 0.5 });
+    assert_eq!((purple * 2.0).sum(), 2.0);
+    assert_eq!(purple.to_array(), [0.5, 0.0, 0.5]);
+
+    let mut c = Rgb::default();
+    c.as_mut()[1] = 0.25;
+    c += red;
+    assert_eq!(c, Rgb { r: 1.0, g: 0.25, b: 0.0 });
+}
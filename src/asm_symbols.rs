@@ -38,27 +38,23 @@ pub extern "C" fn vec3_min_vec_manual(a: Vec3, b: Vec3) -> Vec3 {
 }
 
 #[unsafe(no_mangle)]
-
 pub extern "C" fn vec3_min_vec_trait(a: Vec3, b: Vec3) -> Vec3 {
     black_box(a.min_vec(b))
 }
 
 
 #[unsafe(no_mangle)]
-
 pub extern "C" fn vec3_max_manual(a: Vec3, b: f32) -> Vec3 {
     black_box(Vec3::new(a.x.max(b), a.y.max(b), a.z.max(b)))
 }
 
 #[unsafe(no_mangle)]
-
 pub extern "C" fn vec3_max_trait(a: Vec3, b: f32) -> Vec3 {
     black_box(a.max(b))
 }
 
 
 #[unsafe(no_mangle)]
-
 pub extern "C" fn vec3_max_vec_manual(a: Vec3, b: Vec3) -> Vec3 {
     black_box(Vec3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)))
 }
@@ -179,3 +175,29 @@ pub extern "C" fn vec3_div_euclid_vec_manual(a: Vec3, b: Vec3) -> Vec3 {
 pub extern "C" fn vec3_div_euclid_vec_trait(a: Vec3, b: Vec3) -> Vec3 {
     black_box(a.div_euclid_vec(b))
 }
+
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vec3_map_manual(v: Vec3) -> Vec3 {
+    black_box(Vec3::new(v.x * v.x + 1.0, v.y * v.y + 1.0, v.z * v.z + 1.0))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vec3_map_trait(v: Vec3) -> Vec3 {
+    black_box(v.map(|c| c * c + 1.0))
+}
+
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vec3_zip_with_manual(a: Vec3, b: Vec3) -> Vec3 {
+    black_box(Vec3::new(
+        a.x * 2.0 - b.x,
+        a.y * 2.0 - b.y,
+        a.z * 2.0 - b.z,
+    ))
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn vec3_zip_with_trait(a: Vec3, b: Vec3) -> Vec3 {
+    black_box(a.zip_with(b, |x, y| x * 2.0 - y))
+}
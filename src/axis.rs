@@ -0,0 +1,41 @@
+/// A coordinate axis, usable to index into vectors instead of magic 0/1/2/3 indices
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Axis {
+    X = 0,
+    Y = 1,
+    Z = 2,
+    W = 3,
+}
+
+impl Axis {
+    /// The axes of 2-space
+    pub const XY: [Axis; 2] = [Axis::X, Axis::Y];
+
+    /// The axes of 3-space
+    pub const XYZ: [Axis; 3] = [Axis::X, Axis::Y, Axis::Z];
+
+    /// The axes of 4-space
+    pub const XYZW: [Axis; 4] = [Axis::X, Axis::Y, Axis::Z, Axis::W];
+
+
+    /// The component index of this axis
+    #[inline]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the axis for a component index
+    /// Returns None for i > 3
+    #[inline]
+    pub const fn from_index(i: usize) -> Option<Axis> {
+        match i {
+            0 => Some(Axis::X),
+            1 => Some(Axis::Y),
+            2 => Some(Axis::Z),
+            3 => Some(Axis::W),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+// Drag-free projectile math
+// Gravity is passed as an acceleration vector, e.g. <0 -9.81 0>, so any up axis works
+
+use crate::{
+    Vec3,
+    VecExt,
+};
+
+
+/// Position at time t of a projectile launched from `from` with `velocity`
+#[inline]
+pub fn position_at(from: Vec3, velocity: Vec3, gravity: Vec3, t: f32) -> Vec3 {
+    from + velocity * t + gravity * (0.5 * t * t)
+}
+
+/// Samples the trajectory every dt starting at t = 0
+/// The iterator never ends, use take or take_while to bound it
+#[inline]
+pub fn trajectory_points(from: Vec3, velocity: Vec3, gravity: Vec3, dt: f32) -> impl Iterator<Item = Vec3> {
+    (0u32..).map(move |i| position_at(from, velocity, gravity, i as f32 * dt))
+}
+
+/// Time until the projectile comes back down to its launch height
+/// Returns 0 if it is launched downwards
+/// Requires: gravity should be non-zero
+#[inline]
+pub fn time_of_flight(velocity: Vec3, gravity: Vec3) -> f32 {
+    2.0 * time_to_apex(velocity, gravity)
+}
+
+/// Time until the projectile reaches its highest point, 0 if it is launched downwards
+/// Requires: gravity should be non-zero
+#[inline]
+pub fn time_to_apex(velocity: Vec3, gravity: Vec3) -> f32 {
+    (-velocity.dot(gravity) / gravity.length_2()).max(0.0)
+}
+
+/// The highest point of the trajectory
+/// Requires: gravity should be non-zero
+#[inline]
+pub fn apex(from: Vec3, velocity: Vec3, gravity: Vec3) -> Vec3 {
+    position_at(from, velocity, gravity, time_to_apex(velocity, gravity))
+}
+
+/// Launch velocities with the given speed that hit `to`, as (low arc, high arc)
+/// The low arc arrives sooner, the high arc clears more obstacles, they are equal at maximum range
+/// Returns None if the target is out of range
+/// Requires: gravity should be non-zero and speed should be positive
+pub fn launch_velocity(from: Vec3, to: Vec3, speed: f32, gravity: Vec3) -> Option<(Vec3, Vec3)> {
+    let g = gravity.length();
+    let up = gravity / -g;
+    let delta = to - from;
+    let dy = delta.dot(up);
+    let horizontal = delta - up * dy;
+    let dx = horizontal.length();
+    let s_2 = speed * speed;
+
+    let disc = s_2 * s_2 - g * (g * dx * dx + 2.0 * dy * s_2);
+    if disc < 0.0 {
+        return None;
+    }
+    if dx <= f32::EPSILON * delta.length() {
+        // straight up or down, the high arc goes up and falls back onto the target
+        let low = if dy < 0.0 { -up * speed } else { up * speed };
+        return Some((low, up * speed));
+    }
+
+    let forward = horizontal / dx;
+    let velocity = |tan: f32| {
+        let cos = (1.0 + tan * tan).sqrt().recip();
+        (forward + up * tan) * (speed * cos)
+    };
+    let root = disc.sqrt();
+    Some((velocity((s_2 - root) / (g * dx)), velocity((s_2 + root) / (g * dx))))
+}
+
+/// Velocity with the given speed that intercepts a target moving at constant velocity,
+/// ignoring gravity, along with the time of impact
+/// Returns None if the target can not be reached
+#[inline]
+pub fn intercept(from: Vec3, speed: f32, target_pos: Vec3, target_vel: Vec3) -> Option<(Vec3, f32)> {
+    // |d + target_vel * t| = speed * t is a quadratic in t
+    let d = target_pos - from;
+    let a = target_vel.length_2() - speed * speed;
+    let b = 2.0 * d.dot(target_vel);
+    let c = d.length_2();
+
+    let t = if a.abs() < f32::EPSILON {
+        // equal speeds, the quadratic degenerates to b * t + c = 0
+        (b < 0.0).then(|| -c / b)?
+    } else {
+        let disc = b * b - 4.0 * a * c;
+        if disc < 0.0 {
+            return None;
+        }
+        let root = disc.sqrt();
+        let (t0, t1) = ((-b - root) / (2.0 * a), (-b + root) / (2.0 * a));
+        let (t0, t1) = (t0.min(t1), t0.max(t1));
+        if t0 > 0.0 {
+            t0
+        } else if t1 > 0.0 {
+            t1
+        } else {
+            return None;
+        }
+    };
+    Some(((d + target_vel * t) / t, t))
+}
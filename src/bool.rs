@@ -0,0 +1,8 @@
+pub mod bvec2;
+pub use bvec2::*;
+
+pub mod bvec3;
+pub use bvec3::*;
+
+pub mod bvec4;
+pub use bvec4::*;
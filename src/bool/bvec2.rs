@@ -0,0 +1,286 @@
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+    ops::{
+        BitAnd,
+        BitAndAssign,
+        BitOr,
+        BitOrAssign,
+        BitXor,
+        BitXorAssign,
+        Not,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// A mask of 2 bools, one per component of a Vec2
+/// Made by the componentwise comparisons and consumed by select
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct BVec2 {
+    pub x: bool,
+    pub y: bool,
+}
+
+impl BVec2 {
+    /// The default BVec2 with every component false
+    pub const FALSE: BVec2 = BVec2::splat(false);
+
+    /// Every component true
+    pub const TRUE: BVec2 = BVec2::splat(true);
+
+
+    /// Standard constructor for <x y>
+    #[inline]
+    pub const fn new(x: bool, y: bool) -> BVec2 {
+        BVec2 { x, y }
+    }
+
+    #[inline]
+    pub const fn splat(v: bool) -> BVec2 {
+        BVec2::new(v, v)
+    }
+
+
+    /// Checks if any component is true
+    #[inline]
+    pub const fn any(self) -> bool {
+        self.x || self.y
+    }
+
+    /// Checks if every component is true
+    #[inline]
+    pub const fn all(self) -> bool {
+        self.x && self.y
+    }
+
+    /// Packs the mask into the low bits of a u32, x in bit 0
+    #[inline]
+    pub const fn bitmask(self) -> u32 {
+        self.x as u32 | (self.y as u32) << 1
+    }
+}
+
+
+impl Debug for BVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BVec2")
+            .field(&self.x)
+            .field(&self.y)
+            .finish()
+    }
+}
+impl Display for BVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .finish()
+    }
+}
+
+
+// BVec2 & BVec2
+impl BitAnd<BVec2> for BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitand(self, rhs: BVec2) -> Self::Output {
+        BVec2::new(
+            self.x & rhs.x,
+            self.y & rhs.y,
+        )
+    }
+}
+impl BitAnd<&BVec2> for BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitand(self, rhs: &BVec2) -> Self::Output {
+        self & *rhs
+    }
+}
+impl BitAnd<BVec2> for &BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitand(self, rhs: BVec2) -> Self::Output {
+        *self & rhs
+    }
+}
+impl BitAnd<&BVec2> for &BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitand(self, rhs: &BVec2) -> Self::Output {
+        *self & *rhs
+    }
+}
+
+// BVec2 &= BVec2
+impl BitAndAssign<BVec2> for BVec2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: BVec2) {
+        *self = *self & rhs;
+    }
+}
+impl BitAndAssign<&BVec2> for BVec2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &BVec2) {
+        *self &= *rhs;
+    }
+}
+
+// BVec2 | BVec2
+impl BitOr<BVec2> for BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitor(self, rhs: BVec2) -> Self::Output {
+        BVec2::new(
+            self.x | rhs.x,
+            self.y | rhs.y,
+        )
+    }
+}
+impl BitOr<&BVec2> for BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitor(self, rhs: &BVec2) -> Self::Output {
+        self | *rhs
+    }
+}
+impl BitOr<BVec2> for &BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitor(self, rhs: BVec2) -> Self::Output {
+        *self | rhs
+    }
+}
+impl BitOr<&BVec2> for &BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitor(self, rhs: &BVec2) -> Self::Output {
+        *self | *rhs
+    }
+}
+
+// BVec2 |= BVec2
+impl BitOrAssign<BVec2> for BVec2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: BVec2) {
+        *self = *self | rhs;
+    }
+}
+impl BitOrAssign<&BVec2> for BVec2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &BVec2) {
+        *self |= *rhs;
+    }
+}
+
+// BVec2 ^ BVec2
+impl BitXor<BVec2> for BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitxor(self, rhs: BVec2) -> Self::Output {
+        BVec2::new(
+            self.x ^ rhs.x,
+            self.y ^ rhs.y,
+        )
+    }
+}
+impl BitXor<&BVec2> for BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitxor(self, rhs: &BVec2) -> Self::Output {
+        self ^ *rhs
+    }
+}
+impl BitXor<BVec2> for &BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitxor(self, rhs: BVec2) -> Self::Output {
+        *self ^ rhs
+    }
+}
+impl BitXor<&BVec2> for &BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn bitxor(self, rhs: &BVec2) -> Self::Output {
+        *self ^ *rhs
+    }
+}
+
+// BVec2 ^= BVec2
+impl BitXorAssign<BVec2> for BVec2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: BVec2) {
+        *self = *self ^ rhs;
+    }
+}
+impl BitXorAssign<&BVec2> for BVec2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: &BVec2) {
+        *self ^= *rhs;
+    }
+}
+
+// !BVec2
+impl Not for BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn not(self) -> Self::Output {
+        BVec2::new(
+            !self.x,
+            !self.y,
+        )
+    }
+}
+impl Not for &BVec2 {
+    type Output = BVec2;
+    #[inline]
+    fn not(self) -> Self::Output {
+        !*self
+    }
+}
+
+
+// BVec2[]
+impl Index<usize> for BVec2 {
+    type Output = bool;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Cannot index into a BVec2 at i > 1"),
+        }
+    }
+}
+impl IndexMut<usize> for BVec2 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Cannot index into a BVec2 at i > 1"),
+        }
+    }
+}
+
+
+impl From<[bool; 2]> for BVec2 {
+    #[inline]
+    fn from(arr: [bool; 2]) -> BVec2 {
+        BVec2::new(arr[0], arr[1])
+    }
+}
+
+impl From<BVec2> for [bool; 2] {
+    #[inline]
+    fn from(v: BVec2) -> [bool; 2] {
+        [v.x, v.y]
+    }
+}
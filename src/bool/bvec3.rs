@@ -0,0 +1,295 @@
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+    ops::{
+        BitAnd,
+        BitAndAssign,
+        BitOr,
+        BitOrAssign,
+        BitXor,
+        BitXorAssign,
+        Not,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// A mask of 3 bools, one per component of a Vec3
+/// Made by the componentwise comparisons and consumed by select
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct BVec3 {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+}
+
+impl BVec3 {
+    /// The default BVec3 with every component false
+    pub const FALSE: BVec3 = BVec3::splat(false);
+
+    /// Every component true
+    pub const TRUE: BVec3 = BVec3::splat(true);
+
+
+    /// Standard constructor for <x y z>
+    #[inline]
+    pub const fn new(x: bool, y: bool, z: bool) -> BVec3 {
+        BVec3 { x, y, z }
+    }
+
+    #[inline]
+    pub const fn splat(v: bool) -> BVec3 {
+        BVec3::new(v, v, v)
+    }
+
+
+    /// Checks if any component is true
+    #[inline]
+    pub const fn any(self) -> bool {
+        self.x || self.y || self.z
+    }
+
+    /// Checks if every component is true
+    #[inline]
+    pub const fn all(self) -> bool {
+        self.x && self.y && self.z
+    }
+
+    /// Packs the mask into the low bits of a u32, x in bit 0
+    #[inline]
+    pub const fn bitmask(self) -> u32 {
+        self.x as u32 | (self.y as u32) << 1 | (self.z as u32) << 2
+    }
+}
+
+
+impl Debug for BVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BVec3")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .finish()
+    }
+}
+impl Display for BVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .entry(&self.z)
+            .finish()
+    }
+}
+
+
+// BVec3 & BVec3
+impl BitAnd<BVec3> for BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitand(self, rhs: BVec3) -> Self::Output {
+        BVec3::new(
+            self.x & rhs.x,
+            self.y & rhs.y,
+            self.z & rhs.z,
+        )
+    }
+}
+impl BitAnd<&BVec3> for BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitand(self, rhs: &BVec3) -> Self::Output {
+        self & *rhs
+    }
+}
+impl BitAnd<BVec3> for &BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitand(self, rhs: BVec3) -> Self::Output {
+        *self & rhs
+    }
+}
+impl BitAnd<&BVec3> for &BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitand(self, rhs: &BVec3) -> Self::Output {
+        *self & *rhs
+    }
+}
+
+// BVec3 &= BVec3
+impl BitAndAssign<BVec3> for BVec3 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: BVec3) {
+        *self = *self & rhs;
+    }
+}
+impl BitAndAssign<&BVec3> for BVec3 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &BVec3) {
+        *self &= *rhs;
+    }
+}
+
+// BVec3 | BVec3
+impl BitOr<BVec3> for BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitor(self, rhs: BVec3) -> Self::Output {
+        BVec3::new(
+            self.x | rhs.x,
+            self.y | rhs.y,
+            self.z | rhs.z,
+        )
+    }
+}
+impl BitOr<&BVec3> for BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitor(self, rhs: &BVec3) -> Self::Output {
+        self | *rhs
+    }
+}
+impl BitOr<BVec3> for &BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitor(self, rhs: BVec3) -> Self::Output {
+        *self | rhs
+    }
+}
+impl BitOr<&BVec3> for &BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitor(self, rhs: &BVec3) -> Self::Output {
+        *self | *rhs
+    }
+}
+
+// BVec3 |= BVec3
+impl BitOrAssign<BVec3> for BVec3 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: BVec3) {
+        *self = *self | rhs;
+    }
+}
+impl BitOrAssign<&BVec3> for BVec3 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &BVec3) {
+        *self |= *rhs;
+    }
+}
+
+// BVec3 ^ BVec3
+impl BitXor<BVec3> for BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitxor(self, rhs: BVec3) -> Self::Output {
+        BVec3::new(
+            self.x ^ rhs.x,
+            self.y ^ rhs.y,
+            self.z ^ rhs.z,
+        )
+    }
+}
+impl BitXor<&BVec3> for BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitxor(self, rhs: &BVec3) -> Self::Output {
+        self ^ *rhs
+    }
+}
+impl BitXor<BVec3> for &BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitxor(self, rhs: BVec3) -> Self::Output {
+        *self ^ rhs
+    }
+}
+impl BitXor<&BVec3> for &BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn bitxor(self, rhs: &BVec3) -> Self::Output {
+        *self ^ *rhs
+    }
+}
+
+// BVec3 ^= BVec3
+impl BitXorAssign<BVec3> for BVec3 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: BVec3) {
+        *self = *self ^ rhs;
+    }
+}
+impl BitXorAssign<&BVec3> for BVec3 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: &BVec3) {
+        *self ^= *rhs;
+    }
+}
+
+// !BVec3
+impl Not for BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn not(self) -> Self::Output {
+        BVec3::new(
+            !self.x,
+            !self.y,
+            !self.z,
+        )
+    }
+}
+impl Not for &BVec3 {
+    type Output = BVec3;
+    #[inline]
+    fn not(self) -> Self::Output {
+        !*self
+    }
+}
+
+
+// BVec3[]
+impl Index<usize> for BVec3 {
+    type Output = bool;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Cannot index into a BVec3 at i > 2"),
+        }
+    }
+}
+impl IndexMut<usize> for BVec3 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Cannot index into a BVec3 at i > 2"),
+        }
+    }
+}
+
+
+impl From<[bool; 3]> for BVec3 {
+    #[inline]
+    fn from(arr: [bool; 3]) -> BVec3 {
+        BVec3::new(arr[0], arr[1], arr[2])
+    }
+}
+
+impl From<BVec3> for [bool; 3] {
+    #[inline]
+    fn from(v: BVec3) -> [bool; 3] {
+        [v.x, v.y, v.z]
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+    ops::{
+        BitAnd,
+        BitAndAssign,
+        BitOr,
+        BitOrAssign,
+        BitXor,
+        BitXorAssign,
+        Not,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// A mask of 4 bools, one per component of a Vec4
+/// Made by the componentwise comparisons and consumed by select
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct BVec4 {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+    pub w: bool,
+}
+
+impl BVec4 {
+    /// The default BVec4 with every component false
+    pub const FALSE: BVec4 = BVec4::splat(false);
+
+    /// Every component true
+    pub const TRUE: BVec4 = BVec4::splat(true);
+
+
+    /// Standard constructor for <x y z w>
+    #[inline]
+    pub const fn new(x: bool, y: bool, z: bool, w: bool) -> BVec4 {
+        BVec4 { x, y, z, w }
+    }
+
+    #[inline]
+    pub const fn splat(v: bool) -> BVec4 {
+        BVec4::new(v, v, v, v)
+    }
+
+
+    /// Checks if any component is true
+    #[inline]
+    pub const fn any(self) -> bool {
+        self.x || self.y || self.z || self.w
+    }
+
+    /// Checks if every component is true
+    #[inline]
+    pub const fn all(self) -> bool {
+        self.x && self.y && self.z && self.w
+    }
+
+    /// Packs the mask into the low bits of a u32, x in bit 0
+    #[inline]
+    pub const fn bitmask(self) -> u32 {
+        self.x as u32 | (self.y as u32) << 1 | (self.z as u32) << 2 | (self.w as u32) << 3
+    }
+}
+
+
+impl Debug for BVec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BVec4")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .field(&self.w)
+            .finish()
+    }
+}
+impl Display for BVec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .entry(&self.z)
+            .entry(&self.w)
+            .finish()
+    }
+}
+
+
+// BVec4 & BVec4
+impl BitAnd<BVec4> for BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitand(self, rhs: BVec4) -> Self::Output {
+        BVec4::new(
+            self.x & rhs.x,
+            self.y & rhs.y,
+            self.z & rhs.z,
+            self.w & rhs.w,
+        )
+    }
+}
+impl BitAnd<&BVec4> for BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitand(self, rhs: &BVec4) -> Self::Output {
+        self & *rhs
+    }
+}
+impl BitAnd<BVec4> for &BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitand(self, rhs: BVec4) -> Self::Output {
+        *self & rhs
+    }
+}
+impl BitAnd<&BVec4> for &BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitand(self, rhs: &BVec4) -> Self::Output {
+        *self & *rhs
+    }
+}
+
+// BVec4 &= BVec4
+impl BitAndAssign<BVec4> for BVec4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: BVec4) {
+        *self = *self & rhs;
+    }
+}
+impl BitAndAssign<&BVec4> for BVec4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: &BVec4) {
+        *self &= *rhs;
+    }
+}
+
+// BVec4 | BVec4
+impl BitOr<BVec4> for BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitor(self, rhs: BVec4) -> Self::Output {
+        BVec4::new(
+            self.x | rhs.x,
+            self.y | rhs.y,
+            self.z | rhs.z,
+            self.w | rhs.w,
+        )
+    }
+}
+impl BitOr<&BVec4> for BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitor(self, rhs: &BVec4) -> Self::Output {
+        self | *rhs
+    }
+}
+impl BitOr<BVec4> for &BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitor(self, rhs: BVec4) -> Self::Output {
+        *self | rhs
+    }
+}
+impl BitOr<&BVec4> for &BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitor(self, rhs: &BVec4) -> Self::Output {
+        *self | *rhs
+    }
+}
+
+// BVec4 |= BVec4
+impl BitOrAssign<BVec4> for BVec4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: BVec4) {
+        *self = *self | rhs;
+    }
+}
+impl BitOrAssign<&BVec4> for BVec4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: &BVec4) {
+        *self |= *rhs;
+    }
+}
+
+// BVec4 ^ BVec4
+impl BitXor<BVec4> for BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitxor(self, rhs: BVec4) -> Self::Output {
+        BVec4::new(
+            self.x ^ rhs.x,
+            self.y ^ rhs.y,
+            self.z ^ rhs.z,
+            self.w ^ rhs.w,
+        )
+    }
+}
+impl BitXor<&BVec4> for BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitxor(self, rhs: &BVec4) -> Self::Output {
+        self ^ *rhs
+    }
+}
+impl BitXor<BVec4> for &BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitxor(self, rhs: BVec4) -> Self::Output {
+        *self ^ rhs
+    }
+}
+impl BitXor<&BVec4> for &BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn bitxor(self, rhs: &BVec4) -> Self::Output {
+        *self ^ *rhs
+    }
+}
+
+// BVec4 ^= BVec4
+impl BitXorAssign<BVec4> for BVec4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: BVec4) {
+        *self = *self ^ rhs;
+    }
+}
+impl BitXorAssign<&BVec4> for BVec4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: &BVec4) {
+        *self ^= *rhs;
+    }
+}
+
+// !BVec4
+impl Not for BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn not(self) -> Self::Output {
+        BVec4::new(
+            !self.x,
+            !self.y,
+            !self.z,
+            !self.w,
+        )
+    }
+}
+impl Not for &BVec4 {
+    type Output = BVec4;
+    #[inline]
+    fn not(self) -> Self::Output {
+        !*self
+    }
+}
+
+
+// BVec4[]
+impl Index<usize> for BVec4 {
+    type Output = bool;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("Cannot index into a BVec4 at i > 3"),
+        }
+    }
+}
+impl IndexMut<usize> for BVec4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("Cannot index into a BVec4 at i > 3"),
+        }
+    }
+}
+
+
+impl From<[bool; 4]> for BVec4 {
+    #[inline]
+    fn from(arr: [bool; 4]) -> BVec4 {
+        BVec4::new(arr[0], arr[1], arr[2], arr[3])
+    }
+}
+
+impl From<BVec4> for [bool; 4] {
+    #[inline]
+    fn from(v: BVec4) -> [bool; 4] {
+        [v.x, v.y, v.z, v.w]
+    }
+}
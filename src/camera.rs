@@ -0,0 +1,160 @@
+// Camera controllers for a left-handed y-up world, matching Vec3::forward_from_yaw
+// Yaw 0 looks along +z and increases towards +x, positive pitch looks up
+
+use crate::{
+    Dir3,
+    Mat3,
+    Mat4,
+    Vec2,
+    Vec3,
+    VecExt,
+};
+use std::f32::consts::FRAC_PI_2;
+
+
+/// How close pitch may get to straight up or down, keeps the view basis from degenerating
+const PITCH_LIMIT: f32 = FRAC_PI_2 - 1e-3;
+
+/// Unit vector looking along yaw and pitch
+#[inline]
+fn forward_from_yaw_pitch(yaw: f32, pitch: f32) -> Vec3 {
+    let (sin_pitch, cos_pitch) = pitch.sin_cos();
+    (Vec3::forward_from_yaw(yaw) * cos_pitch).with_y(sin_pitch)
+}
+
+
+/// A camera circling a target, as in model viewers and third person games
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct OrbitCamera {
+    pub target: Vec3,
+    pub yaw: f32,
+    /// Positive pitch places the camera below the target looking up
+    pub pitch: f32,
+    pub distance: f32,
+}
+
+impl OrbitCamera {
+    #[inline]
+    pub fn new(target: Vec3, yaw: f32, pitch: f32, distance: f32) -> OrbitCamera {
+        OrbitCamera { target, yaw, pitch: pitch.clamp(-PITCH_LIMIT, PITCH_LIMIT), distance }
+    }
+
+    /// Direction from the camera to the target
+    #[inline]
+    pub fn forward(&self) -> Vec3 {
+        forward_from_yaw_pitch(self.yaw, self.pitch)
+    }
+
+    /// Position of the camera
+    #[inline]
+    pub fn eye(&self) -> Vec3 {
+        self.target - self.forward() * self.distance
+    }
+
+    #[inline]
+    pub fn view_matrix(&self) -> Mat4 {
+        Mat4::look_to_lh(self.eye(), self.forward(), Vec3::Y)
+    }
+
+    /// Rotates around the target, keeping pitch short of straight up or down
+    #[inline]
+    pub fn orbit(&mut self, d_yaw: f32, d_pitch: f32) {
+        self.yaw += d_yaw;
+        self.pitch = (self.pitch + d_pitch).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+
+    /// Scales the distance to the target, factor < 1 zooms in
+    #[inline]
+    pub fn zoom(&mut self, factor: f32, min_distance: f32, max_distance: f32) {
+        self.distance = (self.distance * factor).clamp(min_distance, max_distance);
+    }
+
+    /// Moves the target, and with it the camera, along the camera's right and up vectors
+    #[inline]
+    pub fn pan(&mut self, delta: Vec2) {
+        let (right, up, _) = basis_from_forward(self.forward());
+        self.target += right * delta.x + up * delta.y;
+    }
+}
+
+
+/// A free flying camera
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FlyCamera {
+    pub position: Vec3,
+    pub yaw: f32,
+    /// Positive pitch looks up
+    pub pitch: f32,
+}
+
+impl FlyCamera {
+    #[inline]
+    pub fn new(position: Vec3, yaw: f32, pitch: f32) -> FlyCamera {
+        FlyCamera { position, yaw, pitch: pitch.clamp(-PITCH_LIMIT, PITCH_LIMIT) }
+    }
+
+    /// The camera's (right, up, forward) vectors
+    #[inline]
+    pub fn basis(&self) -> (Vec3, Vec3, Vec3) {
+        basis_from_forward(forward_from_yaw_pitch(self.yaw, self.pitch))
+    }
+
+    /// Turns the camera, keeping pitch short of straight up or down
+    #[inline]
+    pub fn look(&mut self, d_yaw: f32, d_pitch: f32) {
+        self.yaw += d_yaw;
+        self.pitch = (self.pitch + d_pitch).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+
+    /// Moves by a camera-local offset, x right, y up and z forward
+    #[inline]
+    pub fn move_local(&mut self, offset: Vec3) {
+        let (right, up, forward) = self.basis();
+        self.position += offset.to_world(right, up, forward);
+    }
+
+    /// Moves by a camera-local offset with forward and right flattened onto the ground,
+    /// so looking up or down does not change walking speed, y moves straight up
+    #[inline]
+    pub fn move_planar(&mut self, offset: Vec3) {
+        let (forward, right) = Vec3::forward_and_right_from_yaw(self.yaw);
+        self.position += offset.to_world(right, Vec3::Y, forward);
+    }
+
+    #[inline]
+    pub fn view_matrix(&self) -> Mat4 {
+        let (_, up, forward) = self.basis();
+        Mat4::look_to_lh(self.position, forward, up)
+    }
+}
+
+/// (right, up, forward) for a forward vector that is not vertical
+#[inline]
+fn basis_from_forward(forward: Vec3) -> (Vec3, Vec3, Vec3) {
+    let right = Vec3::Y.cross(forward).normalize();
+    (right, forward.cross(right), forward)
+}
+
+
+/// Projects a point in [-1, 1] screen space (y up) onto the arcball
+/// Points inside the unit circle land on the sphere, points outside on a hyperbolic sheet,
+/// so dragging past the edge keeps rotating smoothly
+#[inline]
+pub fn arcball_point(p: Vec2) -> Vec3 {
+    let d_2 = p.length_2();
+    // the sphere and the hyperbola z = 0.5 / d meet at d^2 = 0.5
+    let z = if d_2 <= 0.5 { (1.0 - d_2).sqrt() } else { 0.5 / d_2.sqrt() };
+    Vec3::new(p.x, p.y, z).normalize()
+}
+
+/// The rotation that drags the arcball from screen point `from` to `to`, both in [-1, 1] with y up
+/// Returns the identity if the points coincide
+#[inline]
+pub fn arcball_rotation(from: Vec2, to: Vec2) -> Mat3 {
+    let a = arcball_point(from);
+    let b = arcball_point(to);
+    match Dir3::new(a.cross(b)) {
+        Ok(axis) => Mat3::from_axis_angle(axis, a.angle_between(b)),
+        Err(_) => Mat3::IDENTITY,
+    }
+}
@@ -0,0 +1,130 @@
+// Mappings between directions and environment map texture coordinates
+// Directions are in a y-up world and need not be normalized unless stated, uvs are in [0, 1]
+
+use crate::{
+    Vec2,
+    Vec3,
+    VecExt,
+};
+use std::f32::consts::{
+    PI,
+    TAU,
+};
+
+
+/// Equirectangular (latitude-longitude) uv of a direction
+/// u wraps around the y-axis from -z through -x, +z (u = 0.5) and +x,
+/// v runs from +y at 0 to -y at 1
+/// Requires: dir should be non-zero
+#[inline]
+pub fn dir_to_equirect(dir: Vec3) -> Vec2 {
+    let (_, theta, phi) = dir.to_spherical();
+    Vec2::new(phi / TAU + 0.5, theta / PI)
+}
+
+/// Unit direction of an equirectangular uv, the inverse of dir_to_equirect
+#[inline]
+pub fn equirect_to_dir(uv: Vec2) -> Vec3 {
+    Vec3::from_spherical(1.0, uv.y * PI, (uv.x - 0.5) * TAU)
+}
+
+
+/// A face of a cubemap, in the usual GPU layer order
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(u8)]
+pub enum CubeFace {
+    PosX = 0,
+    NegX = 1,
+    PosY = 2,
+    NegY = 3,
+    PosZ = 4,
+    NegZ = 5,
+}
+
+impl CubeFace {
+    pub const ALL: [CubeFace; 6] = [
+        CubeFace::PosX,
+        CubeFace::NegX,
+        CubeFace::PosY,
+        CubeFace::NegY,
+        CubeFace::PosZ,
+        CubeFace::NegZ,
+    ];
+
+    /// The array layer of this face
+    #[inline]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The outward axis through the center of the face
+    #[inline]
+    pub const fn normal(self) -> Vec3 {
+        match self {
+            CubeFace::PosX => Vec3::X,
+            CubeFace::NegX => Vec3::NEG_X,
+            CubeFace::PosY => Vec3::Y,
+            CubeFace::NegY => Vec3::NEG_Y,
+            CubeFace::PosZ => Vec3::Z,
+            CubeFace::NegZ => Vec3::NEG_Z,
+        }
+    }
+
+    /// The directions that u and v increase along on this face
+    #[inline]
+    const fn uv_axes(self) -> (Vec3, Vec3) {
+        match self {
+            CubeFace::PosX => (Vec3::NEG_Z, Vec3::NEG_Y),
+            CubeFace::NegX => (Vec3::Z, Vec3::NEG_Y),
+            CubeFace::PosY => (Vec3::X, Vec3::Z),
+            CubeFace::NegY => (Vec3::X, Vec3::NEG_Z),
+            CubeFace::PosZ => (Vec3::X, Vec3::NEG_Y),
+            CubeFace::NegZ => (Vec3::NEG_X, Vec3::NEG_Y),
+        }
+    }
+}
+
+/// The cubemap face a direction points into and the uv on that face
+/// Uses the OpenGL/Direct3D/Vulkan cubemap layout
+/// Requires: dir should be non-zero
+#[inline]
+pub fn dir_to_cube(dir: Vec3) -> (CubeFace, Vec2) {
+    let a = dir.abs();
+    let face = if a.x >= a.y && a.x >= a.z {
+        if dir.x >= 0.0 { CubeFace::PosX } else { CubeFace::NegX }
+    } else if a.y >= a.z {
+        if dir.y >= 0.0 { CubeFace::PosY } else { CubeFace::NegY }
+    } else if dir.z >= 0.0 {
+        CubeFace::PosZ
+    } else {
+        CubeFace::NegZ
+    };
+    let (u_axis, v_axis) = face.uv_axes();
+    let major = dir.dot(face.normal());
+    let uv = Vec2::new(dir.dot(u_axis), dir.dot(v_axis)) / major;
+    (face, (uv + 1.0) * 0.5)
+}
+
+/// Unit direction through a uv on a cubemap face, the inverse of dir_to_cube
+#[inline]
+pub fn cube_to_dir(face: CubeFace, uv: Vec2) -> Vec3 {
+    let (u_axis, v_axis) = face.uv_axes();
+    let st = uv * 2.0 - 1.0;
+    (face.normal() + u_axis * st.x + v_axis * st.y).normalize()
+}
+
+
+/// Stereographic projection of a unit direction from the -y pole onto the xz-plane
+/// The upper hemisphere maps inside the unit disk, and -y itself maps to infinity
+/// Requires: dir should be normalized
+#[inline]
+pub fn dir_to_stereographic(dir: Vec3) -> Vec2 {
+    Vec2::new(dir.x, dir.z) / (1.0 + dir.y)
+}
+
+/// Unit direction of a point on the stereographic plane, the inverse of dir_to_stereographic
+#[inline]
+pub fn stereographic_to_dir(p: Vec2) -> Vec3 {
+    let p_2 = p.length_2();
+    Vec3::new(2.0 * p.x, 1.0 - p_2, 2.0 * p.y) / (1.0 + p_2)
+}
@@ -0,0 +1,42 @@
+use std::{
+    error,
+    fmt::{
+        self,
+        Display,
+    },
+};
+
+
+/// Errors returned by the fallible `try_` APIs
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MathError {
+    /// A vector of length zero was given where a direction was required
+    ZeroLength,
+    /// An input or result contained NaN or infinity
+    NonFinite,
+    /// An input contained NaN where infinities were allowed
+    NaN,
+    /// Points that should span a shape are collinear or coincident
+    Degenerate,
+    /// A slice did not have the number of elements required
+    SliceLength {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::ZeroLength => write!(f, "vector has zero length"),
+            MathError::NonFinite => write!(f, "value is NaN or infinite"),
+            MathError::NaN => write!(f, "value is NaN"),
+            MathError::Degenerate => write!(f, "points are collinear or coincident"),
+            MathError::SliceLength { expected, found } => {
+                write!(f, "expected a slice of length {expected}, found {found}")
+            }
+        }
+    }
+}
+
+impl error::Error for MathError {}
@@ -0,0 +1,11 @@
+pub mod half;
+pub use half::F16;
+
+pub mod f16vec2;
+pub use f16vec2::*;
+
+pub mod f16vec3;
+pub use f16vec3::*;
+
+pub mod f16vec4;
+pub use f16vec4::*;
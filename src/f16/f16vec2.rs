@@ -0,0 +1,113 @@
+use crate::{
+    F16,
+    Vec2,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+};
+
+
+/// A half-precision vector in 2-space for compact storage, such as vertex data
+/// Convert to a Vec2 to do math, the conversion rounds to nearest with ties to even
+#[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct F16Vec2 {
+    pub x: F16,
+    pub y: F16,
+}
+
+impl F16Vec2 {
+    /// The default F16Vec2 with all 0's
+    pub const ZERO: F16Vec2 = F16Vec2::splat(F16::ZERO);
+
+
+    /// Standard constructor for <x y>
+    #[inline]
+    pub const fn new(x: F16, y: F16) -> F16Vec2 {
+        F16Vec2 { x, y }
+    }
+
+    #[inline]
+    pub const fn splat(v: F16) -> F16Vec2 {
+        F16Vec2::new(v, v)
+    }
+
+    /// Converts from a Vec2, rounding each component to the nearest F16
+    #[inline]
+    pub const fn from_vec2(v: Vec2) -> F16Vec2 {
+        F16Vec2::new(
+            F16::from_f32(v.x),
+            F16::from_f32(v.y),
+        )
+    }
+
+    /// Converts to a Vec2, which is exact
+    #[inline]
+    pub const fn to_vec2(self) -> Vec2 {
+        Vec2 {
+            x: self.x.to_f32(),
+            y: self.y.to_f32(),
+        }
+    }
+
+    /// The raw bits of each component, ready to upload
+    #[inline]
+    pub const fn to_bits(self) -> [u16; 2] {
+        [self.x.to_bits(), self.y.to_bits()]
+    }
+
+    #[inline]
+    pub const fn from_bits(bits: [u16; 2]) -> F16Vec2 {
+        F16Vec2::new(F16::from_bits(bits[0]), F16::from_bits(bits[1]))
+    }
+}
+
+
+impl Debug for F16Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("F16Vec2")
+            .field(&self.x)
+            .field(&self.y)
+            .finish()
+    }
+}
+impl Display for F16Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .finish()
+    }
+}
+
+
+impl From<Vec2> for F16Vec2 {
+    #[inline]
+    fn from(v: Vec2) -> F16Vec2 {
+        F16Vec2::from_vec2(v)
+    }
+}
+impl From<F16Vec2> for Vec2 {
+    #[inline]
+    fn from(v: F16Vec2) -> Vec2 {
+        v.to_vec2()
+    }
+}
+
+impl From<[F16; 2]> for F16Vec2 {
+    #[inline]
+    fn from(arr: [F16; 2]) -> F16Vec2 {
+        F16Vec2::new(arr[0], arr[1])
+    }
+}
+impl From<F16Vec2> for [F16; 2] {
+    #[inline]
+    fn from(v: F16Vec2) -> [F16; 2] {
+        [v.x, v.y]
+    }
+}
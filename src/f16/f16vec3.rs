@@ -0,0 +1,118 @@
+use crate::{
+    F16,
+    Vec3,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+};
+
+
+/// A half-precision vector in 3-space for compact storage, such as vertex data
+/// Convert to a Vec3 to do math, the conversion rounds to nearest with ties to even
+#[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct F16Vec3 {
+    pub x: F16,
+    pub y: F16,
+    pub z: F16,
+}
+
+impl F16Vec3 {
+    /// The default F16Vec3 with all 0's
+    pub const ZERO: F16Vec3 = F16Vec3::splat(F16::ZERO);
+
+
+    /// Standard constructor for <x y z>
+    #[inline]
+    pub const fn new(x: F16, y: F16, z: F16) -> F16Vec3 {
+        F16Vec3 { x, y, z }
+    }
+
+    #[inline]
+    pub const fn splat(v: F16) -> F16Vec3 {
+        F16Vec3::new(v, v, v)
+    }
+
+    /// Converts from a Vec3, rounding each component to the nearest F16
+    #[inline]
+    pub const fn from_vec3(v: Vec3) -> F16Vec3 {
+        F16Vec3::new(
+            F16::from_f32(v.x),
+            F16::from_f32(v.y),
+            F16::from_f32(v.z),
+        )
+    }
+
+    /// Converts to a Vec3, which is exact
+    #[inline]
+    pub const fn to_vec3(self) -> Vec3 {
+        Vec3 {
+            x: self.x.to_f32(),
+            y: self.y.to_f32(),
+            z: self.z.to_f32(),
+        }
+    }
+
+    /// The raw bits of each component, ready to upload
+    #[inline]
+    pub const fn to_bits(self) -> [u16; 3] {
+        [self.x.to_bits(), self.y.to_bits(), self.z.to_bits()]
+    }
+
+    #[inline]
+    pub const fn from_bits(bits: [u16; 3]) -> F16Vec3 {
+        F16Vec3::new(F16::from_bits(bits[0]), F16::from_bits(bits[1]), F16::from_bits(bits[2]))
+    }
+}
+
+
+impl Debug for F16Vec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("F16Vec3")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .finish()
+    }
+}
+impl Display for F16Vec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .entry(&self.z)
+            .finish()
+    }
+}
+
+
+impl From<Vec3> for F16Vec3 {
+    #[inline]
+    fn from(v: Vec3) -> F16Vec3 {
+        F16Vec3::from_vec3(v)
+    }
+}
+impl From<F16Vec3> for Vec3 {
+    #[inline]
+    fn from(v: F16Vec3) -> Vec3 {
+        v.to_vec3()
+    }
+}
+
+impl From<[F16; 3]> for F16Vec3 {
+    #[inline]
+    fn from(arr: [F16; 3]) -> F16Vec3 {
+        F16Vec3::new(arr[0], arr[1], arr[2])
+    }
+}
+impl From<F16Vec3> for [F16; 3] {
+    #[inline]
+    fn from(v: F16Vec3) -> [F16; 3] {
+        [v.x, v.y, v.z]
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    F16,
+    Vec4,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+};
+
+
+/// A half-precision vector in 4-space for compact storage, such as vertex data
+/// Convert to a Vec4 to do math, the conversion rounds to nearest with ties to even
+#[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct F16Vec4 {
+    pub x: F16,
+    pub y: F16,
+    pub z: F16,
+    pub w: F16,
+}
+
+impl F16Vec4 {
+    /// The default F16Vec4 with all 0's
+    pub const ZERO: F16Vec4 = F16Vec4::splat(F16::ZERO);
+
+
+    /// Standard constructor for <x y z w>
+    #[inline]
+    pub const fn new(x: F16, y: F16, z: F16, w: F16) -> F16Vec4 {
+        F16Vec4 { x, y, z, w }
+    }
+
+    #[inline]
+    pub const fn splat(v: F16) -> F16Vec4 {
+        F16Vec4::new(v, v, v, v)
+    }
+
+    /// Converts from a Vec4, rounding each component to the nearest F16
+    #[inline]
+    pub const fn from_vec4(v: Vec4) -> F16Vec4 {
+        F16Vec4::new(
+            F16::from_f32(v.x),
+            F16::from_f32(v.y),
+            F16::from_f32(v.z),
+            F16::from_f32(v.w),
+        )
+    }
+
+    /// Converts to a Vec4, which is exact
+    #[inline]
+    pub const fn to_vec4(self) -> Vec4 {
+        Vec4 {
+            x: self.x.to_f32(),
+            y: self.y.to_f32(),
+            z: self.z.to_f32(),
+            w: self.w.to_f32(),
+        }
+    }
+
+    /// The raw bits of each component, ready to upload
+    #[inline]
+    pub const fn to_bits(self) -> [u16; 4] {
+        [self.x.to_bits(), self.y.to_bits(), self.z.to_bits(), self.w.to_bits()]
+    }
+
+    #[inline]
+    pub const fn from_bits(bits: [u16; 4]) -> F16Vec4 {
+        F16Vec4::new(F16::from_bits(bits[0]), F16::from_bits(bits[1]), F16::from_bits(bits[2]), F16::from_bits(bits[3]))
+    }
+}
+
+
+impl Debug for F16Vec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("F16Vec4")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .field(&self.w)
+            .finish()
+    }
+}
+impl Display for F16Vec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .entry(&self.z)
+            .entry(&self.w)
+            .finish()
+    }
+}
+
+
+impl From<Vec4> for F16Vec4 {
+    #[inline]
+    fn from(v: Vec4) -> F16Vec4 {
+        F16Vec4::from_vec4(v)
+    }
+}
+impl From<F16Vec4> for Vec4 {
+    #[inline]
+    fn from(v: F16Vec4) -> Vec4 {
+        v.to_vec4()
+    }
+}
+
+impl From<[F16; 4]> for F16Vec4 {
+    #[inline]
+    fn from(arr: [F16; 4]) -> F16Vec4 {
+        F16Vec4::new(arr[0], arr[1], arr[2], arr[3])
+    }
+}
+impl From<F16Vec4> for [F16; 4] {
+    #[inline]
+    fn from(v: F16Vec4) -> [F16; 4] {
+        [v.x, v.y, v.z, v.w]
+    }
+}
@@ -0,0 +1,158 @@
+use std::{
+    cmp::{
+        Ordering,
+    },
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+};
+
+
+/// An IEEE 754 binary16 value, stored as its bits
+/// A storage format only, convert to f32 to do arithmetic
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct F16(u16);
+
+impl F16 {
+    pub const ZERO: F16 = F16(0x0000);
+    pub const ONE: F16 = F16(0x3c00);
+    pub const INFINITY: F16 = F16(0x7c00);
+    pub const NEG_INFINITY: F16 = F16(0xfc00);
+    pub const NAN: F16 = F16(0x7e00);
+
+    /// The largest finite value, 65504
+    pub const MAX: F16 = F16(0x7bff);
+
+    /// The smallest positive normal value, 2^-14
+    pub const MIN_POSITIVE: F16 = F16(0x0400);
+
+    /// The difference between 1 and the next larger value, 2^-10
+    pub const EPSILON: F16 = F16(0x1400);
+
+
+    #[inline]
+    pub const fn from_bits(bits: u16) -> F16 {
+        F16(bits)
+    }
+
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Converts from f32 rounding to the nearest value, ties to even
+    /// Values too large for an F16 become infinity and NaN stays NaN
+    #[inline]
+    pub const fn from_f32(v: f32) -> F16 {
+        let x = v.to_bits();
+        let sign = ((x >> 16) & 0x8000) as u16;
+        let exp = ((x >> 23) & 0xff) as i32;
+        let man = x & 0x7f_ffff;
+
+        if exp == 0xff {
+            // keep NaN quiet even if its payload would be shifted out
+            let nan = if man != 0 { 0x0200 | (man >> 13) as u16 } else { 0 };
+            return F16(sign | 0x7c00 | nan);
+        }
+        let e = exp - 127 + 15;
+        if e >= 0x1f {
+            return F16(sign | 0x7c00);
+        }
+        if e <= 0 {
+            // subnormal, or too small and rounds to zero
+            if e < -10 {
+                return F16(sign);
+            }
+            let man = man | 0x80_0000;
+            let shift = (14 - e) as u32;
+            let half_man = man >> shift;
+            let rem = man & ((1 << shift) - 1);
+            let halfway = 1 << (shift - 1);
+            let round = (rem > halfway || (rem == halfway && half_man & 1 != 0)) as u32;
+            return F16(sign | (half_man + round) as u16);
+        }
+        let half_man = man >> 13;
+        let rem = man & 0x1fff;
+        let round = (rem > 0x1000 || (rem == 0x1000 && half_man & 1 != 0)) as u32;
+        // a carry out of the mantissa correctly bumps the exponent, up to infinity
+        F16(sign | (((e as u32) << 10) + half_man + round) as u16)
+    }
+
+    /// Converts to f32, which is exact
+    #[inline]
+    pub const fn to_f32(self) -> f32 {
+        let h = self.0 as u32;
+        let sign = (h & 0x8000) << 16;
+        let exp = (h >> 10) & 0x1f;
+        let man = h & 0x3ff;
+        let bits = if exp == 0x1f {
+            sign | 0x7f80_0000 | (man << 13)
+        } else if exp != 0 {
+            sign | ((exp + 127 - 15) << 23) | (man << 13)
+        } else if man == 0 {
+            sign
+        } else {
+            // subnormal, shift the leading 1 into the implicit bit
+            let shift = man.leading_zeros() - 21;
+            let man = (man << shift) & 0x3ff;
+            sign | ((127 - 14 - shift) << 23) | (man << 13)
+        };
+        f32::from_bits(bits)
+    }
+
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7fff > 0x7c00
+    }
+
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        self.0 & 0x7c00 != 0x7c00
+    }
+}
+
+
+// Compares by value like f32, so -0 == 0 and NaN != NaN
+impl PartialEq for F16 {
+    #[inline]
+    fn eq(&self, rhs: &F16) -> bool {
+        self.to_f32() == rhs.to_f32()
+    }
+}
+
+impl PartialOrd for F16 {
+    #[inline]
+    fn partial_cmp(&self, rhs: &F16) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&rhs.to_f32())
+    }
+}
+
+
+impl Debug for F16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.to_f32(), f)
+    }
+}
+impl Display for F16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.to_f32(), f)
+    }
+}
+
+
+impl From<f32> for F16 {
+    #[inline]
+    fn from(v: f32) -> F16 {
+        F16::from_f32(v)
+    }
+}
+impl From<F16> for f32 {
+    #[inline]
+    fn from(v: F16) -> f32 {
+        v.to_f32()
+    }
+}
@@ -1,11 +1,64 @@
-pub(crate) mod math;
-pub(crate) use math::*;
+pub mod consts;
+
+pub use crate::scalar::F32Ext;
+
+pub mod math;
+pub use math::{
+    sincos_slice,
+    poly_eval,
+    horner3,
+    horner5,
+};
 
 pub(crate) mod vec;
 pub use vec::VecExt;
 
+pub mod vec2;
+pub use vec2::*;
+
 pub mod vec3;
 pub use vec3::*;
 
+pub mod vec3a;
+pub use vec3a::*;
+
 pub mod vec4;
 pub use vec4::*;
+
+mod swizzle;
+
+pub mod dir2;
+pub use dir2::*;
+
+pub mod dir3;
+pub use dir3::*;
+
+pub mod rot2;
+pub use rot2::*;
+
+pub mod mat2;
+pub use mat2::*;
+
+pub mod mat3;
+pub use mat3::*;
+
+pub mod mat4;
+pub use mat4::*;
+
+pub mod affine2;
+pub use affine2::*;
+
+pub mod affine3;
+pub use affine3::*;
+
+pub mod quat;
+pub use quat::*;
+
+pub mod transform;
+pub use transform::*;
+
+pub mod isometry3;
+pub use isometry3::*;
+
+pub mod checked;
+pub use checked::*;
@@ -0,0 +1,201 @@
+use crate::{
+    validate::validate_finite,
+    Mat2,
+    MathError,
+    Vec2,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    ops::{
+        Mul,
+        MulAssign,
+    },
+};
+
+
+/// An affine transform in 2-space stored as a 2x2 linear part and a translation
+/// Applies matrix2 and then translates
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Affine2 {
+    pub matrix2: Mat2,
+    pub translation: Vec2,
+}
+
+impl Affine2 {
+    /// The identity transform, leaves points unchanged
+    pub const IDENTITY: Affine2 = Affine2::from_mat2_translation(Mat2::IDENTITY, Vec2::ZERO);
+
+
+    /// A transform applying matrix2 and then translating by translation
+    #[inline]
+    pub const fn from_mat2_translation(matrix2: Mat2, translation: Vec2) -> Affine2 {
+        Affine2 { matrix2, translation }
+    }
+
+    /// A transform applying matrix2
+    #[inline]
+    pub const fn from_mat2(matrix2: Mat2) -> Affine2 {
+        Affine2::from_mat2_translation(matrix2, Vec2::ZERO)
+    }
+
+    /// A transform that translates by translation
+    #[inline]
+    pub const fn from_translation(translation: Vec2) -> Affine2 {
+        Affine2::from_mat2_translation(Mat2::IDENTITY, translation)
+    }
+
+    /// A transform that scales each axis
+    #[inline]
+    pub const fn from_scale(scale: Vec2) -> Affine2 {
+        Affine2::from_mat2(Mat2::from_diagonal(scale))
+    }
+
+    /// A counter-clockwise rotation by angle radians
+    #[inline]
+    pub fn from_angle(angle: f32) -> Affine2 {
+        Affine2::from_mat2(Mat2::from_angle(angle))
+    }
+
+    /// A transform that rotates counter-clockwise by angle radians and then translates
+    #[inline]
+    pub fn from_angle_translation(angle: f32, translation: Vec2) -> Affine2 {
+        Affine2::from_mat2_translation(Mat2::from_angle(angle), translation)
+    }
+
+    /// A transform that scales, then rotates, then translates
+    #[inline]
+    pub fn from_scale_angle_translation(scale: Vec2, angle: f32, translation: Vec2) -> Affine2 {
+        Affine2::from_mat2_translation(Mat2::from_scale_angle(scale, angle), translation)
+    }
+
+    /// Transforms a point, applying translation
+    #[inline]
+    pub fn transform_point2(&self, p: Vec2) -> Vec2 {
+        self.matrix2 * p + self.translation
+    }
+
+    /// Transforms a direction, ignoring translation
+    #[inline]
+    pub fn transform_vector2(&self, v: Vec2) -> Vec2 {
+        self.matrix2 * v
+    }
+
+    /// Returns the inverse, failing if matrix2 is singular or not finite
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Affine2, MathError> {
+        let matrix2 = self.matrix2.try_inverse()?;
+        Ok(Affine2::from_mat2_translation(matrix2, -(matrix2 * self.translation)))
+    }
+
+    /// Returns the inverse
+    /// Requires: matrix2 should be invertible
+    #[inline]
+    pub fn inverse(&self) -> Affine2 {
+        let matrix2 = self.matrix2.inverse();
+        let inv = Affine2::from_mat2_translation(matrix2, -(matrix2 * self.translation));
+        validate_finite!("Affine2::inverse", inv);
+        inv
+    }
+
+    /// Returns the inverse of a rotation plus translation by transposing
+    /// Much cheaper than inverse, but wrong if there is any scale or shear
+    /// Requires: matrix2 should be a pure rotation matrix
+    #[inline]
+    pub fn inverse_rigid(&self) -> Affine2 {
+        let matrix2 = self.matrix2.transpose();
+        Affine2::from_mat2_translation(matrix2, -(matrix2 * self.translation))
+    }
+
+    /// Checks if every element is finite
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.matrix2.is_finite() && self.translation.is_finite()
+    }
+
+    /// Checks if every element is within eps of the corresponding element of rhs
+    #[inline]
+    pub fn approx_eq(&self, rhs: Affine2, eps: f32) -> bool {
+        self.matrix2.approx_eq(rhs.matrix2, eps) && self.translation.approx_eq(rhs.translation, eps)
+    }
+}
+
+impl Default for Affine2 {
+    #[inline]
+    fn default() -> Affine2 {
+        Affine2::IDENTITY
+    }
+}
+
+
+impl Debug for Affine2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Affine2")
+            .field("matrix2", &self.matrix2)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+impl Display for Affine2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.matrix2.x_axis)
+            .entry(&self.matrix2.y_axis)
+            .entry(&self.translation)
+            .finish()
+    }
+}
+
+
+// Affine2 * Affine2
+impl Mul<Affine2> for Affine2 {
+    type Output = Affine2;
+    #[inline]
+    fn mul(self, rhs: Affine2) -> Self::Output {
+        Affine2::from_mat2_translation(
+            self.matrix2 * rhs.matrix2,
+            self.transform_point2(rhs.translation),
+        )
+    }
+}
+impl Mul<&Affine2> for Affine2 {
+    type Output = Affine2;
+    #[inline]
+    fn mul(self, rhs: &Affine2) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Affine2> for &Affine2 {
+    type Output = Affine2;
+    #[inline]
+    fn mul(self, rhs: Affine2) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Affine2> for &Affine2 {
+    type Output = Affine2;
+    #[inline]
+    fn mul(self, rhs: &Affine2) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Affine2 *= Affine2
+impl MulAssign<Affine2> for Affine2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Affine2) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&Affine2> for Affine2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Affine2) {
+        *self *= *rhs;
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    validate::validate_finite,
+    Mat3,
+    Mat4,
+    MathError,
+    Vec3,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    ops::{
+        Mul,
+        MulAssign,
+    },
+};
+
+
+/// An affine transform in 3-space stored as a 3x3 linear part and a translation
+/// Applies matrix3 and then translates, the same as the 3x4 top of a Mat4
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Affine3 {
+    pub matrix3: Mat3,
+    pub translation: Vec3,
+}
+
+impl Affine3 {
+    /// The identity transform, leaves points unchanged
+    pub const IDENTITY: Affine3 = Affine3::from_mat3_translation(Mat3::IDENTITY, Vec3::ZERO);
+
+
+    /// A transform applying matrix3 and then translating by translation
+    #[inline]
+    pub const fn from_mat3_translation(matrix3: Mat3, translation: Vec3) -> Affine3 {
+        Affine3 { matrix3, translation }
+    }
+
+    /// A transform applying matrix3
+    #[inline]
+    pub const fn from_mat3(matrix3: Mat3) -> Affine3 {
+        Affine3::from_mat3_translation(matrix3, Vec3::ZERO)
+    }
+
+    /// A transform that translates by translation
+    #[inline]
+    pub const fn from_translation(translation: Vec3) -> Affine3 {
+        Affine3::from_mat3_translation(Mat3::IDENTITY, translation)
+    }
+
+    /// A transform that scales each axis
+    #[inline]
+    pub const fn from_scale(scale: Vec3) -> Affine3 {
+        Affine3::from_mat3(Mat3::from_diagonal(scale))
+    }
+
+    /// A transform that scales, then rotates, then translates
+    /// Requires: rotation should be a pure rotation matrix
+    #[inline]
+    pub fn from_scale_rotation_translation(scale: Vec3, rotation: Mat3, translation: Vec3) -> Affine3 {
+        let matrix3 = Mat3::from_cols(
+            rotation.x_axis * scale.x,
+            rotation.y_axis * scale.y,
+            rotation.z_axis * scale.z,
+        );
+        Affine3::from_mat3_translation(matrix3, translation)
+    }
+
+    /// Extracts the affine part of m, ignoring its bottom row
+    #[inline]
+    pub fn from_mat4(m: Mat4) -> Affine3 {
+        let w = m.w_axis;
+        Affine3::from_mat3_translation(m.to_mat3(), Vec3::new(w.x, w.y, w.z))
+    }
+
+    /// The equivalent 4x4 matrix
+    #[inline]
+    pub const fn to_mat4(self) -> Mat4 {
+        Mat4::from_mat3_translation(self.matrix3, self.translation)
+    }
+
+    /// Transforms a point, applying translation
+    #[inline]
+    pub fn transform_point3(&self, p: Vec3) -> Vec3 {
+        self.matrix3 * p + self.translation
+    }
+
+    /// Transforms a direction, ignoring translation
+    #[inline]
+    pub fn transform_vector3(&self, v: Vec3) -> Vec3 {
+        self.matrix3 * v
+    }
+
+    /// Returns the inverse, failing if matrix3 is singular or not finite
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Affine3, MathError> {
+        let matrix3 = self.matrix3.try_inverse()?;
+        Ok(Affine3::from_mat3_translation(matrix3, -(matrix3 * self.translation)))
+    }
+
+    /// Returns the inverse
+    /// Requires: matrix3 should be invertible
+    #[inline]
+    pub fn inverse(&self) -> Affine3 {
+        let matrix3 = self.matrix3.inverse();
+        let inv = Affine3::from_mat3_translation(matrix3, -(matrix3 * self.translation));
+        validate_finite!("Affine3::inverse", inv);
+        inv
+    }
+
+    /// Returns the inverse of a rotation plus translation by transposing
+    /// Much cheaper than inverse, but wrong if there is any scale or shear
+    /// Requires: matrix3 should be a pure rotation matrix
+    #[inline]
+    pub fn inverse_rigid(&self) -> Affine3 {
+        let matrix3 = self.matrix3.transpose();
+        Affine3::from_mat3_translation(matrix3, -(matrix3 * self.translation))
+    }
+
+    /// Checks if every element is finite
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.matrix3.is_finite() && self.translation.is_finite()
+    }
+
+    /// Checks if every element is within eps of the corresponding element of rhs
+    #[inline]
+    pub fn approx_eq(&self, rhs: Affine3, eps: f32) -> bool {
+        self.matrix3.approx_eq(rhs.matrix3, eps) && self.translation.approx_eq(rhs.translation, eps)
+    }
+}
+
+impl Default for Affine3 {
+    #[inline]
+    fn default() -> Affine3 {
+        Affine3::IDENTITY
+    }
+}
+
+
+impl Debug for Affine3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Affine3")
+            .field("matrix3", &self.matrix3)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+impl Display for Affine3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.matrix3.x_axis)
+            .entry(&self.matrix3.y_axis)
+            .entry(&self.matrix3.z_axis)
+            .entry(&self.translation)
+            .finish()
+    }
+}
+
+
+// Affine3 * Affine3
+impl Mul<Affine3> for Affine3 {
+    type Output = Affine3;
+    #[inline]
+    fn mul(self, rhs: Affine3) -> Self::Output {
+        Affine3::from_mat3_translation(
+            self.matrix3 * rhs.matrix3,
+            self.transform_point3(rhs.translation),
+        )
+    }
+}
+impl Mul<&Affine3> for Affine3 {
+    type Output = Affine3;
+    #[inline]
+    fn mul(self, rhs: &Affine3) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Affine3> for &Affine3 {
+    type Output = Affine3;
+    #[inline]
+    fn mul(self, rhs: Affine3) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Affine3> for &Affine3 {
+    type Output = Affine3;
+    #[inline]
+    fn mul(self, rhs: &Affine3) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Affine3 *= Affine3
+impl MulAssign<Affine3> for Affine3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Affine3) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&Affine3> for Affine3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Affine3) {
+        *self *= *rhs;
+    }
+}
+
+
+impl From<Affine3> for Mat4 {
+    #[inline]
+    fn from(a: Affine3) -> Mat4 {
+        a.to_mat4()
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    MathError,
+    Vec2,
+    Vec3,
+    Vec3A,
+    Vec4,
+    VecExt,
+};
+use std::{
+    cmp::{
+        Ordering,
+    },
+    hash::{
+        Hash,
+        Hasher,
+    },
+    ops::{
+        Deref,
+    },
+};
+
+
+// Wrappers that validate every component once at construction
+// Since the wrapped values can never be NaN they can implement Eq, Ord, and Hash
+// so they can be used as map keys and sorted without partial_cmp unwraps
+
+/// A value whose components are all finite (no NaN or infinity)
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[repr(transparent)]
+pub struct Finite<T>(T);
+
+/// A value whose components are never NaN, infinities are allowed
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+#[repr(transparent)]
+pub struct NotNan<T>(T);
+
+impl<T> Finite<T> {
+    #[inline]
+    pub fn get(self) -> T {
+        self.0
+    }
+}
+
+impl<T> NotNan<T> {
+    #[inline]
+    pub fn get(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Finite<T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Deref for NotNan<T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+
+macro_rules! impl_checked {
+    ($wrapper:ident, $t:ty, $check:expr, $err:expr, |$v:ident| $arr:expr) => {
+        impl $wrapper<$t> {
+            /// Wraps v, failing if any component is invalid for this wrapper
+            #[inline]
+            pub fn new(v: $t) -> Result<Self, MathError> {
+                let $v = v;
+                if $arr.iter().all($check) {
+                    Ok($wrapper(v))
+                } else {
+                    Err($err)
+                }
+            }
+        }
+
+        impl TryFrom<$t> for $wrapper<$t> {
+            type Error = MathError;
+            #[inline]
+            fn try_from(v: $t) -> Result<Self, MathError> {
+                $wrapper::<$t>::new(v)
+            }
+        }
+
+        impl From<$wrapper<$t>> for $t {
+            #[inline]
+            fn from(w: $wrapper<$t>) -> $t {
+                w.0
+            }
+        }
+
+        // PartialEq never sees NaN so it is a full equivalence relation
+        impl Eq for $wrapper<$t> {}
+
+        impl Ord for $wrapper<$t> {
+            /// Lexicographic ordering of the components
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                let $v = self.0;
+                let a = $arr;
+                let $v = other.0;
+                let b = $arr;
+                // NaN is excluded by construction so partial_cmp always succeeds
+                a.partial_cmp(&b).unwrap()
+            }
+        }
+
+        impl PartialOrd for $wrapper<$t> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Hash for $wrapper<$t> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                let $v = self.0;
+                for c in $arr {
+                    // + 0.0 maps -0.0 to 0.0 so values that compare equal hash equally
+                    (c + 0.0).to_bits().hash(state);
+                }
+            }
+        }
+    };
+}
+
+impl_checked!(Finite, f32, |c: &f32| c.is_finite(), MathError::NonFinite, |v| [v]);
+impl_checked!(Finite, Vec2, |c: &f32| c.is_finite(), MathError::NonFinite, |v| v.to_array());
+impl_checked!(Finite, Vec3, |c: &f32| c.is_finite(), MathError::NonFinite, |v| v.to_array());
+impl_checked!(Finite, Vec3A, |c: &f32| c.is_finite(), MathError::NonFinite, |v| v.to_array());
+impl_checked!(Finite, Vec4, |c: &f32| c.is_finite(), MathError::NonFinite, |v| v.to_array());
+
+impl_checked!(NotNan, f32, |c: &f32| !c.is_nan(), MathError::NaN, |v| [v]);
+impl_checked!(NotNan, Vec2, |c: &f32| !c.is_nan(), MathError::NaN, |v| v.to_array());
+impl_checked!(NotNan, Vec3, |c: &f32| !c.is_nan(), MathError::NaN, |v| v.to_array());
+impl_checked!(NotNan, Vec3A, |c: &f32| !c.is_nan(), MathError::NaN, |v| v.to_array());
+impl_checked!(NotNan, Vec4, |c: &f32| !c.is_nan(), MathError::NaN, |v| v.to_array());
@@ -0,0 +1,32 @@
+// Angle constants usable in const contexts
+// Mirrors std::f32::consts for the common ones so a single import covers everything
+
+/// Archimedes' constant (pi)
+pub const PI: f32 = std::f32::consts::PI;
+
+/// The full circle constant (2 * pi)
+pub const TAU: f32 = std::f32::consts::TAU;
+
+/// pi / 2
+pub const FRAC_PI_2: f32 = std::f32::consts::FRAC_PI_2;
+
+/// pi / 3
+pub const FRAC_PI_3: f32 = std::f32::consts::FRAC_PI_3;
+
+/// pi / 4
+pub const FRAC_PI_4: f32 = std::f32::consts::FRAC_PI_4;
+
+/// pi / 6
+pub const FRAC_PI_6: f32 = std::f32::consts::FRAC_PI_6;
+
+/// pi / 8
+pub const FRAC_PI_8: f32 = std::f32::consts::FRAC_PI_8;
+
+/// 1 / pi
+pub const FRAC_1_PI: f32 = std::f32::consts::FRAC_1_PI;
+
+/// Multiply degrees by this to get radians
+pub const DEG_TO_RAD: f32 = PI / 180.0;
+
+/// Multiply radians by this to get degrees
+pub const RAD_TO_DEG: f32 = 180.0 / PI;
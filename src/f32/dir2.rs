@@ -0,0 +1,140 @@
+use crate::{
+    MathError,
+    Vec2,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+    ops::{
+        Deref,
+        Mul,
+        Neg,
+    },
+};
+
+
+/// A direction in 2-space, guaranteed to be normalized
+/// Functions that require a unit vector take this so the check happens once at construction
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Vec2", into = "Vec2"))]
+#[repr(transparent)]
+pub struct Dir2(Vec2);
+
+impl Dir2 {
+    /// The positive x-axis direction
+    pub const X: Dir2 = Dir2(Vec2::X);
+
+    /// The positive y-axis direction
+    pub const Y: Dir2 = Dir2(Vec2::Y);
+
+    /// The negative x-axis direction
+    pub const NEG_X: Dir2 = Dir2(Vec2::NEG_X);
+
+    /// The negative y-axis direction
+    pub const NEG_Y: Dir2 = Dir2(Vec2::NEG_Y);
+
+
+    /// Creates a Dir2 by normalizing v
+    /// Fails if v has zero length or is not finite
+    #[inline]
+    pub fn new(v: Vec2) -> Result<Dir2, MathError> {
+        v.try_normalize().map(Dir2)
+    }
+
+    /// Creates a Dir2 by normalizing v, also returning the length of v
+    /// Fails if v has zero length or is not finite
+    #[inline]
+    pub fn new_and_length(v: Vec2) -> Result<(Dir2, f32), MathError> {
+        let dir = Dir2::new(v)?;
+        Ok((dir, v.length()))
+    }
+
+    /// Creates a Dir2 from a vector that is already normalized
+    /// Requires: v should be normalized, only checked in debug builds
+    #[inline]
+    pub fn new_unchecked(v: Vec2) -> Dir2 {
+        debug_assert!(
+            (v.length_2() - 1.0).abs() < 1e-4,
+            "Dir2::new_unchecked requires a normalized vector",
+        );
+        Dir2(v)
+    }
+
+    #[inline]
+    pub const fn as_vec2(self) -> Vec2 {
+        self.0
+    }
+}
+
+
+impl Debug for Dir2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Dir2")
+            .field(&self.0)
+            .finish()
+    }
+}
+impl Display for Dir2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+
+impl Deref for Dir2 {
+    type Target = Vec2;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+
+// Dir2 * f32
+impl Mul<f32> for Dir2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.0 * rhs
+    }
+}
+
+// f32 * Dir2
+impl Mul<Dir2> for f32 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Dir2) -> Self::Output {
+        self * rhs.0
+    }
+}
+
+
+// -Dir2
+impl Neg for Dir2 {
+    type Output = Dir2;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Dir2(-self.0)
+    }
+}
+
+
+impl From<Dir2> for Vec2 {
+    #[inline]
+    fn from(dir: Dir2) -> Vec2 {
+        dir.0
+    }
+}
+
+impl TryFrom<Vec2> for Dir2 {
+    type Error = MathError;
+    #[inline]
+    fn try_from(v: Vec2) -> Result<Dir2, MathError> {
+        Dir2::new(v)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    MathError,
+    Vec3,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+    ops::{
+        Deref,
+        Mul,
+        Neg,
+    },
+};
+
+
+/// A direction in 3-space, guaranteed to be normalized
+/// Functions that require a unit vector take this so the check happens once at construction
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Vec3", into = "Vec3"))]
+#[repr(transparent)]
+pub struct Dir3(Vec3);
+
+impl Dir3 {
+    /// The positive x-axis direction
+    pub const X: Dir3 = Dir3(Vec3::X);
+
+    /// The positive y-axis direction
+    pub const Y: Dir3 = Dir3(Vec3::Y);
+
+    /// The positive z-axis direction
+    pub const Z: Dir3 = Dir3(Vec3::Z);
+
+    /// The negative x-axis direction
+    pub const NEG_X: Dir3 = Dir3(Vec3::NEG_X);
+
+    /// The negative y-axis direction
+    pub const NEG_Y: Dir3 = Dir3(Vec3::NEG_Y);
+
+    /// The negative z-axis direction
+    pub const NEG_Z: Dir3 = Dir3(Vec3::NEG_Z);
+
+
+    /// Creates a Dir3 by normalizing v
+    /// Fails if v has zero length or is not finite
+    #[inline]
+    pub fn new(v: Vec3) -> Result<Dir3, MathError> {
+        v.try_normalize().map(Dir3)
+    }
+
+    /// Creates a Dir3 by normalizing v, also returning the length of v
+    /// Fails if v has zero length or is not finite
+    #[inline]
+    pub fn new_and_length(v: Vec3) -> Result<(Dir3, f32), MathError> {
+        let dir = Dir3::new(v)?;
+        Ok((dir, v.length()))
+    }
+
+    /// Creates a Dir3 from a vector that is already normalized
+    /// Requires: v should be normalized, only checked in debug builds
+    #[inline]
+    pub fn new_unchecked(v: Vec3) -> Dir3 {
+        debug_assert!(
+            (v.length_2() - 1.0).abs() < 1e-4,
+            "Dir3::new_unchecked requires a normalized vector",
+        );
+        Dir3(v)
+    }
+
+    #[inline]
+    pub const fn as_vec3(self) -> Vec3 {
+        self.0
+    }
+}
+
+
+impl Debug for Dir3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Dir3")
+            .field(&self.0)
+            .finish()
+    }
+}
+impl Display for Dir3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+
+impl Deref for Dir3 {
+    type Target = Vec3;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+
+// Dir3 * f32
+impl Mul<f32> for Dir3 {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.0 * rhs
+    }
+}
+
+// f32 * Dir3
+impl Mul<Dir3> for f32 {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: Dir3) -> Self::Output {
+        self * rhs.0
+    }
+}
+
+
+// -Dir3
+impl Neg for Dir3 {
+    type Output = Dir3;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Dir3(-self.0)
+    }
+}
+
+
+impl From<Dir3> for Vec3 {
+    #[inline]
+    fn from(dir: Dir3) -> Vec3 {
+        dir.0
+    }
+}
+
+impl TryFrom<Vec3> for Dir3 {
+    type Error = MathError;
+    #[inline]
+    fn try_from(v: Vec3) -> Result<Dir3, MathError> {
+        Dir3::new(v)
+    }
+}
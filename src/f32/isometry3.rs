@@ -0,0 +1,241 @@
+use crate::{
+    Affine3,
+    Mat4,
+    Quat,
+    Transform,
+    Vec3,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    ops::{
+        Mul,
+        MulAssign,
+    },
+};
+
+
+/// A rigid transform in 3-space, a rotation followed by a translation
+/// Without scale the inverse is exact and composition stays rigid
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Isometry3 {
+    pub rotation: Quat,
+    pub translation: Vec3,
+}
+
+impl Isometry3 {
+    /// The identity transform, leaves points unchanged
+    pub const IDENTITY: Isometry3 = Isometry3::new(Quat::IDENTITY, Vec3::ZERO);
+
+
+    #[inline]
+    pub const fn new(rotation: Quat, translation: Vec3) -> Isometry3 {
+        Isometry3 { rotation, translation }
+    }
+
+    #[inline]
+    pub const fn from_translation(translation: Vec3) -> Isometry3 {
+        Isometry3::new(Quat::IDENTITY, translation)
+    }
+
+    #[inline]
+    pub const fn from_rotation(rotation: Quat) -> Isometry3 {
+        Isometry3::new(rotation, Vec3::ZERO)
+    }
+
+    /// Returns a copy of self with the translation replaced
+    #[inline]
+    pub const fn with_translation(mut self, translation: Vec3) -> Isometry3 {
+        self.translation = translation;
+        self
+    }
+
+    /// Returns a copy of self with the rotation replaced
+    #[inline]
+    pub const fn with_rotation(mut self, rotation: Quat) -> Isometry3 {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Transforms a point, applying rotation and translation
+    #[inline]
+    pub fn transform_point(&self, p: Vec3) -> Vec3 {
+        self.rotation * p + self.translation
+    }
+
+    /// Transforms a direction, ignoring translation
+    #[inline]
+    pub fn transform_vector(&self, v: Vec3) -> Vec3 {
+        self.rotation * v
+    }
+
+    /// Returns the inverse, exact for any isometry
+    /// Requires: rotation should be normalized
+    #[inline]
+    pub fn inverse(&self) -> Isometry3 {
+        let rotation = self.rotation.inverse();
+        Isometry3::new(rotation, -(rotation * self.translation))
+    }
+
+    /// Moves along a straight line from self to rhs, slerping the rotation
+    #[inline]
+    pub fn lerp(&self, rhs: &Isometry3, t: f32) -> Isometry3 {
+        Isometry3::new(
+            self.rotation.slerp(rhs.rotation, t),
+            self.translation.lerp(rhs.translation, t),
+        )
+    }
+
+    #[inline]
+    pub fn to_affine3(&self) -> Affine3 {
+        Affine3::from_mat3_translation(self.rotation.to_mat3(), self.translation)
+    }
+
+    #[inline]
+    pub fn to_mat4(&self) -> Mat4 {
+        self.to_affine3().to_mat4()
+    }
+
+    /// The equivalent Transform with unit scale
+    #[inline]
+    pub const fn to_transform(&self) -> Transform {
+        Transform::from_scale_rotation_translation(Vec3::splat(1.0), self.rotation, self.translation)
+    }
+
+    /// Checks if every component is finite
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.rotation.is_finite() && self.translation.is_finite()
+    }
+
+    /// Checks if each part is within eps of the same part of rhs
+    #[inline]
+    pub fn approx_eq(&self, rhs: &Isometry3, eps: f32) -> bool {
+        self.rotation.approx_eq(rhs.rotation, eps) && self.translation.approx_eq(rhs.translation, eps)
+    }
+}
+
+impl Default for Isometry3 {
+    #[inline]
+    fn default() -> Isometry3 {
+        Isometry3::IDENTITY
+    }
+}
+
+
+impl Debug for Isometry3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Isometry3")
+            .field("rotation", &self.rotation)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
+impl Display for Isometry3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "R{} T{}", self.rotation, self.translation)
+    }
+}
+
+
+// Isometry3 * Isometry3
+impl Mul<Isometry3> for Isometry3 {
+    type Output = Isometry3;
+    #[inline]
+    fn mul(self, rhs: Isometry3) -> Self::Output {
+        Isometry3::new(
+            self.rotation * rhs.rotation,
+            self.transform_point(rhs.translation),
+        )
+    }
+}
+impl Mul<&Isometry3> for Isometry3 {
+    type Output = Isometry3;
+    #[inline]
+    fn mul(self, rhs: &Isometry3) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Isometry3> for &Isometry3 {
+    type Output = Isometry3;
+    #[inline]
+    fn mul(self, rhs: Isometry3) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Isometry3> for &Isometry3 {
+    type Output = Isometry3;
+    #[inline]
+    fn mul(self, rhs: &Isometry3) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Isometry3 *= Isometry3
+impl MulAssign<Isometry3> for Isometry3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Isometry3) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&Isometry3> for Isometry3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Isometry3) {
+        *self *= *rhs;
+    }
+}
+
+// Isometry3 * Vec3
+impl Mul<Vec3> for Isometry3 {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        self.transform_point(rhs)
+    }
+}
+impl Mul<&Vec3> for Isometry3 {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: &Vec3) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Vec3> for &Isometry3 {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Vec3> for &Isometry3 {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: &Vec3) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+
+impl From<Isometry3> for Affine3 {
+    #[inline]
+    fn from(iso: Isometry3) -> Affine3 {
+        iso.to_affine3()
+    }
+}
+impl From<Isometry3> for Mat4 {
+    #[inline]
+    fn from(iso: Isometry3) -> Mat4 {
+        iso.to_mat4()
+    }
+}
+impl From<Isometry3> for Transform {
+    #[inline]
+    fn from(iso: Isometry3) -> Transform {
+        iso.to_transform()
+    }
+}
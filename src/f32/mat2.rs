@@ -0,0 +1,480 @@
+use crate::{
+    validate::validate_finite,
+    F32Ext,
+    MathError,
+    Vec2,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+        Mul,
+        MulAssign,
+        Neg,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// A 2x2 matrix stored as 2 column vectors
+/// Transforms column vectors, so (a * b) * v applies b first
+#[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Mat2 {
+    pub x_axis: Vec2,
+    pub y_axis: Vec2,
+}
+
+impl Mat2 {
+    /// The matrix with all 0's
+    pub const ZERO: Mat2 = Mat2::from_cols(Vec2::ZERO, Vec2::ZERO);
+
+    /// The identity matrix, leaves vectors unchanged
+    pub const IDENTITY: Mat2 = Mat2::from_cols(Vec2::X, Vec2::Y);
+
+
+    /// Creates a matrix from its columns
+    #[inline]
+    pub const fn from_cols(x_axis: Vec2, y_axis: Vec2) -> Mat2 {
+        Mat2 { x_axis, y_axis }
+    }
+
+    /// Creates a matrix from 4 values in column-major order
+    #[inline]
+    pub const fn from_cols_array(m: [f32; 4]) -> Mat2 {
+        Mat2::from_cols(Vec2::new(m[0], m[1]), Vec2::new(m[2], m[3]))
+    }
+
+    /// The 4 values in column-major order
+    #[inline]
+    pub const fn to_cols_array(self) -> [f32; 4] {
+        [self.x_axis.x, self.x_axis.y, self.y_axis.x, self.y_axis.y]
+    }
+
+    /// Creates a diagonal matrix, a non-uniform scale
+    #[inline]
+    pub const fn from_diagonal(d: Vec2) -> Mat2 {
+        Mat2::from_cols(Vec2::new(d.x, 0.0), Vec2::new(0.0, d.y))
+    }
+
+    /// Counter-clockwise rotation by angle radians
+    #[inline]
+    pub fn from_angle(angle: f32) -> Mat2 {
+        let (sin, cos) = angle.sincos();
+        Mat2::from_cols(Vec2::new(cos, sin), Vec2::new(-sin, cos))
+    }
+
+    /// Scales each axis, then rotates counter-clockwise by angle radians
+    #[inline]
+    pub fn from_scale_angle(scale: Vec2, angle: f32) -> Mat2 {
+        let rot = Mat2::from_angle(angle);
+        Mat2::from_cols(rot.x_axis * scale.x, rot.y_axis * scale.y)
+    }
+
+    /// A shear that adds shear.x * y to x and shear.y * x to y
+    #[inline]
+    pub const fn from_shear(shear: Vec2) -> Mat2 {
+        Mat2::from_cols(Vec2::new(1.0, shear.y), Vec2::new(shear.x, 1.0))
+    }
+
+    /// Returns column i
+    /// Panics if i > 1
+    #[inline]
+    pub fn col(&self, i: usize) -> Vec2 {
+        self[i]
+    }
+
+    /// Returns row i
+    /// Panics if i > 1
+    #[inline]
+    pub fn row(&self, i: usize) -> Vec2 {
+        Vec2::new(self.x_axis[i], self.y_axis[i])
+    }
+
+    #[inline]
+    pub fn transpose(&self) -> Mat2 {
+        Mat2::from_cols(self.row(0), self.row(1))
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.x_axis.x * self.y_axis.y - self.y_axis.x * self.x_axis.y
+    }
+
+    /// Returns the inverse, failing if self is singular or not finite
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Mat2, MathError> {
+        let det = self.determinant();
+        if det == 0.0 {
+            Err(MathError::Degenerate)
+        } else if !det.is_finite() {
+            Err(MathError::NonFinite)
+        } else {
+            Ok(self.adjugate() * det.recip())
+        }
+    }
+
+    /// Returns the inverse
+    /// Requires: self should be invertible
+    #[inline]
+    pub fn inverse(&self) -> Mat2 {
+        let inv = self.adjugate() * self.determinant().recip();
+        validate_finite!("Mat2::inverse", inv);
+        inv
+    }
+
+    /// The transposed cofactor matrix, inverse * determinant
+    #[inline]
+    fn adjugate(&self) -> Mat2 {
+        Mat2::from_cols(
+            Vec2::new(self.y_axis.y, -self.x_axis.y),
+            Vec2::new(-self.y_axis.x, self.x_axis.x),
+        )
+    }
+
+    /// Checks if every element is finite
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x_axis.is_finite() && self.y_axis.is_finite()
+    }
+
+    /// Checks if every element is within eps of the corresponding element of rhs
+    #[inline]
+    pub fn approx_eq(&self, rhs: Mat2, eps: f32) -> bool {
+        self.x_axis.approx_eq(rhs.x_axis, eps) && self.y_axis.approx_eq(rhs.y_axis, eps)
+    }
+}
+
+
+impl Debug for Mat2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mat2")
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .finish()
+    }
+}
+impl Display for Mat2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x_axis)
+            .entry(&self.y_axis)
+            .finish()
+    }
+}
+
+
+// Mat2 + Mat2
+impl Add<Mat2> for Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn add(self, rhs: Mat2) -> Self::Output {
+        Mat2::from_cols(
+            self.x_axis + rhs.x_axis,
+            self.y_axis + rhs.y_axis,
+        )
+    }
+}
+impl Add<&Mat2> for Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn add(self, rhs: &Mat2) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<Mat2> for &Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn add(self, rhs: Mat2) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&Mat2> for &Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn add(self, rhs: &Mat2) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// Mat2 += Mat2
+impl AddAssign<Mat2> for Mat2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Mat2) {
+        *self = *self + rhs;
+    }
+}
+impl AddAssign<&Mat2> for Mat2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Mat2) {
+        *self += *rhs;
+    }
+}
+
+
+// Mat2 - Mat2
+impl Sub<Mat2> for Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn sub(self, rhs: Mat2) -> Self::Output {
+        Mat2::from_cols(
+            self.x_axis - rhs.x_axis,
+            self.y_axis - rhs.y_axis,
+        )
+    }
+}
+impl Sub<&Mat2> for Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn sub(self, rhs: &Mat2) -> Self::Output {
+        self - *rhs
+    }
+}
+impl Sub<Mat2> for &Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn sub(self, rhs: Mat2) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&Mat2> for &Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn sub(self, rhs: &Mat2) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+// Mat2 -= Mat2
+impl SubAssign<Mat2> for Mat2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Mat2) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&Mat2> for Mat2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Mat2) {
+        *self -= *rhs;
+    }
+}
+
+
+// Mat2 * Mat2
+impl Mul<Mat2> for Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: Mat2) -> Self::Output {
+        Mat2::from_cols(
+            self * rhs.x_axis,
+            self * rhs.y_axis,
+        )
+    }
+}
+impl Mul<&Mat2> for Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: &Mat2) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Mat2> for &Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: Mat2) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Mat2> for &Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: &Mat2) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Mat2 *= Mat2
+impl MulAssign<Mat2> for Mat2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Mat2) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&Mat2> for Mat2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Mat2) {
+        *self *= *rhs;
+    }
+}
+
+// Mat2 * Vec2
+impl Mul<Vec2> for Mat2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        self.x_axis * rhs.x + self.y_axis * rhs.y
+    }
+}
+impl Mul<&Vec2> for Mat2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &Vec2) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Vec2> for &Mat2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Vec2> for &Mat2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &Vec2) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Mat2 * f32
+impl Mul<f32> for Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Mat2::from_cols(
+            self.x_axis * rhs,
+            self.y_axis * rhs,
+        )
+    }
+}
+impl Mul<&f32> for Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: &f32) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<f32> for &Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&f32> for &Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: &f32) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// f32 * Mat2
+impl Mul<Mat2> for f32 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: Mat2) -> Self::Output {
+        rhs * self
+    }
+}
+impl Mul<&Mat2> for f32 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: &Mat2) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Mat2> for &f32 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: Mat2) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Mat2> for &f32 {
+    type Output = Mat2;
+    #[inline]
+    fn mul(self, rhs: &Mat2) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Mat2 *= f32
+impl MulAssign<f32> for Mat2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&f32> for Mat2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &f32) {
+        *self *= *rhs;
+    }
+}
+
+
+// -Mat2
+impl Neg for Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+impl Neg for &Mat2 {
+    type Output = Mat2;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        *self * -1.0
+    }
+}
+
+
+// Mat2[] indexes columns
+impl Index<usize> for Mat2 {
+    type Output = Vec2;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            _ => panic!("Cannot index into a Mat2 at i > 1"),
+        }
+    }
+}
+impl IndexMut<usize> for Mat2 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            _ => panic!("Cannot index into a Mat2 at i > 1"),
+        }
+    }
+}
+
+
+impl From<[[f32; 2]; 2]> for Mat2 {
+    #[inline]
+    fn from(cols: [[f32; 2]; 2]) -> Mat2 {
+        Mat2::from_cols(cols[0].into(), cols[1].into())
+    }
+}
+impl From<Mat2> for [[f32; 2]; 2] {
+    #[inline]
+    fn from(m: Mat2) -> [[f32; 2]; 2] {
+        [m.x_axis.into(), m.y_axis.into()]
+    }
+}
@@ -0,0 +1,517 @@
+use crate::{
+    validate::validate_finite,
+    Dir3,
+    MathError,
+    Vec3,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+        Mul,
+        MulAssign,
+        Neg,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// A 3x3 matrix stored as 3 column vectors
+/// Transforms column vectors, so (a * b) * v applies b first
+#[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Mat3 {
+    pub x_axis: Vec3,
+    pub y_axis: Vec3,
+    pub z_axis: Vec3,
+}
+
+impl Mat3 {
+    /// The matrix with all 0's
+    pub const ZERO: Mat3 = Mat3::from_cols(Vec3::ZERO, Vec3::ZERO, Vec3::ZERO);
+
+    /// The identity matrix, leaves vectors unchanged
+    pub const IDENTITY: Mat3 = Mat3::from_cols(Vec3::X, Vec3::Y, Vec3::Z);
+
+
+    /// Creates a matrix from its columns
+    #[inline]
+    pub const fn from_cols(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Mat3 {
+        Mat3 { x_axis, y_axis, z_axis }
+    }
+
+    /// Creates a matrix from 9 values in column-major order
+    #[inline]
+    pub const fn from_cols_array(m: [f32; 9]) -> Mat3 {
+        Mat3::from_cols(
+            Vec3::new(m[0], m[1], m[2]),
+            Vec3::new(m[3], m[4], m[5]),
+            Vec3::new(m[6], m[7], m[8]),
+        )
+    }
+
+    /// The 9 values in column-major order
+    #[inline]
+    pub const fn to_cols_array(self) -> [f32; 9] {
+        let Mat3 { x_axis: x, y_axis: y, z_axis: z } = self;
+        [x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z]
+    }
+
+    /// Creates a matrix from its rows
+    #[inline]
+    pub fn from_rows(r0: Vec3, r1: Vec3, r2: Vec3) -> Mat3 {
+        Mat3::from_cols(r0, r1, r2).transpose()
+    }
+
+    /// Creates a diagonal matrix, a non-uniform scale
+    #[inline]
+    pub const fn from_diagonal(d: Vec3) -> Mat3 {
+        Mat3::from_cols(
+            Vec3::new(d.x, 0.0, 0.0),
+            Vec3::new(0.0, d.y, 0.0),
+            Vec3::new(0.0, 0.0, d.z),
+        )
+    }
+
+    /// Rotation by angle radians about axis, counter-clockwise when looking down the axis towards the origin
+    #[inline]
+    pub fn from_axis_angle(axis: Dir3, angle: f32) -> Mat3 {
+        let (sin, cos) = angle.sin_cos();
+        let Vec3 { x, y, z } = axis.as_vec3();
+        let t = 1.0 - cos;
+        Mat3::from_cols(
+            Vec3::new(t * x * x + cos, t * x * y + sin * z, t * x * z - sin * y),
+            Vec3::new(t * x * y - sin * z, t * y * y + cos, t * y * z + sin * x),
+            Vec3::new(t * x * z + sin * y, t * y * z - sin * x, t * z * z + cos),
+        )
+    }
+
+    /// The outer product a * b^T
+    #[inline]
+    pub fn from_outer(a: Vec3, b: Vec3) -> Mat3 {
+        Mat3::from_cols(a * b.x, a * b.y, a * b.z)
+    }
+
+    /// Returns column i
+    /// Panics if i > 2
+    #[inline]
+    pub fn col(&self, i: usize) -> Vec3 {
+        self[i]
+    }
+
+    /// Returns row i
+    /// Panics if i > 2
+    #[inline]
+    pub fn row(&self, i: usize) -> Vec3 {
+        Vec3::new(self.x_axis[i], self.y_axis[i], self.z_axis[i])
+    }
+
+    #[inline]
+    pub fn diagonal(&self) -> Vec3 {
+        Vec3::new(self.x_axis.x, self.y_axis.y, self.z_axis.z)
+    }
+
+    /// Sum of the diagonal
+    #[inline]
+    pub fn trace(&self) -> f32 {
+        self.diagonal().sum()
+    }
+
+    #[inline]
+    pub fn transpose(&self) -> Mat3 {
+        Mat3::from_cols(self.row(0), self.row(1), self.row(2))
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.x_axis.dot(self.y_axis.cross(self.z_axis))
+    }
+
+    /// Returns the inverse, failing if self is singular or not finite
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Mat3, MathError> {
+        let det = self.determinant();
+        if det == 0.0 {
+            Err(MathError::Degenerate)
+        } else if !det.is_finite() {
+            Err(MathError::NonFinite)
+        } else {
+            Ok(self.adjugate() * det.recip())
+        }
+    }
+
+    /// Returns the inverse
+    /// Requires: self should be invertible
+    #[inline]
+    pub fn inverse(&self) -> Mat3 {
+        let inv = self.adjugate() * self.determinant().recip();
+        validate_finite!("Mat3::inverse", inv);
+        inv
+    }
+
+    /// The transposed cofactor matrix, inverse * determinant
+    #[inline]
+    fn adjugate(&self) -> Mat3 {
+        Mat3::from_rows(
+            self.y_axis.cross(self.z_axis),
+            self.z_axis.cross(self.x_axis),
+            self.x_axis.cross(self.y_axis),
+        )
+    }
+
+    /// Checks if every element is finite
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x_axis.is_finite() && self.y_axis.is_finite() && self.z_axis.is_finite()
+    }
+
+    /// Checks if every element is within eps of the corresponding element of rhs
+    #[inline]
+    pub fn approx_eq(&self, rhs: Mat3, eps: f32) -> bool {
+        self.x_axis.approx_eq(rhs.x_axis, eps)
+            && self.y_axis.approx_eq(rhs.y_axis, eps)
+            && self.z_axis.approx_eq(rhs.z_axis, eps)
+    }
+}
+
+
+impl Debug for Mat3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mat3")
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .field("z_axis", &self.z_axis)
+            .finish()
+    }
+}
+impl Display for Mat3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x_axis)
+            .entry(&self.y_axis)
+            .entry(&self.z_axis)
+            .finish()
+    }
+}
+
+
+// Mat3 + Mat3
+impl Add<Mat3> for Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn add(self, rhs: Mat3) -> Self::Output {
+        Mat3::from_cols(
+            self.x_axis + rhs.x_axis,
+            self.y_axis + rhs.y_axis,
+            self.z_axis + rhs.z_axis,
+        )
+    }
+}
+impl Add<&Mat3> for Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn add(self, rhs: &Mat3) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<Mat3> for &Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn add(self, rhs: Mat3) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&Mat3> for &Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn add(self, rhs: &Mat3) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// Mat3 += Mat3
+impl AddAssign<Mat3> for Mat3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Mat3) {
+        *self = *self + rhs;
+    }
+}
+impl AddAssign<&Mat3> for Mat3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Mat3) {
+        *self += *rhs;
+    }
+}
+
+
+// Mat3 - Mat3
+impl Sub<Mat3> for Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn sub(self, rhs: Mat3) -> Self::Output {
+        Mat3::from_cols(
+            self.x_axis - rhs.x_axis,
+            self.y_axis - rhs.y_axis,
+            self.z_axis - rhs.z_axis,
+        )
+    }
+}
+impl Sub<&Mat3> for Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn sub(self, rhs: &Mat3) -> Self::Output {
+        self - *rhs
+    }
+}
+impl Sub<Mat3> for &Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn sub(self, rhs: Mat3) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&Mat3> for &Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn sub(self, rhs: &Mat3) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+// Mat3 -= Mat3
+impl SubAssign<Mat3> for Mat3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Mat3) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&Mat3> for Mat3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Mat3) {
+        *self -= *rhs;
+    }
+}
+
+
+// Mat3 * Mat3
+impl Mul<Mat3> for Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: Mat3) -> Self::Output {
+        Mat3::from_cols(
+            self * rhs.x_axis,
+            self * rhs.y_axis,
+            self * rhs.z_axis,
+        )
+    }
+}
+impl Mul<&Mat3> for Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: &Mat3) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Mat3> for &Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: Mat3) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Mat3> for &Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: &Mat3) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Mat3 *= Mat3
+impl MulAssign<Mat3> for Mat3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Mat3) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&Mat3> for Mat3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Mat3) {
+        *self *= *rhs;
+    }
+}
+
+// Mat3 * Vec3
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        self.x_axis * rhs.x + self.y_axis * rhs.y + self.z_axis * rhs.z
+    }
+}
+impl Mul<&Vec3> for Mat3 {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: &Vec3) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Vec3> for &Mat3 {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Vec3> for &Mat3 {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: &Vec3) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Mat3 * f32
+impl Mul<f32> for Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Mat3::from_cols(
+            self.x_axis * rhs,
+            self.y_axis * rhs,
+            self.z_axis * rhs,
+        )
+    }
+}
+impl Mul<&f32> for Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: &f32) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<f32> for &Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&f32> for &Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: &f32) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// f32 * Mat3
+impl Mul<Mat3> for f32 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: Mat3) -> Self::Output {
+        rhs * self
+    }
+}
+impl Mul<&Mat3> for f32 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: &Mat3) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Mat3> for &f32 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: Mat3) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Mat3> for &f32 {
+    type Output = Mat3;
+    #[inline]
+    fn mul(self, rhs: &Mat3) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Mat3 *= f32
+impl MulAssign<f32> for Mat3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&f32> for Mat3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &f32) {
+        *self *= *rhs;
+    }
+}
+
+
+// -Mat3
+impl Neg for Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+impl Neg for &Mat3 {
+    type Output = Mat3;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        *self * -1.0
+    }
+}
+
+
+// Mat3[] indexes columns
+impl Index<usize> for Mat3 {
+    type Output = Vec3;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            _ => panic!("Cannot index into a Mat3 at i > 2"),
+        }
+    }
+}
+impl IndexMut<usize> for Mat3 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            2 => &mut self.z_axis,
+            _ => panic!("Cannot index into a Mat3 at i > 2"),
+        }
+    }
+}
+
+
+impl From<[[f32; 3]; 3]> for Mat3 {
+    #[inline]
+    fn from(cols: [[f32; 3]; 3]) -> Mat3 {
+        Mat3::from_cols(cols[0].into(), cols[1].into(), cols[2].into())
+    }
+}
+impl From<Mat3> for [[f32; 3]; 3] {
+    #[inline]
+    fn from(m: Mat3) -> [[f32; 3]; 3] {
+        [m.x_axis.into(), m.y_axis.into(), m.z_axis.into()]
+    }
+}
@@ -0,0 +1,648 @@
+use crate::{
+    validate::validate_finite,
+    Axis,
+    Dir3,
+    Mat3,
+    MathError,
+    Vec3,
+    Vec4,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+        Mul,
+        MulAssign,
+        Neg,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// A 4x4 matrix stored as 4 column vectors
+/// Transforms column vectors, so (a * b) * v applies b first
+#[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Mat4 {
+    pub x_axis: Vec4,
+    pub y_axis: Vec4,
+    pub z_axis: Vec4,
+    pub w_axis: Vec4,
+}
+
+impl Mat4 {
+    /// The matrix with all 0's
+    pub const ZERO: Mat4 = Mat4::from_cols(Vec4::ZERO, Vec4::ZERO, Vec4::ZERO, Vec4::ZERO);
+
+    /// The identity matrix, leaves vectors unchanged
+    pub const IDENTITY: Mat4 = Mat4::from_cols(Vec4::X, Vec4::Y, Vec4::Z, Vec4::W);
+
+
+    /// Creates a matrix from its columns
+    #[inline]
+    pub const fn from_cols(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Mat4 {
+        Mat4 { x_axis, y_axis, z_axis, w_axis }
+    }
+
+    /// Creates a matrix from 16 values in column-major order
+    #[inline]
+    pub const fn from_cols_array(m: [f32; 16]) -> Mat4 {
+        Mat4::from_cols(
+            Vec4::new(m[0], m[1], m[2], m[3]),
+            Vec4::new(m[4], m[5], m[6], m[7]),
+            Vec4::new(m[8], m[9], m[10], m[11]),
+            Vec4::new(m[12], m[13], m[14], m[15]),
+        )
+    }
+
+    /// The 16 values in column-major order
+    #[inline]
+    pub const fn to_cols_array(self) -> [f32; 16] {
+        let Mat4 { x_axis: x, y_axis: y, z_axis: z, w_axis: w } = self;
+        [x.x, x.y, x.z, x.w, y.x, y.y, y.z, y.w, z.x, z.y, z.z, z.w, w.x, w.y, w.z, w.w]
+    }
+
+    /// Creates a matrix from its rows
+    #[inline]
+    pub fn from_rows(r0: Vec4, r1: Vec4, r2: Vec4, r3: Vec4) -> Mat4 {
+        Mat4::from_cols(r0, r1, r2, r3).transpose()
+    }
+
+    /// Creates a diagonal matrix
+    #[inline]
+    pub const fn from_diagonal(d: Vec4) -> Mat4 {
+        Mat4::from_cols(
+            Vec4::new(d.x, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, d.y, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, d.z, 0.0),
+            Vec4::new(0.0, 0.0, 0.0, d.w),
+        )
+    }
+
+    /// An affine transform that translates by t
+    #[inline]
+    pub const fn from_translation(t: Vec3) -> Mat4 {
+        Mat4::from_cols(Vec4::X, Vec4::Y, Vec4::Z, Vec4::new(t.x, t.y, t.z, 1.0))
+    }
+
+    /// An affine transform that scales each axis
+    #[inline]
+    pub const fn from_scale(s: Vec3) -> Mat4 {
+        Mat4::from_diagonal(Vec4::new(s.x, s.y, s.z, 1.0))
+    }
+
+    /// An affine transform applying m and then translating by t
+    #[inline]
+    pub const fn from_mat3_translation(m: Mat3, t: Vec3) -> Mat4 {
+        let Mat3 { x_axis: x, y_axis: y, z_axis: z } = m;
+        Mat4::from_cols(
+            Vec4::new(x.x, x.y, x.z, 0.0),
+            Vec4::new(y.x, y.y, y.z, 0.0),
+            Vec4::new(z.x, z.y, z.z, 0.0),
+            Vec4::new(t.x, t.y, t.z, 1.0),
+        )
+    }
+
+    /// An affine transform applying m
+    #[inline]
+    pub const fn from_mat3(m: Mat3) -> Mat4 {
+        Mat4::from_mat3_translation(m, Vec3::ZERO)
+    }
+
+    /// An affine transform rotating by angle radians about a coordinate axis,
+    /// counter-clockwise when looking down the axis towards the origin
+    /// Panics if axis is not one of the 3 axes of a Vec3
+    #[inline]
+    pub fn from_rotation(axis: Axis, angle: f32) -> Mat4 {
+        Mat4::from_mat3(Mat3::from_axis_angle(Dir3::new_unchecked(Vec3::axis(axis)), angle))
+    }
+
+    /// The upper left 3x3 block, the linear part of an affine transform
+    #[inline]
+    pub fn to_mat3(&self) -> Mat3 {
+        let xyz = |c: Vec4| Vec3::new(c.x, c.y, c.z);
+        Mat3::from_cols(xyz(self.x_axis), xyz(self.y_axis), xyz(self.z_axis))
+    }
+
+    /// A view matrix for a camera at eye looking along forward, in a left-handed y-up world
+    /// View space has +x right, +y up and +z forward, matching Vec3::forward_from_yaw
+    /// Requires: forward should be normalized and not parallel to up
+    #[inline]
+    pub fn look_to_lh(eye: Vec3, forward: Vec3, up: Vec3) -> Mat4 {
+        let right = up.cross(forward).normalize();
+        let up = forward.cross(right);
+        Mat4::from_cols(
+            Vec4::new(right.x, up.x, forward.x, 0.0),
+            Vec4::new(right.y, up.y, forward.y, 0.0),
+            Vec4::new(right.z, up.z, forward.z, 0.0),
+            Vec4::new(-right.dot(eye), -up.dot(eye), -forward.dot(eye), 1.0),
+        )
+    }
+
+    /// A view matrix for a camera at eye looking at target, in a left-handed y-up world
+    /// Requires: target should not be eye, and the direction to it should not be parallel to up
+    #[inline]
+    pub fn look_at_lh(eye: Vec3, target: Vec3, up: Vec3) -> Mat4 {
+        Mat4::look_to_lh(eye, (target - eye).normalize(), up)
+    }
+
+    /// Returns column i
+    /// Panics if i > 3
+    #[inline]
+    pub fn col(&self, i: usize) -> Vec4 {
+        self[i]
+    }
+
+    /// Returns row i
+    /// Panics if i > 3
+    #[inline]
+    pub fn row(&self, i: usize) -> Vec4 {
+        Vec4::new(self.x_axis[i], self.y_axis[i], self.z_axis[i], self.w_axis[i])
+    }
+
+    #[inline]
+    pub fn transpose(&self) -> Mat4 {
+        Mat4::from_cols(self.row(0), self.row(1), self.row(2), self.row(3))
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.cofactors().1
+    }
+
+    /// Returns the inverse, failing if self is singular or not finite
+    #[inline]
+    pub fn try_inverse(&self) -> Result<Mat4, MathError> {
+        let (adjugate, det) = self.cofactors();
+        if det == 0.0 {
+            Err(MathError::Degenerate)
+        } else if !det.is_finite() {
+            Err(MathError::NonFinite)
+        } else {
+            Ok(adjugate * det.recip())
+        }
+    }
+
+    /// Returns the inverse
+    /// Requires: self should be invertible
+    #[inline]
+    pub fn inverse(&self) -> Mat4 {
+        let (adjugate, det) = self.cofactors();
+        let inv = adjugate * det.recip();
+        validate_finite!("Mat4::inverse", inv);
+        inv
+    }
+
+    /// The adjugate and determinant, computed together from shared 2x2 minors
+    /// See Eberly, The Laplace Expansion Theorem
+    #[inline]
+    fn cofactors(&self) -> (Mat4, f32) {
+        let [a00, a01, a02, a03] = self.row(0).to_array();
+        let [a10, a11, a12, a13] = self.row(1).to_array();
+        let [a20, a21, a22, a23] = self.row(2).to_array();
+        let [a30, a31, a32, a33] = self.row(3).to_array();
+
+        // minors of the top two rows
+        let s0 = a00 * a11 - a10 * a01;
+        let s1 = a00 * a12 - a10 * a02;
+        let s2 = a00 * a13 - a10 * a03;
+        let s3 = a01 * a12 - a11 * a02;
+        let s4 = a01 * a13 - a11 * a03;
+        let s5 = a02 * a13 - a12 * a03;
+        // minors of the bottom two rows
+        let c0 = a20 * a31 - a30 * a21;
+        let c1 = a20 * a32 - a30 * a22;
+        let c2 = a20 * a33 - a30 * a23;
+        let c3 = a21 * a32 - a31 * a22;
+        let c4 = a21 * a33 - a31 * a23;
+        let c5 = a22 * a33 - a32 * a23;
+
+        let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+        let adjugate = Mat4::from_cols(
+            Vec4::new(
+                a11 * c5 - a12 * c4 + a13 * c3,
+                -a10 * c5 + a12 * c2 - a13 * c1,
+                a10 * c4 - a11 * c2 + a13 * c0,
+                -a10 * c3 + a11 * c1 - a12 * c0,
+            ),
+            Vec4::new(
+                -a01 * c5 + a02 * c4 - a03 * c3,
+                a00 * c5 - a02 * c2 + a03 * c1,
+                -a00 * c4 + a01 * c2 - a03 * c0,
+                a00 * c3 - a01 * c1 + a02 * c0,
+            ),
+            Vec4::new(
+                a31 * s5 - a32 * s4 + a33 * s3,
+                -a30 * s5 + a32 * s2 - a33 * s1,
+                a30 * s4 - a31 * s2 + a33 * s0,
+                -a30 * s3 + a31 * s1 - a32 * s0,
+            ),
+            Vec4::new(
+                -a21 * s5 + a22 * s4 - a23 * s3,
+                a20 * s5 - a22 * s2 + a23 * s1,
+                -a20 * s4 + a21 * s2 - a23 * s0,
+                a20 * s3 - a21 * s1 + a22 * s0,
+            ),
+        );
+        (adjugate, det)
+    }
+
+    /// Transforms a point, applying translation
+    /// Assumes self is affine, the w row is ignored
+    #[inline]
+    pub fn transform_point3(&self, p: Vec3) -> Vec3 {
+        let v = self.x_axis * p.x + self.y_axis * p.y + self.z_axis * p.z + self.w_axis;
+        Vec3::new(v.x, v.y, v.z)
+    }
+
+    /// Transforms a point and divides by the resulting w, for projection matrices
+    #[inline]
+    pub fn project_point3(&self, p: Vec3) -> Vec3 {
+        let v = *self * Vec4::new(p.x, p.y, p.z, 1.0);
+        Vec3::new(v.x, v.y, v.z) / v.w
+    }
+
+    /// Transforms a direction, ignoring translation
+    #[inline]
+    pub fn transform_vector3(&self, v: Vec3) -> Vec3 {
+        let v = self.x_axis * v.x + self.y_axis * v.y + self.z_axis * v.z;
+        Vec3::new(v.x, v.y, v.z)
+    }
+
+    /// Checks if every element is finite
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x_axis.is_finite() && self.y_axis.is_finite() && self.z_axis.is_finite() && self.w_axis.is_finite()
+    }
+
+    /// Checks if every element is within eps of the corresponding element of rhs
+    #[inline]
+    pub fn approx_eq(&self, rhs: Mat4, eps: f32) -> bool {
+        self.x_axis.approx_eq(rhs.x_axis, eps)
+            && self.y_axis.approx_eq(rhs.y_axis, eps)
+            && self.z_axis.approx_eq(rhs.z_axis, eps)
+            && self.w_axis.approx_eq(rhs.w_axis, eps)
+    }
+}
+
+
+impl Debug for Mat4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mat4")
+            .field("x_axis", &self.x_axis)
+            .field("y_axis", &self.y_axis)
+            .field("z_axis", &self.z_axis)
+            .field("w_axis", &self.w_axis)
+            .finish()
+    }
+}
+impl Display for Mat4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x_axis)
+            .entry(&self.y_axis)
+            .entry(&self.z_axis)
+            .entry(&self.w_axis)
+            .finish()
+    }
+}
+
+
+// Mat4 + Mat4
+impl Add<Mat4> for Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn add(self, rhs: Mat4) -> Self::Output {
+        Mat4::from_cols(
+            self.x_axis + rhs.x_axis,
+            self.y_axis + rhs.y_axis,
+            self.z_axis + rhs.z_axis,
+            self.w_axis + rhs.w_axis,
+        )
+    }
+}
+impl Add<&Mat4> for Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn add(self, rhs: &Mat4) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<Mat4> for &Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn add(self, rhs: Mat4) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&Mat4> for &Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn add(self, rhs: &Mat4) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// Mat4 += Mat4
+impl AddAssign<Mat4> for Mat4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Mat4) {
+        *self = *self + rhs;
+    }
+}
+impl AddAssign<&Mat4> for Mat4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Mat4) {
+        *self += *rhs;
+    }
+}
+
+
+// Mat4 - Mat4
+impl Sub<Mat4> for Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn sub(self, rhs: Mat4) -> Self::Output {
+        Mat4::from_cols(
+            self.x_axis - rhs.x_axis,
+            self.y_axis - rhs.y_axis,
+            self.z_axis - rhs.z_axis,
+            self.w_axis - rhs.w_axis,
+        )
+    }
+}
+impl Sub<&Mat4> for Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn sub(self, rhs: &Mat4) -> Self::Output {
+        self - *rhs
+    }
+}
+impl Sub<Mat4> for &Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn sub(self, rhs: Mat4) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&Mat4> for &Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn sub(self, rhs: &Mat4) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+// Mat4 -= Mat4
+impl SubAssign<Mat4> for Mat4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Mat4) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&Mat4> for Mat4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Mat4) {
+        *self -= *rhs;
+    }
+}
+
+
+// Mat4 * Mat4
+impl Mul<Mat4> for Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: Mat4) -> Self::Output {
+        Mat4::from_cols(
+            self * rhs.x_axis,
+            self * rhs.y_axis,
+            self * rhs.z_axis,
+            self * rhs.w_axis,
+        )
+    }
+}
+impl Mul<&Mat4> for Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: &Mat4) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Mat4> for &Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: Mat4) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Mat4> for &Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: &Mat4) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Mat4 *= Mat4
+impl MulAssign<Mat4> for Mat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Mat4) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&Mat4> for Mat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Mat4) {
+        *self *= *rhs;
+    }
+}
+
+// Mat4 * Vec4
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+    #[inline]
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        self.x_axis * rhs.x + self.y_axis * rhs.y + self.z_axis * rhs.z + self.w_axis * rhs.w
+    }
+}
+impl Mul<&Vec4> for Mat4 {
+    type Output = Vec4;
+    #[inline]
+    fn mul(self, rhs: &Vec4) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Vec4> for &Mat4 {
+    type Output = Vec4;
+    #[inline]
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Vec4> for &Mat4 {
+    type Output = Vec4;
+    #[inline]
+    fn mul(self, rhs: &Vec4) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Mat4 * f32
+impl Mul<f32> for Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Mat4::from_cols(
+            self.x_axis * rhs,
+            self.y_axis * rhs,
+            self.z_axis * rhs,
+            self.w_axis * rhs,
+        )
+    }
+}
+impl Mul<&f32> for Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: &f32) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<f32> for &Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&f32> for &Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: &f32) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// f32 * Mat4
+impl Mul<Mat4> for f32 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: Mat4) -> Self::Output {
+        rhs * self
+    }
+}
+impl Mul<&Mat4> for f32 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: &Mat4) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Mat4> for &f32 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: Mat4) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Mat4> for &f32 {
+    type Output = Mat4;
+    #[inline]
+    fn mul(self, rhs: &Mat4) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Mat4 *= f32
+impl MulAssign<f32> for Mat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&f32> for Mat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &f32) {
+        *self *= *rhs;
+    }
+}
+
+
+// -Mat4
+impl Neg for Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+impl Neg for &Mat4 {
+    type Output = Mat4;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        *self * -1.0
+    }
+}
+
+// Mat4[] indexes columns
+impl Index<usize> for Mat4 {
+    type Output = Vec4;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            3 => &self.w_axis,
+            _ => panic!("Cannot index into a Mat4 at i > 3"),
+        }
+    }
+}
+impl IndexMut<usize> for Mat4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            2 => &mut self.z_axis,
+            3 => &mut self.w_axis,
+            _ => panic!("Cannot index into a Mat4 at i > 3"),
+        }
+    }
+}
+
+
+impl From<[[f32; 4]; 4]> for Mat4 {
+    #[inline]
+    fn from(cols: [[f32; 4]; 4]) -> Mat4 {
+        Mat4::from_cols(cols[0].into(), cols[1].into(), cols[2].into(), cols[3].into())
+    }
+}
+impl From<Mat4> for [[f32; 4]; 4] {
+    #[inline]
+    fn from(m: Mat4) -> [[f32; 4]; 4] {
+        [m.x_axis.into(), m.y_axis.into(), m.z_axis.into(), m.w_axis.into()]
+    }
+}
+
+impl From<[f32; 16]> for Mat4 {
+    #[inline]
+    fn from(m: [f32; 16]) -> Mat4 {
+        Mat4::from_cols_array(m)
+    }
+}
+impl From<Mat4> for [f32; 16] {
+    #[inline]
+    fn from(m: Mat4) -> [f32; 16] {
+        m.to_cols_array()
+    }
+}
@@ -1,11 +1,43 @@
+use crate::{
+    Scalar,
+};
 
-pub(crate) trait F32Ext {
-    fn rsqrt(self) -> f32;
+
+/// Computes the sine and cosine of every angle in a slice
+/// Panics if angles, sin_out and cos_out differ in length
+#[inline]
+pub fn sincos_slice(angles: &[f32], sin_out: &mut [f32], cos_out: &mut [f32]) {
+    assert!(
+        angles.len() == sin_out.len() && angles.len() == cos_out.len(),
+        "sincos_slice requires slices of equal length",
+    );
+    for ((a, s), c) in angles.iter().zip(sin_out.iter_mut()).zip(cos_out.iter_mut()) {
+        (*s, *c) = a.sincos();
+    }
 }
 
-impl F32Ext for f32 {
-    #[inline]
-    fn rsqrt(self) -> f32 {
-        1.0 / self.sqrt()
+
+/// Evaluates the polynomial c[0] + c[1] * x + c[2] * x^2 + ... using Horner's method
+/// Returns 0 for an empty slice of coefficients
+#[inline]
+pub const fn poly_eval(x: f32, c: &[f32]) -> f32 {
+    let mut acc = 0.0;
+    let mut i = c.len();
+    while i > 0 {
+        i -= 1;
+        acc = acc * x + c[i];
     }
+    acc
+}
+
+/// Evaluates the quadratic c[0] + c[1] * x + c[2] * x^2 using Horner's method
+#[inline]
+pub const fn horner3(x: f32, c: [f32; 3]) -> f32 {
+    (c[2] * x + c[1]) * x + c[0]
+}
+
+/// Evaluates the quartic c[0] + c[1] * x + ... + c[4] * x^4 using Horner's method
+#[inline]
+pub const fn horner5(x: f32, c: [f32; 5]) -> f32 {
+    (((c[4] * x + c[3]) * x + c[2]) * x + c[1]) * x + c[0]
 }
@@ -0,0 +1,334 @@
+use crate::{
+    validate::validate_finite,
+    Dir3,
+    Mat3,
+    MathError,
+    Vec3,
+    Vec4,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    ops::{
+        Mul,
+        MulAssign,
+        Neg,
+    },
+};
+
+
+/// A rotation in 3-space stored as a unit quaternion xi + yj + zk + w
+/// q and -q are the same rotation
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quat {
+    /// The rotation by 0 radians
+    pub const IDENTITY: Quat = Quat::from_xyzw(0.0, 0.0, 0.0, 1.0);
+
+
+    /// Creates a quaternion from its components
+    /// Requires: the components should have length 1 to be a rotation
+    #[inline]
+    pub const fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Quat {
+        Quat { x, y, z, w }
+    }
+
+    /// Rotation by angle radians about axis, counter-clockwise when looking down the axis towards the origin
+    #[inline]
+    pub fn from_axis_angle(axis: Dir3, angle: f32) -> Quat {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        let v = axis.as_vec3() * sin;
+        Quat::from_xyzw(v.x, v.y, v.z, cos)
+    }
+
+    /// The rotation equivalent to a rotation matrix
+    /// Requires: m should be a pure rotation matrix, without scale or reflection
+    // Shepperd's method, pivots on the largest of w, x, y, z to stay accurate
+    #[inline]
+    pub fn from_mat3(m: Mat3) -> Quat {
+        let Mat3 { x_axis: c0, y_axis: c1, z_axis: c2 } = m;
+        let trace = c0.x + c1.y + c2.z;
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quat::from_xyzw((c1.z - c2.y) / s, (c2.x - c0.z) / s, (c0.y - c1.x) / s, 0.25 * s)
+        } else if c0.x > c1.y && c0.x > c2.z {
+            let s = (1.0 + c0.x - c1.y - c2.z).sqrt() * 2.0;
+            Quat::from_xyzw(0.25 * s, (c1.x + c0.y) / s, (c2.x + c0.z) / s, (c1.z - c2.y) / s)
+        } else if c1.y > c2.z {
+            let s = (1.0 + c1.y - c0.x - c2.z).sqrt() * 2.0;
+            Quat::from_xyzw((c1.x + c0.y) / s, 0.25 * s, (c2.y + c1.z) / s, (c2.x - c0.z) / s)
+        } else {
+            let s = (1.0 + c2.z - c0.x - c1.y).sqrt() * 2.0;
+            Quat::from_xyzw((c2.x + c0.z) / s, (c2.y + c1.z) / s, 0.25 * s, (c0.y - c1.x) / s)
+        };
+        q.normalize()
+    }
+
+    /// The equivalent rotation matrix
+    #[inline]
+    pub fn to_mat3(self) -> Mat3 {
+        let Quat { x, y, z, w } = self;
+        let (x2, y2, z2) = (x + x, y + y, z + z);
+        let (xx, yy, zz) = (x * x2, y * y2, z * z2);
+        let (xy, xz, yz) = (x * y2, x * z2, y * z2);
+        let (wx, wy, wz) = (w * x2, w * y2, w * z2);
+        Mat3::from_cols(
+            Vec3::new(1.0 - (yy + zz), xy + wz, xz - wy),
+            Vec3::new(xy - wz, 1.0 - (xx + zz), yz + wx),
+            Vec3::new(xz + wy, yz - wx, 1.0 - (xx + yy)),
+        )
+    }
+
+    /// The vector part x, y, z
+    #[inline]
+    pub const fn xyz(self) -> Vec3 {
+        Vec3 { x: self.x, y: self.y, z: self.z }
+    }
+
+    /// The opposite rotation
+    /// Requires: self should be normalized
+    #[inline]
+    pub const fn inverse(self) -> Quat {
+        Quat::from_xyzw(-self.x, -self.y, -self.z, self.w)
+    }
+
+    #[inline]
+    pub fn dot(self, rhs: Quat) -> f32 {
+        self.to_vec4().dot(rhs.to_vec4())
+    }
+
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.to_vec4().length()
+    }
+
+    /// Rescales to unit length, undoing drift from many compositions
+    /// Requires: self should not be of length zero
+    #[inline]
+    pub fn normalize(self) -> Quat {
+        Quat::from_vec4(self.to_vec4().normalize())
+    }
+
+    /// Checks if the length is within eps of 1, using the same rule as VecExt::is_normalized
+    #[inline]
+    pub fn is_normalized(self, eps: f32) -> bool {
+        self.to_vec4().is_normalized(eps)
+    }
+
+    /// Rotates towards rhs at a constant angular speed, taking the shorter way round
+    /// Requires: self and rhs should be normalized
+    #[inline]
+    pub fn slerp(self, rhs: Quat, t: f32) -> Quat {
+        let mut cos = self.dot(rhs);
+        // flip to the same hemisphere so the path is the short one
+        let rhs = if cos < 0.0 {
+            cos = -cos;
+            -rhs
+        } else {
+            rhs
+        };
+        let (a, b) = if cos > 0.9995 {
+            // nearly parallel, sin is too small to divide by so lerp instead
+            (1.0 - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin_recip = theta.sin().recip();
+            (((1.0 - t) * theta).sin() * sin_recip, (t * theta).sin() * sin_recip)
+        };
+        Quat::from_vec4(self.to_vec4() * a + rhs.to_vec4() * b).normalize()
+    }
+
+    #[inline]
+    pub const fn to_vec4(self) -> Vec4 {
+        Vec4 { x: self.x, y: self.y, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn from_vec4(v: Vec4) -> Quat {
+        Quat::from_xyzw(v.x, v.y, v.z, v.w)
+    }
+
+    /// Builds a quaternion from a slice of exactly 4 elements in x, y, z, w order
+    #[inline]
+    pub fn try_from_slice(slice: &[f32]) -> Result<Quat, MathError> {
+        Vec4::try_from_slice(slice).map(Quat::from_vec4)
+    }
+
+    /// Checks if every component is finite
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.to_vec4().is_finite()
+    }
+
+    /// Checks if self and rhs are within eps of the same rotation, treating q and -q as equal
+    #[inline]
+    pub fn approx_eq(self, rhs: Quat, eps: f32) -> bool {
+        self.to_vec4().approx_eq(rhs.to_vec4(), eps) || self.to_vec4().approx_eq(-rhs.to_vec4(), eps)
+    }
+}
+
+impl Default for Quat {
+    #[inline]
+    fn default() -> Quat {
+        Quat::IDENTITY
+    }
+}
+
+
+impl Debug for Quat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Quat")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .field(&self.w)
+            .finish()
+    }
+}
+impl Display for Quat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .entry(&self.z)
+            .entry(&self.w)
+            .finish()
+    }
+}
+
+
+// Quat * Quat
+impl Mul<Quat> for Quat {
+    type Output = Quat;
+    #[inline]
+    fn mul(self, rhs: Quat) -> Self::Output {
+        // the Hamilton product, applies rhs first
+        let (a, b) = (self, rhs);
+        let q = Quat::from_xyzw(
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+        );
+        validate_finite!("Quat * Quat", q);
+        q
+    }
+}
+impl Mul<&Quat> for Quat {
+    type Output = Quat;
+    #[inline]
+    fn mul(self, rhs: &Quat) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Quat> for &Quat {
+    type Output = Quat;
+    #[inline]
+    fn mul(self, rhs: Quat) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Quat> for &Quat {
+    type Output = Quat;
+    #[inline]
+    fn mul(self, rhs: &Quat) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Quat *= Quat
+impl MulAssign<Quat> for Quat {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Quat) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&Quat> for Quat {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Quat) {
+        *self *= *rhs;
+    }
+}
+
+// Quat * Vec3
+impl Mul<Vec3> for Quat {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        // v + 2w(q x v) + 2q x (q x v), cheaper than building the matrix
+        let q = self.xyz();
+        let t = q.cross(rhs) * 2.0;
+        rhs + t * self.w + q.cross(t)
+    }
+}
+impl Mul<&Vec3> for Quat {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: &Vec3) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Vec3> for &Quat {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Vec3> for &Quat {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: &Vec3) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+
+// -Quat, the same rotation
+impl Neg for Quat {
+    type Output = Quat;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Quat::from_xyzw(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+impl Neg for &Quat {
+    type Output = Quat;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+
+impl From<Quat> for Mat3 {
+    #[inline]
+    fn from(q: Quat) -> Mat3 {
+        q.to_mat3()
+    }
+}
+
+impl From<[f32; 4]> for Quat {
+    #[inline]
+    fn from(arr: [f32; 4]) -> Quat {
+        Quat::from_xyzw(arr[0], arr[1], arr[2], arr[3])
+    }
+}
+impl From<Quat> for [f32; 4] {
+    #[inline]
+    fn from(q: Quat) -> [f32; 4] {
+        [q.x, q.y, q.z, q.w]
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{
+    F32Ext,
+    Mat2,
+    Vec2,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    ops::{
+        Mul,
+        MulAssign,
+    },
+};
+
+
+/// A 2D rotation stored as a unit complex number cos + i sin
+/// Composing and applying rotations never calls sin or cos
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Rot2 {
+    pub cos: f32,
+    pub sin: f32,
+}
+
+impl Rot2 {
+    /// The rotation by 0 radians
+    pub const IDENTITY: Rot2 = Rot2 { cos: 1.0, sin: 0.0 };
+
+
+    /// Counter-clockwise rotation by angle radians
+    #[inline]
+    pub fn from_angle(angle: f32) -> Rot2 {
+        let (sin, cos) = angle.sincos();
+        Rot2 { cos, sin }
+    }
+
+    /// Creates a rotation from its sine and cosine
+    /// Requires: sin^2 + cos^2 should be 1
+    #[inline]
+    pub const fn from_sin_cos(sin: f32, cos: f32) -> Rot2 {
+        Rot2 { cos, sin }
+    }
+
+    /// The rotation taking the direction of from to the direction of to
+    /// Requires: neither from nor to should be of length zero
+    #[inline]
+    pub fn from_rotation_arc(from: Vec2, to: Vec2) -> Rot2 {
+        Rot2 {
+            cos: from.dot(to),
+            sin: from.perp_dot(to),
+        }.normalize()
+    }
+
+    /// The counter-clockwise angle in radians, in [-PI, PI]
+    #[inline]
+    pub fn angle(self) -> f32 {
+        self.sin.atan2(self.cos)
+    }
+
+    /// The opposite rotation, the complex conjugate
+    #[inline]
+    pub const fn inverse(self) -> Rot2 {
+        Rot2 {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    /// Rescales to unit length, undoing drift from many compositions
+    /// Requires: self should not be of length zero
+    #[inline]
+    pub fn normalize(self) -> Rot2 {
+        let len_recip = (self.cos * self.cos + self.sin * self.sin).rsqrt();
+        Rot2 {
+            cos: self.cos * len_recip,
+            sin: self.sin * len_recip,
+        }
+    }
+
+    /// Checks if the length of (cos, sin) is within eps of 1, using the same rule as VecExt::is_normalized
+    #[inline]
+    pub fn is_normalized(self, eps: f32) -> bool {
+        Vec2 { x: self.cos, y: self.sin }.is_normalized(eps)
+    }
+
+    /// Rotates towards rhs at a constant angular speed, taking the shorter way round
+    #[inline]
+    pub fn slerp(self, rhs: Rot2, t: f32) -> Rot2 {
+        let delta = (self.inverse() * rhs).angle();
+        self * Rot2::from_angle(delta * t)
+    }
+
+    /// The equivalent rotation matrix
+    #[inline]
+    pub const fn to_mat2(self) -> Mat2 {
+        Mat2::from_cols(
+            Vec2::new(self.cos, self.sin),
+            Vec2::new(-self.sin, self.cos),
+        )
+    }
+
+    /// Checks if both components are within eps of the components of rhs
+    #[inline]
+    pub fn approx_eq(self, rhs: Rot2, eps: f32) -> bool {
+        (self.cos - rhs.cos).abs() <= eps && (self.sin - rhs.sin).abs() <= eps
+    }
+}
+
+impl Default for Rot2 {
+    #[inline]
+    fn default() -> Rot2 {
+        Rot2::IDENTITY
+    }
+}
+
+
+impl Debug for Rot2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rot2")
+            .field("cos", &self.cos)
+            .field("sin", &self.sin)
+            .finish()
+    }
+}
+impl Display for Rot2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} rad", self.angle())
+    }
+}
+
+
+// Rot2 * Rot2
+impl Mul<Rot2> for Rot2 {
+    type Output = Rot2;
+    #[inline]
+    fn mul(self, rhs: Rot2) -> Self::Output {
+        // complex multiplication, adds the angles
+        Rot2 {
+            cos: self.cos * rhs.cos - self.sin * rhs.sin,
+            sin: self.sin * rhs.cos + self.cos * rhs.sin,
+        }
+    }
+}
+impl Mul<&Rot2> for Rot2 {
+    type Output = Rot2;
+    #[inline]
+    fn mul(self, rhs: &Rot2) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Rot2> for &Rot2 {
+    type Output = Rot2;
+    #[inline]
+    fn mul(self, rhs: Rot2) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Rot2> for &Rot2 {
+    type Output = Rot2;
+    #[inline]
+    fn mul(self, rhs: &Rot2) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Rot2 *= Rot2
+impl MulAssign<Rot2> for Rot2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Rot2) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&Rot2> for Rot2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Rot2) {
+        *self *= *rhs;
+    }
+}
+
+// Rot2 * Vec2
+impl Mul<Vec2> for Rot2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        Vec2::new(
+            self.cos * rhs.x - self.sin * rhs.y,
+            self.sin * rhs.x + self.cos * rhs.y,
+        )
+    }
+}
+impl Mul<&Vec2> for Rot2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &Vec2) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Vec2> for &Rot2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Vec2> for &Rot2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &Vec2) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+
+impl From<Rot2> for Mat2 {
+    #[inline]
+    fn from(r: Rot2) -> Mat2 {
+        r.to_mat2()
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{
+    Affine3,
+    Mat3,
+    Mat4,
+    Quat,
+    Vec3,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    ops::{
+        Mul,
+        MulAssign,
+    },
+};
+
+
+/// A scene graph transform that scales, then rotates, then translates
+/// parent * child gives the child's transform in the parent's space
+/// Non-uniform scale under a rotated child cannot be represented exactly, use Affine3 for that
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+impl Transform {
+    /// The identity transform, leaves points unchanged
+    pub const IDENTITY: Transform = Transform {
+        translation: Vec3::ZERO,
+        rotation: Quat::IDENTITY,
+        scale: Vec3::splat(1.0),
+    };
+
+
+    #[inline]
+    pub const fn from_translation(translation: Vec3) -> Transform {
+        Transform { translation, ..Transform::IDENTITY }
+    }
+
+    #[inline]
+    pub const fn from_rotation(rotation: Quat) -> Transform {
+        Transform { rotation, ..Transform::IDENTITY }
+    }
+
+    #[inline]
+    pub const fn from_scale(scale: Vec3) -> Transform {
+        Transform { scale, ..Transform::IDENTITY }
+    }
+
+    #[inline]
+    pub const fn from_scale_rotation_translation(scale: Vec3, rotation: Quat, translation: Vec3) -> Transform {
+        Transform { translation, rotation, scale }
+    }
+
+    /// Returns a copy of self with the translation replaced
+    #[inline]
+    pub const fn with_translation(mut self, translation: Vec3) -> Transform {
+        self.translation = translation;
+        self
+    }
+
+    /// Returns a copy of self with the rotation replaced
+    #[inline]
+    pub const fn with_rotation(mut self, rotation: Quat) -> Transform {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Returns a copy of self with the scale replaced
+    #[inline]
+    pub const fn with_scale(mut self, scale: Vec3) -> Transform {
+        self.scale = scale;
+        self
+    }
+
+    /// Decomposes an affine transform into scale, rotation and translation
+    /// A reflection is folded into a negative x scale
+    /// Requires: a should have no shear and no zero scale
+    #[inline]
+    pub fn from_affine3(a: Affine3) -> Transform {
+        let m = a.matrix3;
+        let sign = if m.determinant() < 0.0 { -1.0 } else { 1.0 };
+        let scale = Vec3::new(
+            m.x_axis.length() * sign,
+            m.y_axis.length(),
+            m.z_axis.length(),
+        );
+        let rotation = Mat3::from_cols(
+            m.x_axis / scale.x,
+            m.y_axis / scale.y,
+            m.z_axis / scale.z,
+        );
+        Transform::from_scale_rotation_translation(scale, Quat::from_mat3(rotation), a.translation)
+    }
+
+    /// Decomposes the affine part of m, ignoring its bottom row
+    /// Requires: m should have no shear and no zero scale
+    #[inline]
+    pub fn from_mat4(m: Mat4) -> Transform {
+        Transform::from_affine3(Affine3::from_mat4(m))
+    }
+
+    #[inline]
+    pub fn to_affine3(&self) -> Affine3 {
+        Affine3::from_scale_rotation_translation(self.scale, self.rotation.to_mat3(), self.translation)
+    }
+
+    #[inline]
+    pub fn to_mat4(&self) -> Mat4 {
+        self.to_affine3().to_mat4()
+    }
+
+    /// Transforms a point, applying scale, rotation and translation
+    #[inline]
+    pub fn transform_point(&self, p: Vec3) -> Vec3 {
+        self.rotation * (self.scale * p) + self.translation
+    }
+
+    /// Transforms a direction, ignoring translation
+    #[inline]
+    pub fn transform_vector(&self, v: Vec3) -> Vec3 {
+        self.rotation * (self.scale * v)
+    }
+
+    /// Returns the inverse transform
+    /// Only exact for uniform scale, non-uniform scale would need shear
+    /// Requires: no component of scale should be zero
+    #[inline]
+    pub fn inverse(&self) -> Transform {
+        let rotation = self.rotation.inverse();
+        let scale = self.scale.recip();
+        let translation = -(scale * (rotation * self.translation));
+        Transform { translation, rotation, scale }
+    }
+
+    /// Moves along a straight line from self to rhs, slerping the rotation
+    #[inline]
+    pub fn lerp(&self, rhs: &Transform, t: f32) -> Transform {
+        Transform {
+            translation: self.translation.lerp(rhs.translation, t),
+            rotation: self.rotation.slerp(rhs.rotation, t),
+            scale: self.scale.lerp(rhs.scale, t),
+        }
+    }
+
+    /// Checks if every component is finite
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.translation.is_finite() && self.rotation.is_finite() && self.scale.is_finite()
+    }
+
+    /// Checks if each part is within eps of the same part of rhs
+    #[inline]
+    pub fn approx_eq(&self, rhs: &Transform, eps: f32) -> bool {
+        self.translation.approx_eq(rhs.translation, eps)
+            && self.rotation.approx_eq(rhs.rotation, eps)
+            && self.scale.approx_eq(rhs.scale, eps)
+    }
+}
+
+impl Default for Transform {
+    #[inline]
+    fn default() -> Transform {
+        Transform::IDENTITY
+    }
+}
+
+
+impl Debug for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transform")
+            .field("translation", &self.translation)
+            .field("rotation", &self.rotation)
+            .field("scale", &self.scale)
+            .finish()
+    }
+}
+impl Display for Transform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "T{} R{} S{}", self.translation, self.rotation, self.scale)
+    }
+}
+
+
+// Transform * Transform
+impl Mul<Transform> for Transform {
+    type Output = Transform;
+    #[inline]
+    fn mul(self, rhs: Transform) -> Self::Output {
+        // exact unless self has non-uniform scale and rhs is rotated
+        Transform {
+            translation: self.transform_point(rhs.translation),
+            rotation: self.rotation * rhs.rotation,
+            scale: self.scale * rhs.scale,
+        }
+    }
+}
+impl Mul<&Transform> for Transform {
+    type Output = Transform;
+    #[inline]
+    fn mul(self, rhs: &Transform) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Transform> for &Transform {
+    type Output = Transform;
+    #[inline]
+    fn mul(self, rhs: Transform) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Transform> for &Transform {
+    type Output = Transform;
+    #[inline]
+    fn mul(self, rhs: &Transform) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Transform *= Transform
+impl MulAssign<Transform> for Transform {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Transform) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&Transform> for Transform {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Transform) {
+        *self *= *rhs;
+    }
+}
+
+
+impl From<Transform> for Affine3 {
+    #[inline]
+    fn from(t: Transform) -> Affine3 {
+        t.to_affine3()
+    }
+}
+impl From<Transform> for Mat4 {
+    #[inline]
+    fn from(t: Transform) -> Mat4 {
+        t.to_mat4()
+    }
+}
+impl From<Affine3> for Transform {
+    #[inline]
+    fn from(a: Affine3) -> Transform {
+        Transform::from_affine3(a)
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{
-    F32Ext,
+    validate::validate_finite,
+    MathError,
+    Scalar,
 };
 use std::{
     array::{
@@ -22,12 +24,14 @@ use std::{
 };
 
 // sum, min, min_vec, max, max_vec, abs, clamp, clamp_vec
-// recip, rem_euclid, rem_euclid_vec, div_euclid, div_euclid_vec
+// recip, rem_euclid, rem_euclid_vec, div_euclid, div_euclid_vec, map, zip_with
 // are all zero-cost abstractions compared to manually implementing them for each vector type of length N
 // The exact same assembly is generated in release mode
 // (given, but every other method is, or should be, zero-cost compared to manual impl as well)
 
-pub trait VecExt<const N: usize>:
+// Generic over the scalar type T so f32 and f64 vectors share these implementations
+// T defaults to f32, so VecExt<N> is the f32 vector trait
+pub trait VecExt<const N: usize, T: Scalar = f32>:
     Copy
     + Clone
     + Default
@@ -35,22 +39,55 @@ pub trait VecExt<const N: usize>:
     + Add<Output = Self>
     + Sub<Output = Self>
     + Mul<Output = Self>
-    + Mul<f32, Output = Self>
-    + Div<f32, Output = Self>
-    + Into<[f32; N]>
-    + From<[f32; N]>
-    + AsRef<[f32; N]>
-    + AsMut<[f32; N]>
+    + Mul<T, Output = Self>
+    + Div<T, Output = Self>
+    + Into<[T; N]>
+    + From<[T; N]>
+    + AsRef<[T; N]>
+    + AsMut<[T; N]>
 {
     #[inline]
-    fn to_array(self) -> [f32; N] {
+    fn to_array(self) -> [T; N] {
         self.into()
     }
 
+    /// Applies f to every component
     #[inline]
-    fn min(self, v: f32) -> Self {
+    fn map(self, f: impl Fn(T) -> T) -> Self {
         let a = self.to_array();
-        let res: [f32; N] = array::from_fn(|i| a[i].min(v));
+        let res = array::from_fn(|i| f(a[i]));
+        Self::from(res)
+    }
+
+    /// Applies f to every pair of corresponding components of self and rhs
+    #[inline]
+    fn zip_with(self, rhs: Self, f: impl Fn(T, T) -> T) -> Self {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        let res = array::from_fn(|i| f(a[i], b[i]));
+        Self::from(res)
+    }
+
+    /// Folds every component into an accumulator, starting from init
+    #[inline]
+    fn fold<A>(self, init: A, f: impl FnMut(A, T) -> A) -> A {
+        self.to_array().into_iter().fold(init, f)
+    }
+
+    /// Builds a vector from a slice of exactly N elements
+    #[inline]
+    fn try_from_slice(slice: &[T]) -> Result<Self, MathError> {
+        let arr: [T; N] = slice.try_into().map_err(|_| MathError::SliceLength {
+            expected: N,
+            found: slice.len(),
+        })?;
+        Ok(Self::from(arr))
+    }
+
+    #[inline]
+    fn min(self, v: T) -> Self {
+        let a = self.to_array();
+        let res: [T; N] = array::from_fn(|i| a[i].min(v));
         Self::from(res)
     }
 
@@ -58,14 +95,14 @@ pub trait VecExt<const N: usize>:
     fn min_vec(self, rhs: Self) -> Self {
         let a = self.to_array();
         let b = rhs.to_array();
-        let res: [f32; N] = array::from_fn(|i| a[i].min(b[i]));
+        let res: [T; N] = array::from_fn(|i| a[i].min(b[i]));
         Self::from(res)
     }
 
     #[inline]
-    fn max(self, v: f32) -> Self {
+    fn max(self, v: T) -> Self {
         let a = self.to_array();
-        let res: [f32; N] = array::from_fn(|i| a[i].max(v));
+        let res: [T; N] = array::from_fn(|i| a[i].max(v));
         Self::from(res)
     }
 
@@ -73,24 +110,328 @@ pub trait VecExt<const N: usize>:
     fn max_vec(self, rhs: Self) -> Self {
         let a = self.to_array();
         let b = rhs.to_array();
-        let res: [f32; N] = array::from_fn(|i| a[i].max(b[i]));
+        let res: [T; N] = array::from_fn(|i| a[i].max(b[i]));
         Self::from(res)
     }
 
+    /// Componentwise minimum according to total_cmp
     #[inline]
-    fn sum(self) -> f32 {
-        self.to_array().iter().sum()
+    fn total_min(self, rhs: Self) -> Self {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        let res = array::from_fn(|i| a[i].total_min(b[i]));
+        Self::from(res)
+    }
+
+    /// Componentwise maximum according to total_cmp
+    #[inline]
+    fn total_max(self, rhs: Self) -> Self {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        let res = array::from_fn(|i| a[i].total_max(b[i]));
+        Self::from(res)
+    }
+
+    /// Componentwise minimum propagating NaN
+    #[inline]
+    fn min_nan_propagating(self, rhs: Self) -> Self {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        let res = array::from_fn(|i| a[i].min_nan_propagating(b[i]));
+        Self::from(res)
+    }
+
+    /// Componentwise maximum propagating NaN
+    #[inline]
+    fn max_nan_propagating(self, rhs: Self) -> Self {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        let res = array::from_fn(|i| a[i].max_nan_propagating(b[i]));
+        Self::from(res)
+    }
+
+    /// Componentwise minimum ignoring NaN
+    #[inline]
+    fn min_nan_ignoring(self, rhs: Self) -> Self {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        let res = array::from_fn(|i| a[i].min_nan_ignoring(b[i]));
+        Self::from(res)
+    }
+
+    /// Componentwise maximum ignoring NaN
+    #[inline]
+    fn max_nan_ignoring(self, rhs: Self) -> Self {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        let res = array::from_fn(|i| a[i].max_nan_ignoring(b[i]));
+        Self::from(res)
+    }
+
+    /// Replaces any NaN component with the canonical quiet NAN
+    #[inline]
+    fn canonicalize_nan(self) -> Self {
+        let a = self.to_array();
+        let res = array::from_fn(|i| a[i].canonicalize_nan());
+        Self::from(res)
+    }
+
+    #[inline]
+    fn sum(self) -> T {
+        self.to_array().into_iter().sum()
+    }
+
+    /// The product of all components, e.g. the volume of an extent
+    #[inline]
+    fn product(self) -> T {
+        self.to_array().into_iter().product()
+    }
+
+    /// The smallest component
+    /// NaN components are ignored unless every component is NaN
+    #[inline]
+    fn min_element(self) -> T {
+        self.to_array().into_iter().reduce(T::min).unwrap()
+    }
+
+    /// The largest component
+    /// NaN components are ignored unless every component is NaN
+    #[inline]
+    fn max_element(self) -> T {
+        self.to_array().into_iter().reduce(T::max).unwrap()
+    }
+
+    /// The index of the smallest component, the first one on ties
+    /// Requires: no component should be NaN
+    #[inline]
+    fn min_position(self) -> usize {
+        let a = self.to_array();
+        (1..N).fold(0, |best, i| if a[i] < a[best] { i } else { best })
+    }
+
+    /// The index of the largest component, the first one on ties
+    /// Useful for picking the dominant axis of a normal
+    /// Requires: no component should be NaN
+    #[inline]
+    fn max_position(self) -> usize {
+        let a = self.to_array();
+        (1..N).fold(0, |best, i| if a[i] > a[best] { i } else { best })
     }
 
     #[inline]
     fn abs(self) -> Self {
         let a = self.to_array();
-        let res: [f32; N] = array::from_fn(|i| a[i].abs());
+        let res: [T; N] = array::from_fn(|i| a[i].abs());
         Self::from(res)
     }
 
+    /// Computes self * mul + add per component with a single rounding, via the fused multiply-add
+    /// More precise than the separate operations, and faster on targets with an FMA instruction
+    #[inline]
+    fn mul_add(self, mul: Self, add: Self) -> Self {
+        let a = self.to_array();
+        let m = mul.to_array();
+        let b = add.to_array();
+        let res = array::from_fn(|i| a[i].mul_add(m[i], b[i]));
+        Self::from(res)
+    }
+
+    /// Raises each component to the power n
+    #[inline]
+    fn powf(self, n: T) -> Self {
+        self.map(|c| c.powf(n))
+    }
+
+    /// Raises each component to the integer power n, faster than powf
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        self.map(|c| c.powi(n))
+    }
+
+    /// Componentwise square root, NaN for negative components
+    #[inline]
+    fn sqrt(self) -> Self {
+        self.map(T::sqrt)
+    }
+
+    /// Componentwise e^c
+    #[inline]
+    fn exp(self) -> Self {
+        self.map(T::exp)
+    }
+
+    /// Componentwise natural logarithm, NaN for negative components and -inf for 0
     #[inline]
-    fn clamp(self, min: f32, max: f32) -> Self {
+    fn ln(self) -> Self {
+        self.map(T::ln)
+    }
+
+    /// Componentwise sine of angles in radians
+    #[inline]
+    fn sin(self) -> Self {
+        self.map(T::sin)
+    }
+
+    /// Componentwise cosine of angles in radians
+    #[inline]
+    fn cos(self) -> Self {
+        self.map(T::cos)
+    }
+
+    /// Componentwise tangent of angles in radians
+    #[inline]
+    fn tan(self) -> Self {
+        self.map(T::tan)
+    }
+
+    /// Componentwise (sin, cos), computed together
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let a = self.to_array();
+        let pairs: [(T, T); N] = array::from_fn(|i| a[i].sin_cos());
+        (
+            Self::from(array::from_fn(|i| pairs[i].0)),
+            Self::from(array::from_fn(|i| pairs[i].1)),
+        )
+    }
+
+    /// Converts each component from radians to degrees
+    #[inline]
+    fn to_degrees(self) -> Self {
+        self.map(T::to_degrees)
+    }
+
+    /// Converts each component from degrees to radians
+    #[inline]
+    fn to_radians(self) -> Self {
+        self.map(T::to_radians)
+    }
+
+    #[inline]
+    fn abs_diff(self, rhs: Self) -> Self {
+        (self - rhs).abs()
+    }
+
+    /// Checks if every component is finite
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.to_array().iter().all(|c| c.is_finite())
+    }
+
+    /// Checks if any component is NaN
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.to_array().iter().any(|c| c.is_nan())
+    }
+
+    /// Checks if the length is within eps of 1
+    /// Compares the squared length, which is within about 2 * eps of 1 for the same vectors, to avoid a sqrt
+    #[inline]
+    fn is_normalized(self, eps: T) -> bool {
+        (self.length_2() - T::ONE).abs() <= eps * T::TWO
+    }
+
+    /// Checks if every component is within eps of the corresponding component of rhs
+    #[inline]
+    fn approx_eq(self, rhs: Self, eps: T) -> bool {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        (0..N).all(|i| a[i].approx_eq(b[i], eps))
+    }
+
+    /// The same as approx_eq, under the name used by other math crates
+    #[inline]
+    fn abs_diff_eq(self, rhs: Self, eps: T) -> bool {
+        self.approx_eq(rhs, eps)
+    }
+
+    /// Checks if every component is relative_eq to the corresponding component of rhs
+    #[inline]
+    fn relative_eq(self, rhs: Self, eps: T, max_relative: T) -> bool {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        (0..N).all(|i| a[i].relative_eq(b[i], eps, max_relative))
+    }
+
+    /// Checks if every component is at most ulps floats apart from the corresponding component of rhs
+    #[inline]
+    fn approx_eq_ulps(self, rhs: Self, ulps: u32) -> bool {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        (0..N).all(|i| a[i].approx_eq_ulps(b[i], ulps))
+    }
+
+    /// Divides self by den componentwise, using the fallback component wherever the quotient is not finite
+    #[inline]
+    fn safe_div(self, den: Self, fallback: Self) -> Self {
+        let a = self.to_array();
+        let b = den.to_array();
+        let c = fallback.to_array();
+        let res = array::from_fn(|i| a[i].safe_div(b[i], c[i]));
+        Self::from(res)
+    }
+
+    /// The mask of components of self equal to those of rhs
+    #[inline]
+    fn cmpeq(self, rhs: Self) -> [bool; N] {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        array::from_fn(|i| a[i] == b[i])
+    }
+
+    /// The mask of components of self not equal to those of rhs
+    #[inline]
+    fn cmpne(self, rhs: Self) -> [bool; N] {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        array::from_fn(|i| a[i] != b[i])
+    }
+
+    /// The mask of components of self less than those of rhs
+    #[inline]
+    fn cmplt(self, rhs: Self) -> [bool; N] {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        array::from_fn(|i| a[i] < b[i])
+    }
+
+    /// The mask of components of self less than or equal to those of rhs
+    #[inline]
+    fn cmple(self, rhs: Self) -> [bool; N] {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        array::from_fn(|i| a[i] <= b[i])
+    }
+
+    /// The mask of components of self greater than those of rhs
+    #[inline]
+    fn cmpgt(self, rhs: Self) -> [bool; N] {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        array::from_fn(|i| a[i] > b[i])
+    }
+
+    /// The mask of components of self greater than or equal to those of rhs
+    #[inline]
+    fn cmpge(self, rhs: Self) -> [bool; N] {
+        let a = self.to_array();
+        let b = rhs.to_array();
+        array::from_fn(|i| a[i] >= b[i])
+    }
+
+    /// Picks the component from if_true where mask is true, otherwise from if_false
+    /// mask is a [bool; N] or the matching BVec, like the cmp* methods return
+    #[inline]
+    fn select(mask: impl Into<[bool; N]>, if_true: Self, if_false: Self) -> Self {
+        let mask = mask.into();
+        let a = if_true.to_array();
+        let b = if_false.to_array();
+        let res = array::from_fn(|i| T::select(mask[i], a[i], b[i]));
+        Self::from(res)
+    }
+
+    #[inline]
+    fn clamp(self, min: T, max: T) -> Self {
         let a = self.to_array();
         let res = array::from_fn(|i| a[i].clamp(min, max));
         Self::from(res)
@@ -98,6 +439,29 @@ pub trait VecExt<const N: usize>:
         // self.max(min).min(max)
     }
 
+    /// Clamps every component to [0, 1]
+    #[inline]
+    fn saturate(self) -> Self {
+        self.clamp(T::ZERO, T::ONE)
+    }
+
+    /// GLSL step per component, 0 where self is below edge and 1 elsewhere
+    #[inline]
+    fn step(self, edge: Self) -> Self {
+        self.zip_with(edge, T::step)
+    }
+
+    /// GLSL smoothstep per component, see Scalar::smoothstep
+    /// Requires: edge0 != edge1 in every component
+    #[inline]
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+        let a = self.to_array();
+        let e0 = edge0.to_array();
+        let e1 = edge1.to_array();
+        let res = array::from_fn(|i| a[i].smoothstep(e0[i], e1[i]));
+        Self::from(res)
+    }
+
     #[inline]
     fn clamp_vec(self, min_vec: Self, max_vec: Self) -> Self {
         let a = self.to_array();
@@ -109,69 +473,191 @@ pub trait VecExt<const N: usize>:
     }
 
     #[inline]
-    fn dot(self, rhs: Self) -> f32 {
+    fn dot(self, rhs: Self) -> T {
         (self * rhs).sum()
     }
 
     #[inline]
     fn normalize(self) -> Self {
-        self * self.length_recip()
+        let n = self * self.length_recip();
+        validate_finite!("normalize", n);
+        n
+    }
+
+    /// Normalizes self, failing instead of producing NaN/inf for zero length or non-finite vectors
+    /// Finite vectors too long or short for length_2 to be represented still succeed
+    #[inline]
+    fn try_normalize(self) -> Result<Self, MathError> {
+        if !self.is_finite() {
+            return Err(MathError::NonFinite);
+        }
+        let largest = self.abs().max_element();
+        if largest == T::ZERO {
+            return Err(MathError::ZeroLength);
+        }
+        // dividing by the largest component first keeps length_2 in [1, N], so it can neither overflow nor underflow
+        let v = self / largest;
+        Ok(v / v.length())
     }
 
     #[inline]
-    fn normalize_and_length(self) -> (Self, f32) {
+    fn normalize_and_length(self) -> (Self, T) {
         let length = self.length();
-        (self / length, length)
+        let n = self / length;
+        validate_finite!("normalize_and_length", n);
+        (n, length)
     }
 
     #[inline]
-    fn length(self) -> f32 {
+    fn length(self) -> T {
         self.length_2().sqrt()
     }
 
     #[inline]
-    fn length_recip(self) -> f32 {
+    fn length_recip(self) -> T {
         self.length_2().rsqrt()
     }
 
     #[inline]
-    fn length_2(self) -> f32 {
+    fn length_2(self) -> T {
         self.dot(self)
     }
 
     #[inline]
-    fn length_2_recip(self) -> f32 {
+    fn length_2_recip(self) -> T {
         self.dot(self).recip()
     }
 
     #[inline]
-    fn distance(self, rhs: Self) -> f32 {
+    fn distance(self, rhs: Self) -> T {
         (self - rhs).length()
     }
 
     #[inline]
-    fn distance_recip(self, rhs: Self) -> f32 {
+    fn distance_recip(self, rhs: Self) -> T {
         (self - rhs).length_recip()
     }
 
     #[inline]
-    fn distance_2(self, rhs: Self) -> f32 {
+    fn distance_2(self, rhs: Self) -> T {
         (self - rhs).length_2()
     }
 
     #[inline]
-    fn distance_2_recip(self, rhs: Self) -> f32 {
+    fn distance_2_recip(self, rhs: Self) -> T {
         (self - rhs).length_2_recip()
     }
 
     #[inline]
-    fn lerp(self, rhs: Self, t: f32) -> Self {
-        self * (1.0 - t) + rhs * t
+    fn lerp(self, rhs: Self, t: T) -> Self {
+        self * (T::ONE - t) + rhs * t
+    }
+
+    /// Lerps with t clamped to [0, 1], so the result never leaves the segment
+    #[inline]
+    fn lerp_clamped(self, rhs: Self, t: T) -> Self {
+        self.lerp(rhs, t.saturate())
+    }
+
+    /// Lerp that is exact at both ends and monotone in t
+    /// Measures from whichever endpoint is closer, at the cost of a branch
+    #[inline]
+    fn lerp_exact(self, rhs: Self, t: T) -> Self {
+        let d = rhs - self;
+        if t < T::HALF {
+            self + d * t
+        } else {
+            rhs - d * (T::ONE - t)
+        }
+    }
+
+    /// Componentwise inverse of lerp, see Scalar::inverse_lerp
+    /// Requires: a != b in every component
+    #[inline]
+    fn inverse_lerp(self, a: Self, b: Self) -> Self {
+        let v = self.to_array();
+        let a = a.to_array();
+        let b = b.to_array();
+        let res = array::from_fn(|i| v[i].inverse_lerp(a[i], b[i]));
+        Self::from(res)
+    }
+
+    /// Componentwise maps self from [in_min, in_max] to [out_min, out_max], without clamping
+    /// Requires: in_min != in_max in every component
+    #[inline]
+    fn remap(self, in_min: Self, in_max: Self, out_min: Self, out_max: Self) -> Self {
+        out_min + (out_max - out_min) * self.inverse_lerp(in_min, in_max)
+    }
+
+    /// Moves self towards target by an amount that is independent of the frame rate
+    /// Equivalent to lerping with t = 1 - e^(-rate * dt)
+    #[inline]
+    fn exp_decay(self, target: Self, rate: T, dt: T) -> Self {
+        self.lerp(target, T::ONE - (-rate * dt).exp())
+    }
+
+    /// Moves self towards target like a critically damped spring, see Scalar::smooth_damp
+    /// velocity carries between calls, start it at zero and keep it alongside the vector
+    #[inline]
+    fn smooth_damp(self, target: Self, velocity: &mut Self, smooth_time: T, dt: T) -> Self {
+        let (omega, decay) = T::smooth_damp_factors(smooth_time, dt);
+        let change = self - target;
+        let temp = (*velocity + change * omega) * dt;
+        *velocity = (*velocity - temp * omega) * decay;
+        let out = target + (change + temp) * decay;
+        // Passed the target, so snap to it and stop
+        if (target - self).dot(out - target) > T::ZERO {
+            *velocity = Self::default();
+            return target;
+        }
+        out
     }
 
     #[inline]
     fn midpoint(self, rhs: Self) -> Self {
-        (self + rhs) * 0.5
+        (self + rhs) * T::HALF
+    }
+
+    /// The average of points, or None if there are none
+    /// Keeps a running mean rather than summing first, so large clouds far from the origin don't lose precision
+    #[inline]
+    fn centroid(points: &[Self]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let mut mean = *first;
+        for (i, p) in rest.iter().enumerate() {
+            mean = mean + (*p - mean) / T::from_i32(i as i32 + 2);
+        }
+        Some(mean)
+    }
+
+    /// The average of points with each scaled by the matching weight
+    /// Weights may be negative, as long as they do not sum to 0
+    /// Errors with SliceLength if the slices differ in length, and Degenerate if the weights sum to 0
+    // Neumaier's compensated sums rather than a running mean like centroid's,
+    // which would divide by partial sums that can pass through 0 with negative weights
+    #[inline]
+    fn weighted_average(points: &[Self], weights: &[T]) -> Result<Self, MathError> {
+        if points.len() != weights.len() {
+            return Err(MathError::SliceLength {
+                expected: points.len(),
+                found: weights.len(),
+            });
+        }
+        let mut sum = [T::ZERO; N];
+        let mut sum_comp = [T::ZERO; N];
+        let mut total = T::ZERO;
+        let mut total_comp = T::ZERO;
+        for (p, &w) in points.iter().zip(weights) {
+            for ((s, c), x) in sum.iter_mut().zip(&mut sum_comp).zip(p.to_array()) {
+                compensated_add(s, c, x * w);
+            }
+            compensated_add(&mut total, &mut total_comp, w);
+        }
+        let total = total + total_comp;
+        if total == T::ZERO {
+            return Err(MathError::Degenerate);
+        }
+        Ok(Self::from(array::from_fn(|i| (sum[i] + sum_comp[i]) / total)))
     }
 
     #[inline]
@@ -182,21 +668,21 @@ pub trait VecExt<const N: usize>:
     }
 
     #[inline]
-    fn rem_euclid(self, v: f32) -> Self {
+    fn rem_euclid(self, v: T) -> Self {
         let a = self.to_array();
         // keep the following array even though b can just be used itself
         // this generates better assembly, unrolls the loop
-        // accessing two const arrays of the same size in the loop rather than one const array and an f32
-        let b = [v, v, v];
+        // accessing two const arrays of the same size in the loop rather than one const array and a scalar
+        let b = [v; N];
         let res = array::from_fn(|i| a[i].rem_euclid(b[i]));
         Self::from(res)
     }
 
     #[inline]
-    fn div_euclid(self, v: f32) -> Self {
+    fn div_euclid(self, v: T) -> Self {
         let a = self.to_array();
         // keep this array, look under rem_euclid for why
-        let b = [v, v, v];
+        let b = [v; N];
         let res = array::from_fn(|i| a[i].div_euclid(b[i]));
         Self::from(res)
     }
@@ -218,18 +704,44 @@ pub trait VecExt<const N: usize>:
     }
 
 
+    /// Returns the component at index, or None if index >= N
+    #[inline]
+    fn get(&self, index: usize) -> Option<&T> {
+        self.as_ref().get(index)
+    }
+
+    /// Returns the component at index mutably, or None if index >= N
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut().get_mut(index)
+    }
+
+
     #[inline]
-    fn into_iter(self) -> IntoIter<f32, N> {
+    fn into_iter(self) -> IntoIter<T, N> {
         self.to_array().into_iter()
     }
 
     #[inline]
-    fn iter(&self) -> Iter<'_, f32> {
+    fn iter(&self) -> Iter<'_, T> {
         self.as_ref().iter()
     }
 
     #[inline]
-    fn iter_mut(&mut self) -> IterMut<'_, f32> {
+    fn iter_mut(&mut self) -> IterMut<'_, T> {
         self.as_mut().iter_mut()
     }
 }
+
+
+// One step of Neumaier's compensated summation, comp collects the low bits lost when adding x to sum
+#[inline]
+fn compensated_add<T: Scalar>(sum: &mut T, comp: &mut T, x: T) {
+    let t = *sum + x;
+    if sum.abs() >= x.abs() {
+        *comp += (*sum - t) + x;
+    } else {
+        *comp += (x - t) + *sum;
+    }
+    *sum = t;
+}
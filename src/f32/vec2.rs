@@ -1,45 +1,1250 @@
+use crate::{
+    validate::{
+        nan_guard,
+        validate_finite,
+    },
+    Axis,
+    BVec2,
+    Dir2,
+    f32::consts::PI,
+    F32Ext,
+    Rot2,
+    Vec3,
+    VecExt,
+};
+use std::{
+    slice::{
+        Iter,
+        IterMut,
+    },
+    iter::{
+        Sum,
+        Product,
+    },
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+    cmp::{
+        Ordering,
+    },
+    ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+        Mul,
+        MulAssign,
+        Div,
+        DivAssign,
+        Rem,
+        RemAssign,
+        Neg,
+        Index,
+        IndexMut,
+    },
+};
+
+
 /// A vector in 2-space
 #[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
 }
 
+impl VecExt<2> for Vec2 {}
+
 impl Vec2 {
-    /// The default Vec3 with all zeros
-    pub const ZERO: Self = Self::splat(0.0);
+    /// The default Vec2 with all 0's
+    pub const ZERO: Vec2 = Vec2::splat(0.0);
+
+    /// All 1's
+    pub const ONE: Vec2 = Vec2::splat(1.0);
+
+    /// All -1's
+    pub const NEG_ONE: Vec2 = Vec2::splat(-1.0);
+
+    /// All f32::MIN, the most negative finite value
+    pub const MIN: Vec2 = Vec2::splat(f32::MIN);
+
+    /// All f32::MAX, the largest finite value
+    pub const MAX: Vec2 = Vec2::splat(f32::MAX);
+
+    /// All positive infinity, handy for seeding a running minimum
+    pub const INFINITY: Vec2 = Vec2 { x: f32::INFINITY, y: f32::INFINITY };
+
+    /// All negative infinity, handy for seeding a running maximum
+    pub const NEG_INFINITY: Vec2 = Vec2 { x: f32::NEG_INFINITY, y: f32::NEG_INFINITY };
+
+    /// All NaN
+    /// Built directly so it is available even with the validate feature, which would reject it in new
+    pub const NAN: Vec2 = Vec2 { x: f32::NAN, y: f32::NAN };
 
     /// The positive x-axis basis vector
-    pub const X: Self = Self::new(1.0, 0.0);
+    pub const X: Vec2 = Vec2::new(1.0, 0.0);
 
     /// The positive y-axis basis vector
-    pub const Y: Self = Self::new(0.0, 1.0);
+    pub const Y: Vec2 = Vec2::new(0.0, 1.0);
 
     /// The negative x-axis basis vector
-    pub const NEG_X: Self = Self::new(-1.0, 0.0);
+    pub const NEG_X: Vec2 = Vec2::new(-1.0, 0.0);
 
     /// The negative y-axis basis vector
-    pub const NEG_Y: Self = Self::new(0.0, -1.0);
+    pub const NEG_Y: Vec2 = Vec2::new(0.0, -1.0);
+
+    /// The positive basis vectors, in axis order
+    pub const AXES: [Vec2; 2] = [Vec2::X, Vec2::Y];
+
+
+    /// Standard constructor for <x y>
+    #[inline]
+    pub const fn new(x: f32, y: f32) -> Vec2 {
+        validate_finite!("Vec2::new", x, y);
+        Vec2 { x, y }
+    }
+
+    #[inline]
+    pub const fn splat(v: f32) -> Vec2 {
+        Vec2::new(v, v)
+    }
+
+    /// Returns a copy of self with the x component replaced
+    #[inline]
+    pub const fn with_x(mut self, x: f32) -> Vec2 {
+        self.x = x;
+        self
+    }
+
+    /// Returns a copy of self with the y component replaced
+    #[inline]
+    pub const fn with_y(mut self, y: f32) -> Vec2 {
+        self.y = y;
+        self
+    }
+
+    /// Appends z to make a Vec3
+    #[inline]
+    pub const fn extend(self, z: f32) -> Vec3 {
+        Vec3::new(self.x, self.y, z)
+    }
+
+    /// Componentwise self + rhs, usable in const contexts
+    #[inline]
+    pub const fn const_add(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+
+    /// Componentwise self - rhs, usable in const contexts
+    #[inline]
+    pub const fn const_sub(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+
+    /// self * rhs, usable in const contexts
+    #[inline]
+    pub const fn const_mul(self, rhs: f32) -> Vec2 {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+
+    /// Componentwise self * rhs, usable in const contexts
+    #[inline]
+    pub const fn const_mul_vec(self, rhs: Vec2) -> Vec2 {
+        Vec2::new(self.x * rhs.x, self.y * rhs.y)
+    }
+
+    /// -self, usable in const contexts
+    #[inline]
+    pub const fn const_neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+
+    /// Dot product, usable in const contexts
+    #[inline]
+    pub const fn const_dot(self, rhs: Vec2) -> f32 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// self rotated 90 degrees counter-clockwise, <-y x>
+    #[inline]
+    pub const fn perp(self) -> Vec2 {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// The 2D cross product, self.perp().dot(rhs)
+    /// Positive when rhs is counter-clockwise from self, and twice the signed area of the triangle they span
+    #[inline]
+    pub fn perp_dot(self, rhs: Vec2) -> f32 {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// Returns the positive basis vector along axis
+    /// Panics if axis is not one of the 2 axes of a Vec2
+    #[inline]
+    pub fn axis(axis: Axis) -> Vec2 {
+        let mut v = Vec2::ZERO;
+        v[axis] = 1.0;
+        v
+    }
+
+    /// Returns the component along axis, the same as self[axis]
+    /// Panics if axis is not one of the 2 axes of a Vec2
+    #[inline]
+    pub fn get_axis(self, axis: Axis) -> f32 {
+        self[axis]
+    }
+
+    /// Sets the component along axis, the same as self[axis] = v
+    /// Panics if axis is not one of the 2 axes of a Vec2
+    #[inline]
+    pub fn set_axis(&mut self, axis: Axis, v: f32) {
+        self[axis] = v;
+    }
+
+    /// The axis of the component with the largest magnitude, the first one on ties
+    /// Picks the projection plane for a normal, or the longest side to split a box along
+    /// Requires: no component should be NaN
+    #[inline]
+    pub fn dominant_axis(self) -> Axis {
+        Axis::XYZW[self.abs().max_position()]
+    }
+
+    /// The mask of components of self equal to those of rhs
+    #[inline]
+    pub fn cmpeq(self, rhs: Vec2) -> BVec2 {
+        BVec2::from(VecExt::cmpeq(self, rhs))
+    }
+
+    /// The mask of components of self not equal to those of rhs
+    #[inline]
+    pub fn cmpne(self, rhs: Vec2) -> BVec2 {
+        BVec2::from(VecExt::cmpne(self, rhs))
+    }
+
+    /// The mask of components of self less than those of rhs
+    #[inline]
+    pub fn cmplt(self, rhs: Vec2) -> BVec2 {
+        BVec2::from(VecExt::cmplt(self, rhs))
+    }
+
+    /// The mask of components of self less than or equal to those of rhs
+    #[inline]
+    pub fn cmple(self, rhs: Vec2) -> BVec2 {
+        BVec2::from(VecExt::cmple(self, rhs))
+    }
+
+    /// The mask of components of self greater than those of rhs
+    #[inline]
+    pub fn cmpgt(self, rhs: Vec2) -> BVec2 {
+        BVec2::from(VecExt::cmpgt(self, rhs))
+    }
+
+    /// The mask of components of self greater than or equal to those of rhs
+    #[inline]
+    pub fn cmpge(self, rhs: Vec2) -> BVec2 {
+        BVec2::from(VecExt::cmpge(self, rhs))
+    }
+
+    /// Picks the component from if_true where mask is true, otherwise from if_false, like a shader's select
+    #[inline]
+    pub fn select(mask: BVec2, if_true: Vec2, if_false: Vec2) -> Vec2 {
+        <Vec2 as VecExt<2>>::select(mask, if_true, if_false)
+    }
+
+    #[inline]
+    pub fn from_array(arr: [f32; 2]) -> Vec2 {
+        Vec2::new(arr[0], arr[1])
+    }
+
+    /// Creates a unit Vec2 pointing at an angle in radians counter-clockwise from the positive x-axis
+    #[inline]
+    pub fn from_angle(angle: f32) -> Vec2 {
+        let (sin_a, cos_a) = angle.sincos();
+        Vec2::new(cos_a, sin_a)
+    }
+
+    /// The angle in radians counter-clockwise from the positive x-axis, the inverse of from_angle
+    /// In (-pi, pi], and 0 for the zero vector
+    #[inline]
+    pub fn to_angle(self) -> f32 {
+        self.y.atan2(self.x)
+    }
+
+    /// Computes the direction of a ray reflected off the normal of a surface
+    #[inline]
+    pub fn reflect(self, normal: Dir2) -> Vec2 {
+        self - 2.0 * normal * self.dot(*normal)
+    }
+
+    /// Returns the direction vector of a ray refracted to the surface normal
+    /// Returns zero on total internal reflection
+    /// Requires: self should be normalized
+    // https://en.wikipedia.org/wiki/Snell's_law
+    #[inline]
+    pub fn refract(self, normal: Dir2, r: f32) -> Vec2 {
+        let cos_a1 = -normal.dot(self);
+        let cos_a2_2 = 1.0 - r * r * (1.0 - cos_a1 * cos_a1);
+        if cos_a2_2 >= 0.0 {
+            r * self + (r * cos_a1 - cos_a2_2.sqrt()) * normal
+        } else {
+            Vec2::ZERO
+        }
+    }
+
+    /// Returns cos of the positive angle between two Vec2s
+    /// Requires: neither self nor rhs should be of length zero
+    #[inline]
+    pub fn cos_angle_between(self, rhs: Vec2) -> f32 {
+        let numerator = self.dot(rhs);
+        let denominator = (self.length_2() * rhs.length_2()).sqrt();
+        // rounding can push nearly parallel vectors slightly past 1
+        (numerator / denominator).clamp(-1.0, 1.0)
+    }
+
+    /// Returns the positive angle between two Vec2s, in [0, pi]
+    /// Use signed_angle_between for which way round it is
+    /// Requires: neither self nor rhs should be of length zero
+    #[inline]
+    pub fn angle_between(self, rhs: Vec2) -> f32 {
+        self.cos_angle_between(rhs).acos_clamped()
+    }
+
+    /// The angle in radians to rotate self counter-clockwise by to point along rhs
+    /// In (-pi, pi], opposite vectors give pi
+    /// Requires: neither self nor rhs should be of length zero
+    #[inline]
+    pub fn signed_angle_between(self, rhs: Vec2) -> f32 {
+        let angle = self.perp_dot(rhs).atan2(self.dot(rhs));
+        if angle == -PI { PI } else { angle }
+    }
+
+    /// Rotates counter-clockwise by angle radians
+    /// Prefer rotate_by with a stored Rot2 when rotating many vectors by the same angle
+    #[inline]
+    pub fn rotate(self, angle: f32) -> Vec2 {
+        Rot2::from_angle(angle) * self
+    }
+
+    /// Rotates counter-clockwise by angle radians about pivot, for orbiting a point
+    #[inline]
+    pub fn rotate_around(self, pivot: Vec2, angle: f32) -> Vec2 {
+        pivot + (self - pivot).rotate(angle)
+    }
+
+    /// Rotates by r, without calling sin or cos
+    #[inline]
+    pub fn rotate_by(self, r: Rot2) -> Vec2 {
+        r * self
+    }
+
+    /// Rotates the direction self towards target by at most max_angle radians, taking the shorter way round
+    /// Returns target once it is within max_angle
+    /// Requires: self and target should be normalized, max_angle should be non-negative
+    #[inline]
+    pub fn rotate_towards(self, target: Vec2, max_angle: f32) -> Vec2 {
+        let angle = self.signed_angle_between(target);
+        if angle.abs() <= max_angle {
+            target
+        } else {
+            self.rotate(max_angle.copysign(angle))
+        }
+    }
+
+    /// Creates a Vec2 from polar coordinates
+    /// angle is in radians counter-clockwise from +x
+    #[inline]
+    pub fn from_polar(r: f32, angle: f32) -> Vec2 {
+        Vec2::from_angle(angle) * r
+    }
+
+    /// Returns the polar coordinates (r, angle) of self, the inverse of from_polar
+    /// angle is in (-pi, pi], and is 0 for the zero vector
+    #[inline]
+    pub fn to_polar(self) -> (f32, f32) {
+        (self.length(), self.to_angle())
+    }
+}
+
+
+impl Debug for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Vec2")
+            .field(&self.x)
+            .field(&self.y)
+            .finish()
+    }
+}
+impl Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .finish()
+    }
+}
+
+
+// Vec2 cmp Vec2
+impl PartialOrd for Vec2 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.length_2().partial_cmp(&other.length_2())
+    }
+}
+
+
+// Vec2 + Vec2
+impl Add<Vec2> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: Vec2) -> Self::Output {
+        let out = Vec2::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+        );
+        nan_guard!("Vec2 + Vec2", out, self, rhs);
+        out
+    }
+}
+impl Add<&Vec2> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: &Vec2) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<Vec2> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: Vec2) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&Vec2> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: &Vec2) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// Vec2 + f32
+impl Add<f32> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: f32) -> Self::Output {
+        let out = Vec2::new(
+            self.x + rhs,
+            self.y + rhs,
+        );
+        nan_guard!("Vec2 + f32", out, self, rhs);
+        out
+    }
+}
+impl Add<&f32> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: &f32) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<f32> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: f32) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&f32> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: &f32) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// f32 + Vec2
+impl Add<Vec2> for f32 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: Vec2) -> Self::Output {
+        let out = Vec2::new(
+            self + rhs.x,
+            self + rhs.y,
+        );
+        nan_guard!("f32 + Vec2", out, self, rhs);
+        out
+    }
+}
+impl Add<&Vec2> for f32 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: &Vec2) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<Vec2> for &f32 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: Vec2) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&Vec2> for &f32 {
+    type Output = Vec2;
+    #[inline]
+    fn add(self, rhs: &Vec2) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// Vec2 += Vec2
+impl AddAssign<Vec2> for Vec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+impl AddAssign<&Vec2> for Vec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &Vec2) {
+        *self += *rhs;
+    }
+}
+
+// Vec2 += f32
+impl AddAssign<f32> for Vec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: f32) {
+        *self = *self + rhs;
+    }
+}
+impl AddAssign<&f32> for Vec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &f32) {
+        *self += *rhs;
+    }
+}
 
 
+// Vec2 - Vec2
+impl Sub<Vec2> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        let out = Vec2::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+        );
+        nan_guard!("Vec2 - Vec2", out, self, rhs);
+        out
+    }
+}
+impl Sub<&Vec2> for Vec2 {
+    type Output = Vec2;
     #[inline]
-    pub fn to_array(self) -> [f32; 2] {
-        [self.x, self.y]
+    fn sub(self, rhs: &Vec2) -> Self::Output {
+        self - *rhs
     }
+}
+impl Sub<Vec2> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&Vec2> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn sub(self, rhs: &Vec2) -> Self::Output {
+        *self - *rhs
+    }
+}
 
+// Vec2 - f32
+impl Sub<f32> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn sub(self, rhs: f32) -> Self::Output {
+        let out = Vec2::new(
+            self.x - rhs,
+            self.y - rhs,
+        );
+        nan_guard!("Vec2 - f32", out, self, rhs);
+        out
+    }
+}
+impl Sub<&f32> for Vec2 {
+    type Output = Vec2;
     #[inline]
-    pub fn from_array(arr: [f32; 2]) -> Self {
-        Self::new(arr[0], arr[1])
+    fn sub(self, rhs: &f32) -> Self::Output {
+        self - *rhs
     }
+}
+impl Sub<f32> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn sub(self, rhs: f32) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&f32> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn sub(self, rhs: &f32) -> Self::Output {
+        *self - *rhs
+    }
+}
 
+// f32 - Vec2
+impl Sub<Vec2> for f32 {
+    type Output = Vec2;
     #[inline]
-    pub const fn new(x: f32, y: f32) -> Self {
-        Self { x, y }
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        let out = Vec2::new(
+            self - rhs.x,
+            self - rhs.y,
+        );
+        nan_guard!("f32 - Vec2", out, self, rhs);
+        out
     }
+}
+impl Sub<&Vec2> for f32 {
+    type Output = Vec2;
+    #[inline]
+    fn sub(self, rhs: &Vec2) -> Self::Output {
+        self - *rhs
+    }
+}
+impl Sub<Vec2> for &f32 {
+    type Output = Vec2;
+    #[inline]
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&Vec2> for &f32 {
+    type Output = Vec2;
+    #[inline]
+    fn sub(self, rhs: &Vec2) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+// Vec2 -= Vec2
+impl SubAssign<Vec2> for Vec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&Vec2> for Vec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &Vec2) {
+        *self -= *rhs;
+    }
+}
+
+// Vec2 -= f32
+impl SubAssign<f32> for Vec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: f32) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&f32> for Vec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &f32) {
+        *self -= *rhs;
+    }
+}
+
+
+// Vec2 * Vec2
+impl Mul<Vec2> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        let out = Vec2::new(
+            self.x * rhs.x,
+            self.y * rhs.y,
+        );
+        nan_guard!("Vec2 * Vec2", out, self, rhs);
+        out
+    }
+}
+impl Mul<&Vec2> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &Vec2) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Vec2> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Vec2> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &Vec2) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Vec2 * f32
+impl Mul<f32> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        let out = Vec2::new(
+            self.x * rhs,
+            self.y * rhs,
+        );
+        nan_guard!("Vec2 * f32", out, self, rhs);
+        out
+    }
+}
+impl Mul<&f32> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &f32) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<f32> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&f32> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &f32) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// f32 * Vec2
+impl Mul<Vec2> for f32 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        let out = Vec2::new(
+            self * rhs.x,
+            self * rhs.y,
+        );
+        nan_guard!("f32 * Vec2", out, self, rhs);
+        out
+    }
+}
+impl Mul<&Vec2> for f32 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &Vec2) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<Vec2> for &f32 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&Vec2> for &f32 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: &Vec2) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// Vec2 *= Vec2
+impl MulAssign<Vec2> for Vec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Vec2) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&Vec2> for Vec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Vec2) {
+        *self *= *rhs;
+    }
+}
+
+// Vec2 *= f32
+impl MulAssign<f32> for Vec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&f32> for Vec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &f32) {
+        *self *= *rhs;
+    }
+}
+
+
+// Vec2 / Vec2
+impl Div<Vec2> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: Vec2) -> Self::Output {
+        let out = Vec2::new(
+            self.x / rhs.x,
+            self.y / rhs.y,
+        );
+        nan_guard!("Vec2 / Vec2", out, self, rhs);
+        out
+    }
+}
+impl Div<&Vec2> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: &Vec2) -> Self::Output {
+        self / *rhs
+    }
+}
+impl Div<Vec2> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: Vec2) -> Self::Output {
+        *self / rhs
+    }
+}
+impl Div<&Vec2> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: &Vec2) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+// Vec2 / f32
+impl Div<f32> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: f32) -> Self::Output {
+        let out = Vec2::new(
+            self.x / rhs,
+            self.y / rhs,
+        );
+        nan_guard!("Vec2 / f32", out, self, rhs);
+        out
+    }
+}
+impl Div<&f32> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: &f32) -> Self::Output {
+        self / *rhs
+    }
+}
+impl Div<f32> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: f32) -> Self::Output {
+        *self / rhs
+    }
+}
+impl Div<&f32> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: &f32) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+// f32 / Vec2
+impl Div<Vec2> for f32 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: Vec2) -> Self::Output {
+        let out = Vec2::new(
+            self / rhs.x,
+            self / rhs.y,
+        );
+        nan_guard!("f32 / Vec2", out, self, rhs);
+        out
+    }
+}
+impl Div<&Vec2> for f32 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: &Vec2) -> Self::Output {
+        self / *rhs
+    }
+}
+impl Div<Vec2> for &f32 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: Vec2) -> Self::Output {
+        *self / rhs
+    }
+}
+impl Div<&Vec2> for &f32 {
+    type Output = Vec2;
+    #[inline]
+    fn div(self, rhs: &Vec2) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+// Vec2 /= Vec2
+impl DivAssign<Vec2> for Vec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Vec2) {
+        *self = *self / rhs;
+    }
+}
+impl DivAssign<&Vec2> for Vec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &Vec2) {
+        *self /= *rhs;
+    }
+}
+
+// Vec2 /= f32
+impl DivAssign<f32> for Vec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+impl DivAssign<&f32> for Vec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &f32) {
+        *self /= *rhs;
+    }
+}
+
+
+// Vec2 % Vec2
+impl Rem<Vec2> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: Vec2) -> Self::Output {
+        let out = Vec2::new(
+            self.x % rhs.x,
+            self.y % rhs.y,
+        );
+        nan_guard!("Vec2 % Vec2", out, self, rhs);
+        out
+    }
+}
+impl Rem<&Vec2> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: &Vec2) -> Self::Output {
+        self % *rhs
+    }
+}
+impl Rem<Vec2> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: Vec2) -> Self::Output {
+        *self % rhs
+    }
+}
+impl Rem<&Vec2> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: &Vec2) -> Self::Output {
+        *self % *rhs
+    }
+}
+
+// Vec2 % f32
+impl Rem<f32> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: f32) -> Self::Output {
+        let out = Vec2::new(
+            self.x % rhs,
+            self.y % rhs,
+        );
+        nan_guard!("Vec2 % f32", out, self, rhs);
+        out
+    }
+}
+impl Rem<&f32> for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: &f32) -> Self::Output {
+        self % *rhs
+    }
+}
+impl Rem<f32> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: f32) -> Self::Output {
+        *self % rhs
+    }
+}
+impl Rem<&f32> for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: &f32) -> Self::Output {
+        *self % *rhs
+    }
+}
+
+// f32 % Vec2
+impl Rem<Vec2> for f32 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: Vec2) -> Self::Output {
+        let out = Vec2::new(
+            self % rhs.x,
+            self % rhs.y,
+        );
+        nan_guard!("f32 % Vec2", out, self, rhs);
+        out
+    }
+}
+impl Rem<&Vec2> for f32 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: &Vec2) -> Self::Output {
+        self % *rhs
+    }
+}
+impl Rem<Vec2> for &f32 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: Vec2) -> Self::Output {
+        *self % rhs
+    }
+}
+impl Rem<&Vec2> for &f32 {
+    type Output = Vec2;
+    #[inline]
+    fn rem(self, rhs: &Vec2) -> Self::Output {
+        *self % *rhs
+    }
+}
+
+// Vec2 %= Vec2
+impl RemAssign<Vec2> for Vec2 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: Vec2) {
+        *self = *self % rhs;
+    }
+}
+impl RemAssign<&Vec2> for Vec2 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &Vec2) {
+        *self %= *rhs;
+    }
+}
+
+// Vec2 %= f32
+impl RemAssign<f32> for Vec2 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: f32) {
+        *self = *self % rhs;
+    }
+}
+impl RemAssign<&f32> for Vec2 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &f32) {
+        *self %= *rhs;
+    }
+}
+
+
+// -Vec2
+impl Neg for Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+impl Neg for &Vec2 {
+    type Output = Vec2;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+
+// Vec2[]
+impl Index<usize> for Vec2 {
+    type Output = f32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Cannot index into a Vec2 at i > 1"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Vec2 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Cannot index into a Vec2 at i > 1"),
+        }
+    }
+}
+
+impl Index<Axis> for Vec2 {
+    type Output = f32;
+    #[inline]
+    fn index(&self, axis: Axis) -> &Self::Output {
+        &self[axis.index()]
+    }
+}
+
+impl IndexMut<Axis> for Vec2 {
+    #[inline]
+    fn index_mut(&mut self, axis: Axis) -> &mut Self::Output {
+        &mut self[axis.index()]
+    }
+}
+
+
+impl From<[f32; 2]> for Vec2 {
+    #[inline]
+    fn from(arr: [f32; 2]) -> Vec2 {
+        Vec2::new(arr[0], arr[1])
+    }
+}
+impl From<&[f32; 2]> for Vec2 {
+    #[inline]
+    fn from(arr: &[f32; 2]) -> Vec2 {
+        Vec2::new(arr[0], arr[1])
+    }
+}
+
+impl From<Vec2> for [f32; 2] {
+    #[inline]
+    fn from(v: Vec2) -> [f32; 2] {
+        [v.x, v.y]
+    }
+}
+impl From<&Vec2> for [f32; 2] {
+    #[inline]
+    fn from(v: &Vec2) -> [f32; 2] {
+        [v.x, v.y]
+    }
+}
+
+impl From<(f32, f32)> for Vec2 {
+    #[inline]
+    fn from(vals: (f32, f32)) -> Self {
+        Vec2::new(vals.0, vals.1)
+    }
+}
+impl From<&(f32, f32)> for Vec2 {
+    #[inline]
+    fn from(vals: &(f32, f32)) -> Self {
+        Vec2::new(vals.0, vals.1)
+    }
+}
+
+impl From<Vec2> for (f32, f32) {
+    #[inline]
+    fn from(v: Vec2) -> (f32, f32) {
+        (v.x, v.y)
+    }
+}
+impl From<&Vec2> for (f32, f32) {
+    #[inline]
+    fn from(v: &Vec2) -> (f32, f32) {
+        (v.x, v.y)
+    }
+}
+
+
+impl AsRef<[f32; 2]> for Vec2 {
+    #[inline]
+    fn as_ref(&self) -> &[f32; 2] {
+        unsafe { &*(self as *const Vec2 as *const [f32; 2]) }
+    }
+}
+
+impl AsMut<[f32; 2]> for Vec2 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32; 2] {
+        unsafe { &mut *(self as *mut Vec2 as *mut [f32; 2]) }
+    }
+}
+
+
+impl<'a> IntoIterator for &'a Vec2 {
+    type Item = &'a f32;
+    type IntoIter = Iter<'a, f32>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Vec2 {
+    type Item = &'a mut f32;
+    type IntoIter = IterMut<'a, f32>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().iter_mut()
+    }
+}
+
+
+impl Sum<Vec2> for Vec2 {
+    #[inline]
+    fn sum<I: Iterator<Item = Vec2>>(iter: I) -> Vec2 {
+        iter.fold(Vec2::ZERO, |acc, v| acc + v)
+    }
+}
+
+impl<'a> Sum<&'a Vec2> for Vec2 {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Vec2>>(iter: I) -> Vec2 {
+        iter.fold(Vec2::ZERO, |acc, v| acc + v)
+    }
+}
+
+impl Product<Vec2> for Vec2 {
+    #[inline]
+    fn product<I: Iterator<Item = Vec2>>(iter: I) -> Vec2 {
+        iter.fold(Vec2::ONE, |acc, v| acc * v)
+    }
+}
+
+impl<'a> Product<&'a Vec2> for Vec2 {
+    #[inline]
+    fn product<I: Iterator<Item = &'a Vec2>>(iter: I) -> Vec2 {
+        iter.fold(Vec2::ONE, |acc, v| acc * v)
+    }
+}
 
+/// Takes the first 2 items as the components, ignoring any after them
+/// Panics if the iterator runs out first
+impl FromIterator<f32> for Vec2 {
     #[inline]
-    pub const fn splat(v: f32) -> Self {
-        Self::new(v, v)
+    fn from_iter<I: IntoIterator<Item = f32>>(iter: I) -> Vec2 {
+        let mut iter = iter.into_iter();
+        let mut next = || iter.next().expect("too few items to build a Vec2");
+        Vec2::new(next(), next())
     }
 }
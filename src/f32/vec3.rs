@@ -1,7 +1,27 @@
 use crate::{
+    validate::{
+        nan_guard,
+        validate_finite,
+    },
+    Axis,
+    BVec3,
+    Dir3,
+    F32Ext,
+    IVec3,
+    UVec3,
+    Vec2,
+    Vec4,
     VecExt,
 };
 use std::{
+    slice::{
+        Iter,
+        IterMut,
+    },
+    iter::{
+        Sum,
+        Product,
+    },
     fmt::{
         self,
         Debug,
@@ -30,6 +50,7 @@ use std::{
 
 /// A vector in 3-space
 #[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Vec3 {
     pub x: f32,
@@ -43,6 +64,28 @@ impl Vec3 {
     /// The default Vec3 with all 0's
     pub const ZERO: Vec3 = Vec3::splat(0.0);
 
+    /// All 1's
+    pub const ONE: Vec3 = Vec3::splat(1.0);
+
+    /// All -1's
+    pub const NEG_ONE: Vec3 = Vec3::splat(-1.0);
+
+    /// All f32::MIN, the most negative finite value
+    pub const MIN: Vec3 = Vec3::splat(f32::MIN);
+
+    /// All f32::MAX, the largest finite value
+    pub const MAX: Vec3 = Vec3::splat(f32::MAX);
+
+    /// All positive infinity, handy for seeding a running minimum
+    pub const INFINITY: Vec3 = Vec3 { x: f32::INFINITY, y: f32::INFINITY, z: f32::INFINITY };
+
+    /// All negative infinity, handy for seeding a running maximum
+    pub const NEG_INFINITY: Vec3 = Vec3 { x: f32::NEG_INFINITY, y: f32::NEG_INFINITY, z: f32::NEG_INFINITY };
+
+    /// All NaN
+    /// Built directly so it is available even with the validate feature, which would reject it in new
+    pub const NAN: Vec3 = Vec3 { x: f32::NAN, y: f32::NAN, z: f32::NAN };
+
     /// The positive x-axis basis vector
     pub const X: Vec3 = Vec3::new(1.0, 0.0, 0.0);
 
@@ -61,10 +104,14 @@ impl Vec3 {
     /// The negative z-axis basis vector
     pub const NEG_Z: Vec3 = Vec3::new(0.0, 0.0, -1.0);
 
+    /// The positive basis vectors, in axis order
+    pub const AXES: [Vec3; 3] = [Vec3::X, Vec3::Y, Vec3::Z];
+
 
     /// Standard constructor for <x y z>
     #[inline]
     pub const fn new(x: f32, y: f32, z: f32) -> Vec3 {
+        validate_finite!("Vec3::new", x, y, z);
         Vec3 { x, y, z }
     }
 
@@ -73,6 +120,167 @@ impl Vec3 {
         Vec3::new(v, v, v)
     }
 
+    /// Returns a copy of self with the x component replaced
+    #[inline]
+    pub const fn with_x(mut self, x: f32) -> Vec3 {
+        self.x = x;
+        self
+    }
+
+    /// Returns a copy of self with the y component replaced
+    #[inline]
+    pub const fn with_y(mut self, y: f32) -> Vec3 {
+        self.y = y;
+        self
+    }
+
+    /// Returns a copy of self with the z component replaced
+    #[inline]
+    pub const fn with_z(mut self, z: f32) -> Vec3 {
+        self.z = z;
+        self
+    }
+
+    /// Appends w to make a Vec4, use 1 for a point and 0 for a direction
+    #[inline]
+    pub const fn extend(self, w: f32) -> Vec4 {
+        Vec4::new(self.x, self.y, self.z, w)
+    }
+
+    /// Drops z to make a Vec2
+    #[inline]
+    pub const fn truncate(self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+
+    /// Componentwise self + rhs, usable in const contexts
+    #[inline]
+    pub const fn const_add(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+
+    /// Componentwise self - rhs, usable in const contexts
+    #[inline]
+    pub const fn const_sub(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+
+    /// self * rhs, usable in const contexts
+    #[inline]
+    pub const fn const_mul(self, rhs: f32) -> Vec3 {
+        Vec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+
+    /// Componentwise self * rhs, usable in const contexts
+    #[inline]
+    pub const fn const_mul_vec(self, rhs: Vec3) -> Vec3 {
+        Vec3::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+
+    /// -self, usable in const contexts
+    #[inline]
+    pub const fn const_neg(self) -> Vec3 {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+
+    /// Dot product, usable in const contexts
+    #[inline]
+    pub const fn const_dot(self, rhs: Vec3) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// Returns the positive basis vector along axis
+    /// Panics if axis is not one of the 3 axes of a Vec3
+    #[inline]
+    pub fn axis(axis: Axis) -> Vec3 {
+        let mut v = Vec3::ZERO;
+        v[axis] = 1.0;
+        v
+    }
+
+    /// Returns the component along axis, the same as self[axis]
+    /// Panics if axis is not one of the 3 axes of a Vec3
+    #[inline]
+    pub fn get_axis(self, axis: Axis) -> f32 {
+        self[axis]
+    }
+
+    /// Sets the component along axis, the same as self[axis] = v
+    /// Panics if axis is not one of the 3 axes of a Vec3
+    #[inline]
+    pub fn set_axis(&mut self, axis: Axis, v: f32) {
+        self[axis] = v;
+    }
+
+    /// The axis of the component with the largest magnitude, the first one on ties
+    /// Picks the projection plane for a normal, or the longest side to split a box along
+    /// Requires: no component should be NaN
+    #[inline]
+    pub fn dominant_axis(self) -> Axis {
+        Axis::XYZW[self.abs().max_position()]
+    }
+
+    /// The mask of components of self equal to those of rhs
+    #[inline]
+    pub fn cmpeq(self, rhs: Vec3) -> BVec3 {
+        BVec3::from(VecExt::cmpeq(self, rhs))
+    }
+
+    /// The mask of components of self not equal to those of rhs
+    #[inline]
+    pub fn cmpne(self, rhs: Vec3) -> BVec3 {
+        BVec3::from(VecExt::cmpne(self, rhs))
+    }
+
+    /// The mask of components of self less than those of rhs
+    #[inline]
+    pub fn cmplt(self, rhs: Vec3) -> BVec3 {
+        BVec3::from(VecExt::cmplt(self, rhs))
+    }
+
+    /// The mask of components of self less than or equal to those of rhs
+    #[inline]
+    pub fn cmple(self, rhs: Vec3) -> BVec3 {
+        BVec3::from(VecExt::cmple(self, rhs))
+    }
+
+    /// The mask of components of self greater than those of rhs
+    #[inline]
+    pub fn cmpgt(self, rhs: Vec3) -> BVec3 {
+        BVec3::from(VecExt::cmpgt(self, rhs))
+    }
+
+    /// The mask of components of self greater than or equal to those of rhs
+    #[inline]
+    pub fn cmpge(self, rhs: Vec3) -> BVec3 {
+        BVec3::from(VecExt::cmpge(self, rhs))
+    }
+
+    /// Picks the component from if_true where mask is true, otherwise from if_false, like a shader's select
+    #[inline]
+    pub fn select(mask: BVec3, if_true: Vec3, if_false: Vec3) -> Vec3 {
+        <Vec3 as VecExt<3>>::select(mask, if_true, if_false)
+    }
+
+    /// Mutable view of the x and y components
+    #[inline]
+    pub fn xy_mut(&mut self) -> &mut [f32; 2] {
+        self.as_mut().first_chunk_mut().unwrap()
+    }
+
+    /// Mutable view of the y and z components
+    #[inline]
+    pub fn yz_mut(&mut self) -> &mut [f32; 2] {
+        self.as_mut().last_chunk_mut().unwrap()
+    }
+
+    /// Mutable references to the x and z components, the horizontal plane in a y-up world
+    #[inline]
+    pub fn xz_mut(&mut self) -> (&mut f32, &mut f32) {
+        let [x, _, z] = self.as_mut();
+        (x, z)
+    }
+
     /// Creates a flattened forward Vec3 from yaw
     #[inline]
     pub fn forward_from_yaw(yaw: f32) -> Vec3 {
@@ -96,6 +304,38 @@ impl Vec3 {
         (forward, right)
     }
 
+    /// Creates a Vec3 from spherical coordinates in a y-up world
+    /// theta is the polar angle down from +y in [0, pi],
+    /// phi is the azimuth around +y measured from +z towards +x, the same as yaw
+    #[inline]
+    pub fn from_spherical(r: f32, theta: f32, phi: f32) -> Vec3 {
+        let (sin_theta, cos_theta) = theta.sincos();
+        (Vec3::forward_from_yaw(phi) * sin_theta).with_y(cos_theta) * r
+    }
+
+    /// Returns the spherical coordinates (r, theta, phi) of self, the inverse of from_spherical
+    /// phi is in (-pi, pi], both angles are 0 for the zero vector
+    #[inline]
+    pub fn to_spherical(self) -> (f32, f32, f32) {
+        let r = self.length();
+        let theta = if r == 0.0 { 0.0 } else { (self.y / r).acos_clamped() };
+        (r, theta, self.x.atan2(self.z))
+    }
+
+    /// Creates a Vec3 from cylindrical coordinates around the y-axis
+    /// phi is the azimuth measured from +z towards +x, the same as yaw
+    #[inline]
+    pub fn from_cylindrical(radius: f32, phi: f32, height: f32) -> Vec3 {
+        (Vec3::forward_from_yaw(phi) * radius).with_y(height)
+    }
+
+    /// Returns the cylindrical coordinates (radius, phi, height) of self, the inverse of from_cylindrical
+    /// phi is in (-pi, pi], and is 0 on the y-axis
+    #[inline]
+    pub fn to_cylindrical(self) -> (f32, f32, f32) {
+        (self.x.hypot(self.z), self.x.atan2(self.z), self.y)
+    }
+
 
     /// Transforms a local-space Vec3 into world-space
     /// Requires: right, up, and forward should all be normalized
@@ -114,6 +354,90 @@ impl Vec3 {
         )
     }
 
+    /// The scalar triple product self · (b × c), the signed volume of the parallelepiped the three span
+    /// Positive when self, b, c are ordered the same way as X, Y, Z, 0 when they are coplanar
+    /// A sixth of it is the signed volume of the tetrahedron with those edges
+    #[inline]
+    pub fn scalar_triple(self, b: Vec3, c: Vec3) -> f32 {
+        self.dot(b.cross(c))
+    }
+
+    /// The vector triple product self × (b × c), which lies in the plane of b and c
+    /// Expanded as b (self · c) - c (self · b), so note self × (b × c) != (self × b) × c
+    #[inline]
+    pub fn vector_triple(self, b: Vec3, c: Vec3) -> Vec3 {
+        b * self.dot(c) - c * self.dot(b)
+    }
+
+    /// Returns some vector perpendicular to self, with no guarantees about its length or direction
+    /// Requires: self should not be zero
+    #[inline]
+    pub fn any_orthogonal(self) -> Vec3 {
+        if self.x.abs() > self.y.abs() {
+            Vec3::new(-self.z, 0.0, self.x)
+        } else {
+            Vec3::new(0.0, self.z, -self.y)
+        }
+    }
+
+    /// Returns some unit vector perpendicular to self
+    /// Requires: self should be normalized
+    #[inline]
+    pub fn any_orthonormal(self) -> Vec3 {
+        self.orthonormal_basis().0
+    }
+
+    /// Returns two unit vectors that form a right-handed orthonormal basis with self, self = a × b
+    /// Branchless, but the basis jumps as self.z changes sign
+    /// Requires: self should be normalized
+    // Duff et al. 2017, Building an Orthonormal Basis, Revisited
+    #[inline]
+    pub fn orthonormal_basis(self) -> (Vec3, Vec3) {
+        let sign = 1.0f32.copysign(self.z);
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            Vec3::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Vec3::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+
+    /// Rotates by angle radians about axis using Rodrigues' formula
+    /// Counter-clockwise when looking down the axis towards the origin, matching Mat3::from_axis_angle
+    #[inline]
+    pub fn rotate_around_axis(self, axis: Dir3, angle: f32) -> Vec3 {
+        let k = axis.as_vec3();
+        let (sin, cos) = angle.sincos();
+        self * cos + k.cross(self) * sin + k * (k.dot(self) * (1.0 - cos))
+    }
+
+    /// Rotates by angle radians about the line through pivot along axis, for orbiting a point
+    #[inline]
+    pub fn rotate_around(self, pivot: Vec3, axis: Dir3, angle: f32) -> Vec3 {
+        pivot + (self - pivot).rotate_around_axis(axis, angle)
+    }
+
+    /// Rotates by angle radians about the x-axis
+    #[inline]
+    pub fn rotate_x(self, angle: f32) -> Vec3 {
+        let (sin, cos) = angle.sincos();
+        Vec3::new(self.x, self.y * cos - self.z * sin, self.y * sin + self.z * cos)
+    }
+
+    /// Rotates by angle radians about the y-axis
+    #[inline]
+    pub fn rotate_y(self, angle: f32) -> Vec3 {
+        let (sin, cos) = angle.sincos();
+        Vec3::new(self.x * cos + self.z * sin, self.y, self.z * cos - self.x * sin)
+    }
+
+    /// Rotates by angle radians about the z-axis
+    #[inline]
+    pub fn rotate_z(self, angle: f32) -> Vec3 {
+        let (sin, cos) = angle.sincos();
+        Vec3::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos, self.z)
+    }
+
     /// Clamps the x value of Vec3
     /// Requires: min < max
     #[inline]
@@ -148,9 +472,8 @@ impl Vec3 {
     }
 
     /// Move along an axis by a distance d
-    /// Requires: axis should be normalized
     #[inline]
-    pub fn move_along(self, axis: Vec3, d: f32) -> Vec3 {
+    pub fn move_along(self, axis: Dir3, d: f32) -> Vec3 {
         self + axis * d
     }
 
@@ -162,18 +485,33 @@ impl Vec3 {
         self + (point - self).normalize() * d
     }
 
+    /// Removes the component of self along normal, leaving its shadow on the plane through the origin
+    #[inline]
+    pub fn project_onto_plane(self, normal: Dir3) -> Vec3 {
+        let n = *normal;
+        self - n * self.dot(n)
+    }
+
+    /// Collision response for a velocity hitting a surface, the part pushing into it is removed
+    /// Velocity already moving away from the surface is returned unchanged
+    #[inline]
+    pub fn slide(self, normal: Dir3) -> Vec3 {
+        let n = *normal;
+        let into = self.dot(n);
+        if into < 0.0 { self - n * into } else { self }
+    }
+
     /// Computes the direction of a ray reflected off the normal of a surface
-    /// Requires: normal should be normalized
     #[inline]
-    pub fn reflect(self, normal: Vec3) -> Vec3 {
-        self - 2.0 * normal * self.dot(normal)
+    pub fn reflect(self, normal: Dir3) -> Vec3 {
+        self - 2.0 * normal * self.dot(*normal)
     }
 
     /// Returns the direction vector of a ray refracted to the surface normal
-    /// Requires: self and normal should be normalized
+    /// Requires: self should be normalized
     // https://en.wikipedia.org/wiki/Snell's_law
     #[inline]
-    pub fn refract(self, normal: Vec3, r: f32) -> Vec3 {
+    pub fn refract(self, normal: Dir3, r: f32) -> Vec3 {
         let cos_a1 = -normal.dot(self);
         let cos_a2_2 = 1.0 - r * r * (1.0 - cos_a1 * cos_a1);
         if cos_a2_2 >= 0.0 {
@@ -189,7 +527,8 @@ impl Vec3 {
     pub fn cos_angle_between(self, rhs: Vec3) -> f32 {
         let numerator = self.dot(rhs);
         let denominator = (self.length_2() * rhs.length_2()).sqrt();
-        numerator / denominator
+        // rounding can push nearly parallel vectors slightly past 1
+        (numerator / denominator).clamp(-1.0, 1.0)
     }
 
     /// Returns sin of the positive acute angle between two Vec3s
@@ -213,7 +552,132 @@ impl Vec3 {
     /// Requires: neither self nor rhs should be of length zero
     #[inline]
     pub fn angle_between(self, rhs: Vec3) -> f32 {
-        self.cos_angle_between(rhs).acos()
+        self.cos_angle_between(rhs).acos_clamped()
+    }
+
+    /// The angle between self and rhs, positive when rotating self to rhs is counter-clockwise about axis
+    /// In (-pi, pi], axis only picks the sign so it need not be perpendicular to either vector
+    /// Requires: neither self nor rhs should be of length zero
+    #[inline]
+    pub fn signed_angle_between(self, rhs: Vec3, axis: Dir3) -> f32 {
+        let cross = self.cross(rhs);
+        let angle = cross.length().atan2(self.dot(rhs));
+        if cross.dot(*axis) < 0.0 { -angle } else { angle }
+    }
+
+    /// Rotates the direction self towards target by at most max_angle radians
+    /// Returns target once it is within max_angle, and picks an arbitrary axis when they are opposite
+    /// Requires: self and target should be normalized, max_angle should be non-negative
+    #[inline]
+    pub fn rotate_towards(self, target: Vec3, max_angle: f32) -> Vec3 {
+        let angle = self.angle_between(target);
+        if angle <= max_angle {
+            return target;
+        }
+        self.rotate_around_axis(self.rotation_axis_to(target), max_angle)
+    }
+
+    /// Interpolates between the directions self and rhs along the great circle at a constant angular speed
+    /// Opposite directions turn about an arbitrary perpendicular axis
+    /// Requires: self and rhs should be normalized
+    #[inline]
+    pub fn slerp(self, rhs: Vec3, t: f32) -> Vec3 {
+        let angle = self.angle_between(rhs);
+        if angle < 1e-4 {
+            // too close for a reliable rotation axis, and a plain lerp is already within rounding of unit length here
+            return self.lerp(rhs, t);
+        }
+        self.rotate_around_axis(self.rotation_axis_to(rhs), angle * t)
+    }
+
+    /// Cheaper approximation of slerp that lerps then renormalizes
+    /// Angular speed is not constant, fastest around t = 0.5, falls back to slerp for opposite directions
+    /// Requires: self and rhs should be normalized
+    #[inline]
+    pub fn nlerp(self, rhs: Vec3, t: f32) -> Vec3 {
+        let v = self.lerp(rhs, t);
+        let len_2 = v.length_2();
+        if len_2 < 1e-8 {
+            return self.slerp(rhs, t);
+        }
+        v / len_2.sqrt()
+    }
+
+    // The axis rotating self towards target by the right-hand rule, or any perpendicular one when they're parallel
+    #[inline]
+    fn rotation_axis_to(self, target: Vec3) -> Dir3 {
+        let axis = self.cross(target);
+        if axis.length_2() > 1e-12 {
+            Dir3::new_unchecked(axis.normalize())
+        } else {
+            Dir3::new_unchecked(self.any_orthonormal())
+        }
+    }
+
+
+    /// Converts to an IVec3 rounding each component towards negative infinity
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn as_ivec3_floor(self) -> IVec3 {
+        IVec3::new(
+            self.x.fast_floor_i32(),
+            self.y.fast_floor_i32(),
+            self.z.fast_floor_i32(),
+        )
+    }
+
+    /// Converts to an IVec3 rounding each component to the nearest integer, ties to even
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn as_ivec3_round(self) -> IVec3 {
+        IVec3::new(
+            self.x.round_half_even_i32(),
+            self.y.round_half_even_i32(),
+            self.z.round_half_even_i32(),
+        )
+    }
+
+    /// Converts to an IVec3 rounding each component towards positive infinity
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn as_ivec3_ceil(self) -> IVec3 {
+        IVec3::new(
+            self.x.fast_ceil_i32(),
+            self.y.fast_ceil_i32(),
+            self.z.fast_ceil_i32(),
+        )
+    }
+
+    /// Converts to an IVec3 rounding each component towards zero
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn as_ivec3_trunc(self) -> IVec3 {
+        IVec3::new(
+            self.x as i32,
+            self.y as i32,
+            self.z as i32,
+        )
+    }
+
+    /// Converts to an IVec3 rounding each component towards zero
+    /// Components below i32::MIN or above i32::MAX (including infinities) saturate to those bounds
+    /// NaN components become 0
+    #[inline]
+    pub fn as_ivec3_saturating(self) -> IVec3 {
+        // `as` is already saturating, this name spells that out at the call site
+        self.as_ivec3_trunc()
+    }
+
+    /// Converts to a UVec3 rounding each component towards zero
+    /// Negative components clamp to 0 and components above u32::MAX clamp to u32::MAX
+    /// NaN components become 0
+    #[inline]
+    pub fn as_uvec3_clamped(self) -> UVec3 {
+        UVec3::new(
+            self.x as u32,
+            self.y as u32,
+            self.z as u32,
+        )
     }
 }
 
@@ -252,11 +716,13 @@ impl Add<Vec3> for Vec3 {
     type Output = Vec3;
     #[inline]
     fn add(self, rhs: Vec3) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self.x + rhs.x,
             self.y + rhs.y,
             self.z + rhs.z,
-        )
+        );
+        nan_guard!("Vec3 + Vec3", out, self, rhs);
+        out
     }
 }
 impl Add<&Vec3> for Vec3 {
@@ -286,11 +752,13 @@ impl Add<f32> for Vec3 {
     type Output = Vec3;
     #[inline]
     fn add(self, rhs: f32) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self.x + rhs,
             self.y + rhs,
             self.z + rhs,
-        )
+        );
+        nan_guard!("Vec3 + f32", out, self, rhs);
+        out
     }
 }
 impl Add<&f32> for Vec3 {
@@ -320,11 +788,13 @@ impl Add<Vec3> for f32 {
     type Output = Vec3;
     #[inline]
     fn add(self, rhs: Vec3) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self + rhs.x,
             self + rhs.y,
             self + rhs.z,
-        )
+        );
+        nan_guard!("f32 + Vec3", out, self, rhs);
+        out
     }
 }
 impl Add<&Vec3> for f32 {
@@ -353,9 +823,7 @@ impl Add<&Vec3> for &f32 {
 impl AddAssign<Vec3> for Vec3 {
     #[inline]
     fn add_assign(&mut self, rhs: Vec3) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
+        *self = *self + rhs;
     }
 }
 impl AddAssign<&Vec3> for Vec3 {
@@ -369,9 +837,7 @@ impl AddAssign<&Vec3> for Vec3 {
 impl AddAssign<f32> for Vec3 {
     #[inline]
     fn add_assign(&mut self, rhs: f32) {
-        self.x += rhs;
-        self.y += rhs;
-        self.z += rhs;
+        *self = *self + rhs;
     }
 }
 impl AddAssign<&f32> for Vec3 {
@@ -387,11 +853,13 @@ impl Sub<Vec3> for Vec3 {
     type Output = Vec3;
     #[inline]
     fn sub(self, rhs: Vec3) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self.x - rhs.x,
             self.y - rhs.y,
             self.z - rhs.z,
-        )
+        );
+        nan_guard!("Vec3 - Vec3", out, self, rhs);
+        out
     }
 }
 impl Sub<&Vec3> for Vec3 {
@@ -421,11 +889,13 @@ impl Sub<f32> for Vec3 {
     type Output = Vec3;
     #[inline]
     fn sub(self, rhs: f32) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self.x - rhs,
             self.y - rhs,
             self.z - rhs,
-        )
+        );
+        nan_guard!("Vec3 - f32", out, self, rhs);
+        out
     }
 }
 impl Sub<&f32> for Vec3 {
@@ -455,11 +925,13 @@ impl Sub<Vec3> for f32 {
     type Output = Vec3;
     #[inline]
     fn sub(self, rhs: Vec3) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self - rhs.x,
             self - rhs.y,
             self - rhs.z,
-        )
+        );
+        nan_guard!("f32 - Vec3", out, self, rhs);
+        out
     }
 }
 impl Sub<&Vec3> for f32 {
@@ -488,9 +960,7 @@ impl Sub<&Vec3> for &f32 {
 impl SubAssign<Vec3> for Vec3 {
     #[inline]
     fn sub_assign(&mut self, rhs: Vec3) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-        self.z -= rhs.z;
+        *self = *self - rhs;
     }
 }
 impl SubAssign<&Vec3> for Vec3 {
@@ -504,9 +974,7 @@ impl SubAssign<&Vec3> for Vec3 {
 impl SubAssign<f32> for Vec3 {
     #[inline]
     fn sub_assign(&mut self, rhs: f32) {
-        self.x -= rhs;
-        self.y -= rhs;
-        self.z -= rhs;
+        *self = *self - rhs;
     }
 }
 impl SubAssign<&f32> for Vec3 {
@@ -522,11 +990,13 @@ impl Mul<Vec3> for Vec3 {
     type Output = Vec3;
     #[inline]
     fn mul(self, rhs: Vec3) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self.x * rhs.x,
             self.y * rhs.y,
             self.z * rhs.z,
-        )
+        );
+        nan_guard!("Vec3 * Vec3", out, self, rhs);
+        out
     }
 }
 impl Mul<&Vec3> for Vec3 {
@@ -556,11 +1026,13 @@ impl Mul<f32> for Vec3 {
     type Output = Vec3;
     #[inline]
     fn mul(self, rhs: f32) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self.x * rhs,
             self.y * rhs,
             self.z * rhs,
-        )
+        );
+        nan_guard!("Vec3 * f32", out, self, rhs);
+        out
     }
 }
 impl Mul<&f32> for Vec3 {
@@ -590,11 +1062,13 @@ impl Mul<Vec3> for f32 {
     type Output = Vec3;
     #[inline]
     fn mul(self, rhs: Vec3) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self * rhs.x,
             self * rhs.y,
             self * rhs.z,
-        )
+        );
+        nan_guard!("f32 * Vec3", out, self, rhs);
+        out
     }
 }
 impl Mul<&Vec3> for f32 {
@@ -623,9 +1097,7 @@ impl Mul<&Vec3> for &f32 {
 impl MulAssign<Vec3> for Vec3 {
     #[inline]
     fn mul_assign(&mut self, rhs: Vec3) {
-        self.x *= rhs.x;
-        self.y *= rhs.y;
-        self.z *= rhs.z;
+        *self = *self * rhs;
     }
 }
 impl MulAssign<&Vec3> for Vec3 {
@@ -639,9 +1111,7 @@ impl MulAssign<&Vec3> for Vec3 {
 impl MulAssign<f32> for Vec3 {
     #[inline]
     fn mul_assign(&mut self, rhs: f32) {
-        self.x *= rhs;
-        self.y *= rhs;
-        self.z *= rhs;
+        *self = *self * rhs;
     }
 }
 impl MulAssign<&f32> for Vec3 {
@@ -657,11 +1127,13 @@ impl Div<Vec3> for Vec3 {
     type Output = Vec3;
     #[inline]
     fn div(self, rhs: Vec3) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self.x / rhs.x,
             self.y / rhs.y,
             self.z / rhs.z,
-        )
+        );
+        nan_guard!("Vec3 / Vec3", out, self, rhs);
+        out
     }
 }
 impl Div<&Vec3> for Vec3 {
@@ -691,11 +1163,13 @@ impl Div<f32> for Vec3 {
     type Output = Vec3;
     #[inline]
     fn div(self, rhs: f32) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self.x / rhs,
             self.y / rhs,
             self.z / rhs,
-        )
+        );
+        nan_guard!("Vec3 / f32", out, self, rhs);
+        out
     }
 }
 impl Div<&f32> for Vec3 {
@@ -725,11 +1199,13 @@ impl Div<Vec3> for f32 {
     type Output = Vec3;
     #[inline]
     fn div(self, rhs: Vec3) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self / rhs.x,
             self / rhs.y,
             self / rhs.z,
-        )
+        );
+        nan_guard!("f32 / Vec3", out, self, rhs);
+        out
     }
 }
 impl Div<&Vec3> for f32 {
@@ -758,9 +1234,7 @@ impl Div<&Vec3> for &f32 {
 impl DivAssign<Vec3> for Vec3 {
     #[inline]
     fn div_assign(&mut self, rhs: Vec3) {
-        self.x /= rhs.x;
-        self.y /= rhs.y;
-        self.z /= rhs.z;
+        *self = *self / rhs;
     }
 }
 impl DivAssign<&Vec3> for Vec3 {
@@ -774,9 +1248,7 @@ impl DivAssign<&Vec3> for Vec3 {
 impl DivAssign<f32> for Vec3 {
     #[inline]
     fn div_assign(&mut self, rhs: f32) {
-        self.x /= rhs;
-        self.y /= rhs;
-        self.z /= rhs;
+        *self = *self / rhs;
     }
 }
 impl DivAssign<&f32> for Vec3 {
@@ -792,11 +1264,13 @@ impl Rem<Vec3> for Vec3 {
     type Output = Vec3;
     #[inline]
     fn rem(self, rhs: Vec3) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self.x % rhs.x,
             self.y % rhs.y,
             self.z % rhs.z,
-        )
+        );
+        nan_guard!("Vec3 % Vec3", out, self, rhs);
+        out
     }
 }
 impl Rem<&Vec3> for Vec3 {
@@ -826,11 +1300,13 @@ impl Rem<f32> for Vec3 {
     type Output = Vec3;
     #[inline]
     fn rem(self, rhs: f32) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self.x % rhs,
             self.y % rhs,
             self.z % rhs,
-        )
+        );
+        nan_guard!("Vec3 % f32", out, self, rhs);
+        out
     }
 }
 impl Rem<&f32> for Vec3 {
@@ -860,11 +1336,13 @@ impl Rem<Vec3> for f32 {
     type Output = Vec3;
     #[inline]
     fn rem(self, rhs: Vec3) -> Self::Output {
-        Vec3::new(
+        let out = Vec3::new(
             self % rhs.x,
             self % rhs.y,
             self % rhs.z,
-        )
+        );
+        nan_guard!("f32 % Vec3", out, self, rhs);
+        out
     }
 }
 impl Rem<&Vec3> for f32 {
@@ -893,9 +1371,7 @@ impl Rem<&Vec3> for &f32 {
 impl RemAssign<Vec3> for Vec3 {
     #[inline]
     fn rem_assign(&mut self, rhs: Vec3) {
-        self.x %= rhs.x;
-        self.y %= rhs.y;
-        self.z %= rhs.z;
+        *self = *self % rhs;
     }
 }
 impl RemAssign<&Vec3> for Vec3 {
@@ -909,9 +1385,7 @@ impl RemAssign<&Vec3> for Vec3 {
 impl RemAssign<f32> for Vec3 {
     #[inline]
     fn rem_assign(&mut self, rhs: f32) {
-        self.x %= rhs;
-        self.y %= rhs;
-        self.z %= rhs;
+        *self = *self % rhs;
     }
 }
 impl RemAssign<&f32> for Vec3 {
@@ -965,6 +1439,21 @@ impl IndexMut<usize> for Vec3 {
     }
 }
 
+impl Index<Axis> for Vec3 {
+    type Output = f32;
+    #[inline]
+    fn index(&self, axis: Axis) -> &Self::Output {
+        &self[axis.index()]
+    }
+}
+
+impl IndexMut<Axis> for Vec3 {
+    #[inline]
+    fn index_mut(&mut self, axis: Axis) -> &mut Self::Output {
+        &mut self[axis.index()]
+    }
+}
+
 
 impl From<[f32; 3]> for Vec3 {
     #[inline]
@@ -979,16 +1468,16 @@ impl From<&[f32; 3]> for Vec3 {
     }
 }
 
-impl Into<[f32; 3]> for Vec3 {
+impl From<Vec3> for [f32; 3] {
     #[inline]
-    fn into(self) -> [f32; 3] {
-        [self.x, self.y, self.z]
+    fn from(v: Vec3) -> [f32; 3] {
+        [v.x, v.y, v.z]
     }
 }
-impl Into<[f32; 3]> for &Vec3 {
+impl From<&Vec3> for [f32; 3] {
     #[inline]
-    fn into(self) -> [f32; 3] {
-        [self.x, self.y, self.z]
+    fn from(v: &Vec3) -> [f32; 3] {
+        [v.x, v.y, v.z]
     }
 }
 
@@ -1005,16 +1494,16 @@ impl From<&(f32, f32, f32)> for Vec3 {
     }
 }
 
-impl Into<(f32, f32, f32)> for Vec3 {
+impl From<Vec3> for (f32, f32, f32) {
     #[inline]
-    fn into(self) -> (f32, f32, f32) {
-        (self.x, self.y, self.z)
+    fn from(v: Vec3) -> (f32, f32, f32) {
+        (v.x, v.y, v.z)
     }
 }
-impl Into<(f32, f32, f32)> for &Vec3 {
+impl From<&Vec3> for (f32, f32, f32) {
     #[inline]
-    fn into(self) -> (f32, f32, f32) {
-        (self.x, self.y, self.z)
+    fn from(v: &Vec3) -> (f32, f32, f32) {
+        (v.x, v.y, v.z)
     }
 }
 
@@ -1032,3 +1521,62 @@ impl AsMut<[f32; 3]> for Vec3 {
         unsafe { &mut *(self as *mut Vec3 as *mut [f32; 3]) }
     }
 }
+
+
+impl<'a> IntoIterator for &'a Vec3 {
+    type Item = &'a f32;
+    type IntoIter = Iter<'a, f32>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Vec3 {
+    type Item = &'a mut f32;
+    type IntoIter = IterMut<'a, f32>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().iter_mut()
+    }
+}
+
+
+impl Sum<Vec3> for Vec3 {
+    #[inline]
+    fn sum<I: Iterator<Item = Vec3>>(iter: I) -> Vec3 {
+        iter.fold(Vec3::ZERO, |acc, v| acc + v)
+    }
+}
+
+impl<'a> Sum<&'a Vec3> for Vec3 {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Vec3>>(iter: I) -> Vec3 {
+        iter.fold(Vec3::ZERO, |acc, v| acc + v)
+    }
+}
+
+impl Product<Vec3> for Vec3 {
+    #[inline]
+    fn product<I: Iterator<Item = Vec3>>(iter: I) -> Vec3 {
+        iter.fold(Vec3::ONE, |acc, v| acc * v)
+    }
+}
+
+impl<'a> Product<&'a Vec3> for Vec3 {
+    #[inline]
+    fn product<I: Iterator<Item = &'a Vec3>>(iter: I) -> Vec3 {
+        iter.fold(Vec3::ONE, |acc, v| acc * v)
+    }
+}
+
+/// Takes the first 3 items as the components, ignoring any after them
+/// Panics if the iterator runs out first
+impl FromIterator<f32> for Vec3 {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = f32>>(iter: I) -> Vec3 {
+        let mut iter = iter.into_iter();
+        let mut next = || iter.next().expect("too few items to build a Vec3");
+        Vec3::new(next(), next(), next())
+    }
+}
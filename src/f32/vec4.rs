@@ -1,7 +1,22 @@
 use crate::{
+    validate::{
+        nan_guard,
+        validate_finite,
+    },
+    Axis,
+    BVec4,
+    Vec3,
     VecExt,
 };
 use std::{
+    slice::{
+        Iter,
+        IterMut,
+    },
+    iter::{
+        Sum,
+        Product,
+    },
     fmt::{
         self,
         Debug,
@@ -30,6 +45,7 @@ use std::{
 
 /// A vector in 3-space
 #[derive(Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Vec4 {
     pub x: f32,
@@ -44,6 +60,28 @@ impl Vec4 {
     /// The default Vec4 with all 0's
     pub const ZERO: Vec4 = Vec4::splat(0.0);
 
+    /// All 1's
+    pub const ONE: Vec4 = Vec4::splat(1.0);
+
+    /// All -1's
+    pub const NEG_ONE: Vec4 = Vec4::splat(-1.0);
+
+    /// All f32::MIN, the most negative finite value
+    pub const MIN: Vec4 = Vec4::splat(f32::MIN);
+
+    /// All f32::MAX, the largest finite value
+    pub const MAX: Vec4 = Vec4::splat(f32::MAX);
+
+    /// All positive infinity, handy for seeding a running minimum
+    pub const INFINITY: Vec4 = Vec4 { x: f32::INFINITY, y: f32::INFINITY, z: f32::INFINITY, w: f32::INFINITY };
+
+    /// All negative infinity, handy for seeding a running maximum
+    pub const NEG_INFINITY: Vec4 = Vec4 { x: f32::NEG_INFINITY, y: f32::NEG_INFINITY, z: f32::NEG_INFINITY, w: f32::NEG_INFINITY };
+
+    /// All NaN
+    /// Built directly so it is available even with the validate feature, which would reject it in new
+    pub const NAN: Vec4 = Vec4 { x: f32::NAN, y: f32::NAN, z: f32::NAN, w: f32::NAN };
+
     /// The positive x-axis basis vector
     pub const X: Vec4 = Vec4::new(1.0, 0.0, 0.0, 0.0);
 
@@ -68,10 +106,14 @@ impl Vec4 {
     /// The negative w-axis basis vector
     pub const NEG_W: Vec4 = Vec4::new(0.0, 0.0, 0.0, -1.0);
 
+    /// The positive basis vectors, in axis order
+    pub const AXES: [Vec4; 4] = [Vec4::X, Vec4::Y, Vec4::Z, Vec4::W];
+
 
     /// Standard constructor for <x y z w>
     #[inline]
     pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Vec4 {
+        validate_finite!("Vec4::new", x, y, z, w);
         Vec4 { x, y, z, w }
     }
 
@@ -80,6 +122,176 @@ impl Vec4 {
         Vec4::new(v, v, v, v)
     }
 
+    /// Returns a copy of self with the x component replaced
+    #[inline]
+    pub const fn with_x(mut self, x: f32) -> Vec4 {
+        self.x = x;
+        self
+    }
+
+    /// Returns a copy of self with the y component replaced
+    #[inline]
+    pub const fn with_y(mut self, y: f32) -> Vec4 {
+        self.y = y;
+        self
+    }
+
+    /// Returns a copy of self with the z component replaced
+    #[inline]
+    pub const fn with_z(mut self, z: f32) -> Vec4 {
+        self.z = z;
+        self
+    }
+
+    /// Returns a copy of self with the w component replaced
+    #[inline]
+    pub const fn with_w(mut self, w: f32) -> Vec4 {
+        self.w = w;
+        self
+    }
+
+    /// Drops w to make a Vec3
+    /// Does not divide by w, see project_point3 on Mat4 for that
+    #[inline]
+    pub const fn truncate(self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    /// Componentwise self + rhs, usable in const contexts
+    #[inline]
+    pub const fn const_add(self, rhs: Vec4) -> Vec4 {
+        Vec4::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z, self.w + rhs.w)
+    }
+
+    /// Componentwise self - rhs, usable in const contexts
+    #[inline]
+    pub const fn const_sub(self, rhs: Vec4) -> Vec4 {
+        Vec4::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z, self.w - rhs.w)
+    }
+
+    /// self * rhs, usable in const contexts
+    #[inline]
+    pub const fn const_mul(self, rhs: f32) -> Vec4 {
+        Vec4::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+
+    /// Componentwise self * rhs, usable in const contexts
+    #[inline]
+    pub const fn const_mul_vec(self, rhs: Vec4) -> Vec4 {
+        Vec4::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z, self.w * rhs.w)
+    }
+
+    /// -self, usable in const contexts
+    #[inline]
+    pub const fn const_neg(self) -> Vec4 {
+        Vec4::new(-self.x, -self.y, -self.z, -self.w)
+    }
+
+    /// Dot product, usable in const contexts
+    #[inline]
+    pub const fn const_dot(self, rhs: Vec4) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// Returns the positive basis vector along axis
+    /// Panics if axis is not one of the 4 axes of a Vec4
+    #[inline]
+    pub fn axis(axis: Axis) -> Vec4 {
+        let mut v = Vec4::ZERO;
+        v[axis] = 1.0;
+        v
+    }
+
+    /// Returns the component along axis, the same as self[axis]
+    /// Panics if axis is not one of the 4 axes of a Vec4
+    #[inline]
+    pub fn get_axis(self, axis: Axis) -> f32 {
+        self[axis]
+    }
+
+    /// Sets the component along axis, the same as self[axis] = v
+    /// Panics if axis is not one of the 4 axes of a Vec4
+    #[inline]
+    pub fn set_axis(&mut self, axis: Axis, v: f32) {
+        self[axis] = v;
+    }
+
+    /// The axis of the component with the largest magnitude, the first one on ties
+    /// Picks the projection plane for a normal, or the longest side to split a box along
+    /// Requires: no component should be NaN
+    #[inline]
+    pub fn dominant_axis(self) -> Axis {
+        Axis::XYZW[self.abs().max_position()]
+    }
+
+    /// The mask of components of self equal to those of rhs
+    #[inline]
+    pub fn cmpeq(self, rhs: Vec4) -> BVec4 {
+        BVec4::from(VecExt::cmpeq(self, rhs))
+    }
+
+    /// The mask of components of self not equal to those of rhs
+    #[inline]
+    pub fn cmpne(self, rhs: Vec4) -> BVec4 {
+        BVec4::from(VecExt::cmpne(self, rhs))
+    }
+
+    /// The mask of components of self less than those of rhs
+    #[inline]
+    pub fn cmplt(self, rhs: Vec4) -> BVec4 {
+        BVec4::from(VecExt::cmplt(self, rhs))
+    }
+
+    /// The mask of components of self less than or equal to those of rhs
+    #[inline]
+    pub fn cmple(self, rhs: Vec4) -> BVec4 {
+        BVec4::from(VecExt::cmple(self, rhs))
+    }
+
+    /// The mask of components of self greater than those of rhs
+    #[inline]
+    pub fn cmpgt(self, rhs: Vec4) -> BVec4 {
+        BVec4::from(VecExt::cmpgt(self, rhs))
+    }
+
+    /// The mask of components of self greater than or equal to those of rhs
+    #[inline]
+    pub fn cmpge(self, rhs: Vec4) -> BVec4 {
+        BVec4::from(VecExt::cmpge(self, rhs))
+    }
+
+    /// Picks the component from if_true where mask is true, otherwise from if_false, like a shader's select
+    #[inline]
+    pub fn select(mask: BVec4, if_true: Vec4, if_false: Vec4) -> Vec4 {
+        <Vec4 as VecExt<4>>::select(mask, if_true, if_false)
+    }
+
+
+    /// Mutable view of the x and y components
+    #[inline]
+    pub fn xy_mut(&mut self) -> &mut [f32; 2] {
+        self.as_mut().first_chunk_mut().unwrap()
+    }
+
+    /// Mutable view of the z and w components
+    #[inline]
+    pub fn zw_mut(&mut self) -> &mut [f32; 2] {
+        self.as_mut().last_chunk_mut().unwrap()
+    }
+
+    /// Mutable view of the x, y, and z components
+    #[inline]
+    pub fn xyz_mut(&mut self) -> &mut [f32; 3] {
+        self.as_mut().first_chunk_mut().unwrap()
+    }
+
+    /// Mutable references to the x and z components
+    #[inline]
+    pub fn xz_mut(&mut self) -> (&mut f32, &mut f32) {
+        let [x, _, z, _] = self.as_mut();
+        (x, z)
+    }
+
 
     /// Clamps the x value of Vec4
     /// Requires: min < max
@@ -167,12 +379,14 @@ impl Add<Vec4> for Vec4 {
     type Output = Vec4;
     #[inline]
     fn add(self, rhs: Vec4) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self.x + rhs.x,
             self.y + rhs.y,
             self.z + rhs.z,
             self.w + rhs.w,
-        )
+        );
+        nan_guard!("Vec4 + Vec4", out, self, rhs);
+        out
     }
 }
 impl Add<&Vec4> for Vec4 {
@@ -202,12 +416,14 @@ impl Add<f32> for Vec4 {
     type Output = Vec4;
     #[inline]
     fn add(self, rhs: f32) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self.x + rhs,
             self.y + rhs,
             self.z + rhs,
             self.w + rhs,
-        )
+        );
+        nan_guard!("Vec4 + f32", out, self, rhs);
+        out
     }
 }
 impl Add<&f32> for Vec4 {
@@ -237,12 +453,14 @@ impl Add<Vec4> for f32 {
     type Output = Vec4;
     #[inline]
     fn add(self, rhs: Vec4) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self + rhs.x,
             self + rhs.y,
             self + rhs.z,
             self + rhs.w,
-        )
+        );
+        nan_guard!("f32 + Vec4", out, self, rhs);
+        out
     }
 }
 impl Add<&Vec4> for f32 {
@@ -271,10 +489,7 @@ impl Add<&Vec4> for &f32 {
 impl AddAssign<Vec4> for Vec4 {
     #[inline]
     fn add_assign(&mut self, rhs: Vec4) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
-        self.w += rhs.w;
+        *self = *self + rhs;
     }
 }
 impl AddAssign<&Vec4> for Vec4 {
@@ -288,10 +503,7 @@ impl AddAssign<&Vec4> for Vec4 {
 impl AddAssign<f32> for Vec4 {
     #[inline]
     fn add_assign(&mut self, rhs: f32) {
-        self.x += rhs;
-        self.y += rhs;
-        self.z += rhs;
-        self.w += rhs;
+        *self = *self + rhs;
     }
 }
 impl AddAssign<&f32> for Vec4 {
@@ -307,12 +519,14 @@ impl Sub<Vec4> for Vec4 {
     type Output = Vec4;
     #[inline]
     fn sub(self, rhs: Vec4) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self.x - rhs.x,
             self.y - rhs.y,
             self.z - rhs.z,
             self.w - rhs.w,
-        )
+        );
+        nan_guard!("Vec4 - Vec4", out, self, rhs);
+        out
     }
 }
 impl Sub<&Vec4> for Vec4 {
@@ -342,12 +556,14 @@ impl Sub<f32> for Vec4 {
     type Output = Vec4;
     #[inline]
     fn sub(self, rhs: f32) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self.x - rhs,
             self.y - rhs,
             self.z - rhs,
             self.w - rhs,
-        )
+        );
+        nan_guard!("Vec4 - f32", out, self, rhs);
+        out
     }
 }
 impl Sub<&f32> for Vec4 {
@@ -377,12 +593,14 @@ impl Sub<Vec4> for f32 {
     type Output = Vec4;
     #[inline]
     fn sub(self, rhs: Vec4) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self - rhs.x,
             self - rhs.y,
             self - rhs.z,
             self - rhs.w,
-        )
+        );
+        nan_guard!("f32 - Vec4", out, self, rhs);
+        out
     }
 }
 impl Sub<&Vec4> for f32 {
@@ -411,10 +629,7 @@ impl Sub<&Vec4> for &f32 {
 impl SubAssign<Vec4> for Vec4 {
     #[inline]
     fn sub_assign(&mut self, rhs: Vec4) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-        self.z -= rhs.z;
-        self.w -= rhs.w;
+        *self = *self - rhs;
     }
 }
 impl SubAssign<&Vec4> for Vec4 {
@@ -428,10 +643,7 @@ impl SubAssign<&Vec4> for Vec4 {
 impl SubAssign<f32> for Vec4 {
     #[inline]
     fn sub_assign(&mut self, rhs: f32) {
-        self.x -= rhs;
-        self.y -= rhs;
-        self.z -= rhs;
-        self.w -= rhs;
+        *self = *self - rhs;
     }
 }
 impl SubAssign<&f32> for Vec4 {
@@ -447,12 +659,14 @@ impl Mul<Vec4> for Vec4 {
     type Output = Vec4;
     #[inline]
     fn mul(self, rhs: Vec4) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self.x * rhs.x,
             self.y * rhs.y,
             self.z * rhs.z,
             self.w * rhs.w
-        )
+        );
+        nan_guard!("Vec4 * Vec4", out, self, rhs);
+        out
     }
 }
 impl Mul<&Vec4> for Vec4 {
@@ -482,12 +696,14 @@ impl Mul<f32> for Vec4 {
     type Output = Vec4;
     #[inline]
     fn mul(self, rhs: f32) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self.x * rhs,
             self.y * rhs,
             self.z * rhs,
             self.w * rhs,
-        )
+        );
+        nan_guard!("Vec4 * f32", out, self, rhs);
+        out
     }
 }
 impl Mul<&f32> for Vec4 {
@@ -517,12 +733,14 @@ impl Mul<Vec4> for f32 {
     type Output = Vec4;
     #[inline]
     fn mul(self, rhs: Vec4) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self * rhs.x,
             self * rhs.y,
             self * rhs.z,
             self * rhs.w,
-        )
+        );
+        nan_guard!("f32 * Vec4", out, self, rhs);
+        out
     }
 }
 impl Mul<&Vec4> for f32 {
@@ -551,10 +769,7 @@ impl Mul<&Vec4> for &f32 {
 impl MulAssign<Vec4> for Vec4 {
     #[inline]
     fn mul_assign(&mut self, rhs: Vec4) {
-        self.x *= rhs.x;
-        self.y *= rhs.y;
-        self.z *= rhs.z;
-        self.w *= rhs.w;
+        *self = *self * rhs;
     }
 }
 impl MulAssign<&Vec4> for Vec4 {
@@ -568,10 +783,7 @@ impl MulAssign<&Vec4> for Vec4 {
 impl MulAssign<f32> for Vec4 {
     #[inline]
     fn mul_assign(&mut self, rhs: f32) {
-        self.x *= rhs;
-        self.y *= rhs;
-        self.z *= rhs;
-        self.w *= rhs;
+        *self = *self * rhs;
     }
 }
 impl MulAssign<&f32> for Vec4 {
@@ -587,12 +799,14 @@ impl Div<Vec4> for Vec4 {
     type Output = Vec4;
     #[inline]
     fn div(self, rhs: Vec4) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self.x / rhs.x,
             self.y / rhs.y,
             self.z / rhs.z,
             self.w / rhs.w,
-        )
+        );
+        nan_guard!("Vec4 / Vec4", out, self, rhs);
+        out
     }
 }
 impl Div<&Vec4> for Vec4 {
@@ -622,12 +836,14 @@ impl Div<f32> for Vec4 {
     type Output = Vec4;
     #[inline]
     fn div(self, rhs: f32) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self.x / rhs,
             self.y / rhs,
             self.z / rhs,
             self.w / rhs,
-        )
+        );
+        nan_guard!("Vec4 / f32", out, self, rhs);
+        out
     }
 }
 impl Div<&f32> for Vec4 {
@@ -657,12 +873,14 @@ impl Div<Vec4> for f32 {
     type Output = Vec4;
     #[inline]
     fn div(self, rhs: Vec4) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self / rhs.x,
             self / rhs.y,
             self / rhs.z,
             self / rhs.w,
-        )
+        );
+        nan_guard!("f32 / Vec4", out, self, rhs);
+        out
     }
 }
 impl Div<&Vec4> for f32 {
@@ -691,10 +909,7 @@ impl Div<&Vec4> for &f32 {
 impl DivAssign<Vec4> for Vec4 {
     #[inline]
     fn div_assign(&mut self, rhs: Vec4) {
-        self.x /= rhs.x;
-        self.y /= rhs.y;
-        self.z /= rhs.z;
-        self.w /= rhs.w;
+        *self = *self / rhs;
     }
 }
 impl DivAssign<&Vec4> for Vec4 {
@@ -708,10 +923,7 @@ impl DivAssign<&Vec4> for Vec4 {
 impl DivAssign<f32> for Vec4 {
     #[inline]
     fn div_assign(&mut self, rhs: f32) {
-        self.x /= rhs;
-        self.y /= rhs;
-        self.z /= rhs;
-        self.w /= rhs;
+        *self = *self / rhs;
     }
 }
 impl DivAssign<&f32> for Vec4 {
@@ -727,12 +939,14 @@ impl Rem<Vec4> for Vec4 {
     type Output = Vec4;
     #[inline]
     fn rem(self, rhs: Vec4) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self.x % rhs.x,
             self.y % rhs.y,
             self.z % rhs.z,
             self.w % rhs.w,
-        )
+        );
+        nan_guard!("Vec4 % Vec4", out, self, rhs);
+        out
     }
 }
 impl Rem<&Vec4> for Vec4 {
@@ -762,12 +976,14 @@ impl Rem<f32> for Vec4 {
     type Output = Vec4;
     #[inline]
     fn rem(self, rhs: f32) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self.x % rhs,
             self.y % rhs,
             self.z % rhs,
             self.w % rhs,
-        )
+        );
+        nan_guard!("Vec4 % f32", out, self, rhs);
+        out
     }
 }
 impl Rem<&f32> for Vec4 {
@@ -797,12 +1013,14 @@ impl Rem<Vec4> for f32 {
     type Output = Vec4;
     #[inline]
     fn rem(self, rhs: Vec4) -> Self::Output {
-        Vec4::new(
+        let out = Vec4::new(
             self % rhs.x,
             self % rhs.y,
             self % rhs.z,
             self % rhs.w,
-        )
+        );
+        nan_guard!("f32 % Vec4", out, self, rhs);
+        out
     }
 }
 impl Rem<&Vec4> for f32 {
@@ -831,9 +1049,7 @@ impl Rem<&Vec4> for &f32 {
 impl RemAssign<Vec4> for Vec4 {
     #[inline]
     fn rem_assign(&mut self, rhs: Vec4) {
-        self.x %= rhs.x;
-        self.y %= rhs.y;
-        self.z %= rhs.z;
+        *self = *self % rhs;
     }
 }
 impl RemAssign<&Vec4> for Vec4 {
@@ -847,10 +1063,7 @@ impl RemAssign<&Vec4> for Vec4 {
 impl RemAssign<f32> for Vec4 {
     #[inline]
     fn rem_assign(&mut self, rhs: f32) {
-        self.x %= rhs;
-        self.y %= rhs;
-        self.z %= rhs;
-        self.w %= rhs;
+        *self = *self % rhs;
     }
 }
 impl RemAssign<&f32> for Vec4 {
@@ -906,6 +1119,21 @@ impl IndexMut<usize> for Vec4 {
     }
 }
 
+impl Index<Axis> for Vec4 {
+    type Output = f32;
+    #[inline]
+    fn index(&self, axis: Axis) -> &Self::Output {
+        &self[axis.index()]
+    }
+}
+
+impl IndexMut<Axis> for Vec4 {
+    #[inline]
+    fn index_mut(&mut self, axis: Axis) -> &mut Self::Output {
+        &mut self[axis.index()]
+    }
+}
+
 
 impl From<[f32; 4]> for Vec4 {
     #[inline]
@@ -920,16 +1148,16 @@ impl From<&[f32; 4]> for Vec4 {
     }
 }
 
-impl Into<[f32; 4]> for Vec4 {
+impl From<Vec4> for [f32; 4] {
     #[inline]
-    fn into(self) -> [f32; 4] {
-        [self.x, self.y, self.z, self.w]
+    fn from(v: Vec4) -> [f32; 4] {
+        [v.x, v.y, v.z, v.w]
     }
 }
-impl Into<[f32; 4]> for &Vec4 {
+impl From<&Vec4> for [f32; 4] {
     #[inline]
-    fn into(self) -> [f32; 4] {
-        [self.x, self.y, self.z, self.w]
+    fn from(v: &Vec4) -> [f32; 4] {
+        [v.x, v.y, v.z, v.w]
     }
 }
 
@@ -946,16 +1174,16 @@ impl From<&(f32, f32, f32, f32)> for Vec4 {
     }
 }
 
-impl Into<(f32, f32, f32, f32)> for Vec4 {
+impl From<Vec4> for (f32, f32, f32, f32) {
     #[inline]
-    fn into(self) -> (f32, f32, f32, f32) {
-        (self.x, self.y, self.z, self.w)
+    fn from(v: Vec4) -> (f32, f32, f32, f32) {
+        (v.x, v.y, v.z, v.w)
     }
 }
-impl Into<(f32, f32, f32, f32)> for &Vec4 {
+impl From<&Vec4> for (f32, f32, f32, f32) {
     #[inline]
-    fn into(self) -> (f32, f32, f32, f32) {
-        (self.x, self.y, self.z, self.w)
+    fn from(v: &Vec4) -> (f32, f32, f32, f32) {
+        (v.x, v.y, v.z, v.w)
     }
 }
 
@@ -973,3 +1201,62 @@ impl AsMut<[f32; 4]> for Vec4 {
         unsafe { &mut *(self as *mut Vec4 as *mut [f32; 4]) }
     }
 }
+
+
+impl<'a> IntoIterator for &'a Vec4 {
+    type Item = &'a f32;
+    type IntoIter = Iter<'a, f32>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Vec4 {
+    type Item = &'a mut f32;
+    type IntoIter = IterMut<'a, f32>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut().iter_mut()
+    }
+}
+
+
+impl Sum<Vec4> for Vec4 {
+    #[inline]
+    fn sum<I: Iterator<Item = Vec4>>(iter: I) -> Vec4 {
+        iter.fold(Vec4::ZERO, |acc, v| acc + v)
+    }
+}
+
+impl<'a> Sum<&'a Vec4> for Vec4 {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Vec4>>(iter: I) -> Vec4 {
+        iter.fold(Vec4::ZERO, |acc, v| acc + v)
+    }
+}
+
+impl Product<Vec4> for Vec4 {
+    #[inline]
+    fn product<I: Iterator<Item = Vec4>>(iter: I) -> Vec4 {
+        iter.fold(Vec4::ONE, |acc, v| acc * v)
+    }
+}
+
+impl<'a> Product<&'a Vec4> for Vec4 {
+    #[inline]
+    fn product<I: Iterator<Item = &'a Vec4>>(iter: I) -> Vec4 {
+        iter.fold(Vec4::ONE, |acc, v| acc * v)
+    }
+}
+
+/// Takes the first 4 items as the components, ignoring any after them
+/// Panics if the iterator runs out first
+impl FromIterator<f32> for Vec4 {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = f32>>(iter: I) -> Vec4 {
+        let mut iter = iter.into_iter();
+        let mut next = || iter.next().expect("too few items to build a Vec4");
+        Vec4::new(next(), next(), next(), next())
+    }
+}
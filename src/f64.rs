@@ -0,0 +1 @@
+pub use crate::scalar::F64Ext;
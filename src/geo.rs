@@ -0,0 +1,70 @@
+// Geographic coordinates on a sphere, generic over the scalar so planet-scale f64 vectors work too
+// Cartesian positions are y-up: the north pole is +y, and (lat 0, lon 0) is +z,
+// with longitude increasing eastward towards +x, the same as yaw
+// All angles are in radians
+
+use crate::{
+    Scalar,
+    VecExt,
+};
+
+
+/// Position on a sphere of the given radius centered on the origin
+#[inline]
+pub fn from_lat_lon<V, T>(lat: T, lon: T, radius: T) -> V
+where
+    V: VecExt<3, T>,
+    T: Scalar,
+{
+    let (sin_lat, cos_lat) = lat.sincos();
+    let (sin_lon, cos_lon) = lon.sincos();
+    V::from([cos_lat * sin_lon, sin_lat, cos_lat * cos_lon]) * radius
+}
+
+/// Position at altitude alt above a sphere of the given radius centered on the origin
+#[inline]
+pub fn from_lat_lon_alt<V, T>(lat: T, lon: T, alt: T, radius: T) -> V
+where
+    V: VecExt<3, T>,
+    T: Scalar,
+{
+    from_lat_lon(lat, lon, radius + alt)
+}
+
+/// Returns (lat, lon, alt) of p above a sphere of the given radius, the inverse of from_lat_lon_alt
+/// lat is in [-pi/2, pi/2] and lon in (-pi, pi], both are 0 at the origin
+#[inline]
+pub fn to_lat_lon_alt<V, T>(p: V, radius: T) -> (T, T, T)
+where
+    V: VecExt<3, T>,
+    T: Scalar,
+{
+    let [x, y, z] = p.to_array();
+    let r = p.length();
+    let lat = if r == T::ZERO { T::ZERO } else { (y / r).asin_clamped() };
+    (lat, x.atan2(z), r - radius)
+}
+
+/// Distance along the surface of a sphere between two points, using the haversine formula
+/// which stays accurate for nearby points
+#[inline]
+pub fn great_circle_distance<T: Scalar>(lat1: T, lon1: T, lat2: T, lon2: T, radius: T) -> T {
+    let sin_half_dlat = ((lat2 - lat1) * T::HALF).sincos().0;
+    let sin_half_dlon = ((lon2 - lon1) * T::HALF).sincos().0;
+    let cos_lat1 = lat1.sincos().1;
+    let cos_lat2 = lat2.sincos().1;
+    let h = sin_half_dlat * sin_half_dlat + cos_lat1 * cos_lat2 * sin_half_dlon * sin_half_dlon;
+    T::TWO * radius * h.sqrt().asin_clamped()
+}
+
+/// Initial heading to travel along the great circle from point 1 to point 2
+/// Measured clockwise from north, in (-pi, pi]
+#[inline]
+pub fn initial_bearing<T: Scalar>(lat1: T, lon1: T, lat2: T, lon2: T) -> T {
+    let (sin_dlon, cos_dlon) = (lon2 - lon1).sincos();
+    let (sin_lat1, cos_lat1) = lat1.sincos();
+    let (sin_lat2, cos_lat2) = lat2.sincos();
+    let y = sin_dlon * cos_lat2;
+    let x = cos_lat1 * sin_lat2 - sin_lat1 * cos_lat2 * cos_dlon;
+    y.atan2(x)
+}
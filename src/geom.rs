@@ -0,0 +1,34 @@
+pub mod plucker;
+pub use plucker::*;
+
+pub mod ray2;
+pub use ray2::*;
+
+pub mod ray3;
+pub use ray3::*;
+
+pub mod aabb2;
+pub use aabb2::*;
+
+pub mod aabb3;
+pub use aabb3::*;
+
+pub mod sphere;
+pub use sphere::*;
+
+pub mod plane;
+pub use plane::*;
+
+pub mod triangle;
+pub use triangle::*;
+
+pub mod segment2;
+pub use segment2::*;
+
+pub mod segment3;
+pub use segment3::*;
+
+pub mod capsule;
+pub use capsule::*;
+
+pub mod polygon2;
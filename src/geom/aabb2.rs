@@ -0,0 +1,181 @@
+use crate::{
+    Affine2,
+    Axis,
+    Vec2,
+    VecExt,
+};
+
+
+/// An axis-aligned rectangle given by its bottom-left and top-right corners
+/// Every query treats the boundary as inside, so boxes that only touch still intersect
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb2 {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl Aabb2 {
+    /// Contains nothing, with min at +inf and max at -inf so that expanding it to include a point gives just that point
+    pub const EMPTY: Aabb2 = Aabb2 { min: Vec2::INFINITY, max: Vec2::NEG_INFINITY };
+
+
+    /// Requires: min <= max in every component
+    #[inline]
+    pub const fn new(min: Vec2, max: Vec2) -> Aabb2 {
+        Aabb2 { min, max }
+    }
+
+    /// The box spanned by two opposite corners given in any order
+    #[inline]
+    pub fn from_corners(a: Vec2, b: Vec2) -> Aabb2 {
+        Aabb2::new(a.min_vec(b), a.max_vec(b))
+    }
+
+    /// Requires: half_extents should be non-negative
+    #[inline]
+    pub fn from_center_half_extents(center: Vec2, half_extents: Vec2) -> Aabb2 {
+        Aabb2::new(center - half_extents, center + half_extents)
+    }
+
+    /// Requires: size should be non-negative
+    #[inline]
+    pub fn from_center_size(center: Vec2, size: Vec2) -> Aabb2 {
+        Aabb2::from_center_half_extents(center, size * 0.5)
+    }
+
+    /// The smallest box containing every point, or None if there are none
+    #[inline]
+    pub fn from_points(points: &[Vec2]) -> Option<Aabb2> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Aabb2::new(*first, *first), |b, p| b.expand_to_include(*p)))
+    }
+
+
+    /// Checks if min > max along any axis, like EMPTY
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y
+    }
+
+    #[inline]
+    pub fn center(&self) -> Vec2 {
+        self.min.midpoint(self.max)
+    }
+
+    #[inline]
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+
+    #[inline]
+    pub fn half_extents(&self) -> Vec2 {
+        self.size() * 0.5
+    }
+
+    /// The axis the box is longest along, where to split it when building a BVH
+    /// Requires: self should not be empty
+    #[inline]
+    pub fn longest_axis(&self) -> Axis {
+        self.size().dominant_axis()
+    }
+
+    /// Requires: self should not be empty
+    #[inline]
+    pub fn area(&self) -> f32 {
+        self.size().product()
+    }
+
+    /// Requires: self should not be empty
+    #[inline]
+    pub fn perimeter(&self) -> f32 {
+        let s = self.size();
+        2.0 * (s.x + s.y)
+    }
+
+    /// The 4 corners, with x varying fastest
+    #[inline]
+    pub fn corners(&self) -> [Vec2; 4] {
+        [
+            Vec2::new(self.min.x, self.min.y),
+            Vec2::new(self.max.x, self.min.y),
+            Vec2::new(self.min.x, self.max.y),
+            Vec2::new(self.max.x, self.max.y),
+        ]
+    }
+
+
+    #[inline]
+    pub fn contains_point(&self, p: Vec2) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    /// Checks if other lies entirely inside self
+    #[inline]
+    pub fn contains_aabb(&self, other: &Aabb2) -> bool {
+        other.min.x >= self.min.x && other.max.x <= self.max.x && other.min.y >= self.min.y && other.max.y <= self.max.y
+    }
+
+    /// Checks if the boxes overlap or touch
+    #[inline]
+    pub fn intersects(&self, other: &Aabb2) -> bool {
+        self.min.x <= other.max.x && other.min.x <= self.max.x && self.min.y <= other.max.y && other.min.y <= self.max.y
+    }
+
+    /// The overlapping region, or None if the boxes are disjoint
+    #[inline]
+    pub fn intersection(&self, other: &Aabb2) -> Option<Aabb2> {
+        let overlap = Aabb2::new(self.min.max_vec(other.min), self.max.min_vec(other.max));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// The smallest box containing both
+    #[inline]
+    pub fn union(&self, other: &Aabb2) -> Aabb2 {
+        Aabb2::new(self.min.min_vec(other.min), self.max.max_vec(other.max))
+    }
+
+    /// The smallest box containing self and p
+    #[inline]
+    pub fn expand_to_include(&self, p: Vec2) -> Aabb2 {
+        Aabb2::new(self.min.min_vec(p), self.max.max_vec(p))
+    }
+
+    /// Grows every side outwards by margin, or shrinks them for a negative margin
+    #[inline]
+    pub fn inflate(&self, margin: f32) -> Aabb2 {
+        Aabb2::new(self.min - margin, self.max + margin)
+    }
+
+
+    /// The point in the box closest to p, p itself when it is inside
+    #[inline]
+    pub fn clamp_point(&self, p: Vec2) -> Vec2 {
+        p.clamp_vec(self.min, self.max)
+    }
+
+    /// The squared distance from p to the box, 0 when p is inside
+    #[inline]
+    pub fn distance_2_to_point(&self, p: Vec2) -> f32 {
+        self.clamp_point(p).distance_2(p)
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, p: Vec2) -> f32 {
+        self.distance_2_to_point(p).sqrt()
+    }
+
+
+    /// The box around self after transforming it by m, which is larger than self when m rotates
+    /// Transforms the center and sums the absolute columns of the matrix onto the half extents rather than all 4 corners
+    // Arvo, Transforming Axis-Aligned Bounding Boxes, Graphics Gems 1990
+    #[inline]
+    pub fn transform(&self, m: &Affine2) -> Aabb2 {
+        if self.is_empty() {
+            return *self;
+        }
+        let h = self.half_extents();
+        let half_extents = m.matrix2.x_axis.abs() * h.x + m.matrix2.y_axis.abs() * h.y;
+        Aabb2::from_center_half_extents(m.transform_point2(self.center()), half_extents)
+    }
+}
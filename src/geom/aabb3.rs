@@ -0,0 +1,186 @@
+use crate::{
+    Affine3,
+    Axis,
+    Vec3,
+    VecExt,
+};
+
+
+/// An axis-aligned box given by its lowest and highest corners
+/// Every query treats the boundary as inside, so boxes that only touch still intersect
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb3 {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb3 {
+    /// Contains nothing, with min at +inf and max at -inf so that expanding it to include a point gives just that point
+    pub const EMPTY: Aabb3 = Aabb3 { min: Vec3::INFINITY, max: Vec3::NEG_INFINITY };
+
+
+    /// Requires: min <= max in every component
+    #[inline]
+    pub const fn new(min: Vec3, max: Vec3) -> Aabb3 {
+        Aabb3 { min, max }
+    }
+
+    /// The box spanned by two opposite corners given in any order
+    #[inline]
+    pub fn from_corners(a: Vec3, b: Vec3) -> Aabb3 {
+        Aabb3::new(a.min_vec(b), a.max_vec(b))
+    }
+
+    /// Requires: half_extents should be non-negative
+    #[inline]
+    pub fn from_center_half_extents(center: Vec3, half_extents: Vec3) -> Aabb3 {
+        Aabb3::new(center - half_extents, center + half_extents)
+    }
+
+    /// Requires: size should be non-negative
+    #[inline]
+    pub fn from_center_size(center: Vec3, size: Vec3) -> Aabb3 {
+        Aabb3::from_center_half_extents(center, size * 0.5)
+    }
+
+    /// The smallest box containing every point, or None if there are none
+    #[inline]
+    pub fn from_points(points: &[Vec3]) -> Option<Aabb3> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Aabb3::new(*first, *first), |b, p| b.expand_to_include(*p)))
+    }
+
+
+    /// Checks if min > max along any axis, like EMPTY
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    #[inline]
+    pub fn center(&self) -> Vec3 {
+        self.min.midpoint(self.max)
+    }
+
+    #[inline]
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    #[inline]
+    pub fn half_extents(&self) -> Vec3 {
+        self.size() * 0.5
+    }
+
+    /// The axis the box is longest along, where to split it when building a BVH
+    /// Requires: self should not be empty
+    #[inline]
+    pub fn longest_axis(&self) -> Axis {
+        self.size().dominant_axis()
+    }
+
+    /// Requires: self should not be empty
+    #[inline]
+    pub fn volume(&self) -> f32 {
+        self.size().product()
+    }
+
+    /// The total area of the six faces, the usual cost metric when building a BVH
+    /// Requires: self should not be empty
+    #[inline]
+    pub fn surface_area(&self) -> f32 {
+        let s = self.size();
+        2.0 * (s.x * s.y + s.y * s.z + s.z * s.x)
+    }
+
+    /// The 8 corners, with x varying fastest, then y
+    #[inline]
+    pub fn corners(&self) -> [Vec3; 8] {
+        [
+            Vec3::new(self.min.x, self.min.y, self.min.z),
+            Vec3::new(self.max.x, self.min.y, self.min.z),
+            Vec3::new(self.min.x, self.max.y, self.min.z),
+            Vec3::new(self.max.x, self.max.y, self.min.z),
+            Vec3::new(self.min.x, self.min.y, self.max.z),
+            Vec3::new(self.max.x, self.min.y, self.max.z),
+            Vec3::new(self.min.x, self.max.y, self.max.z),
+            Vec3::new(self.max.x, self.max.y, self.max.z),
+        ]
+    }
+
+
+    #[inline]
+    pub fn contains_point(&self, p: Vec3) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y && p.z >= self.min.z && p.z <= self.max.z
+    }
+
+    /// Checks if other lies entirely inside self
+    #[inline]
+    pub fn contains_aabb(&self, other: &Aabb3) -> bool {
+        other.min.x >= self.min.x && other.max.x <= self.max.x && other.min.y >= self.min.y && other.max.y <= self.max.y && other.min.z >= self.min.z && other.max.z <= self.max.z
+    }
+
+    /// Checks if the boxes overlap or touch
+    #[inline]
+    pub fn intersects(&self, other: &Aabb3) -> bool {
+        self.min.x <= other.max.x && other.min.x <= self.max.x && self.min.y <= other.max.y && other.min.y <= self.max.y && self.min.z <= other.max.z && other.min.z <= self.max.z
+    }
+
+    /// The overlapping region, or None if the boxes are disjoint
+    #[inline]
+    pub fn intersection(&self, other: &Aabb3) -> Option<Aabb3> {
+        let overlap = Aabb3::new(self.min.max_vec(other.min), self.max.min_vec(other.max));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// The smallest box containing both
+    #[inline]
+    pub fn union(&self, other: &Aabb3) -> Aabb3 {
+        Aabb3::new(self.min.min_vec(other.min), self.max.max_vec(other.max))
+    }
+
+    /// The smallest box containing self and p
+    #[inline]
+    pub fn expand_to_include(&self, p: Vec3) -> Aabb3 {
+        Aabb3::new(self.min.min_vec(p), self.max.max_vec(p))
+    }
+
+    /// Grows every side outwards by margin, or shrinks them for a negative margin
+    #[inline]
+    pub fn inflate(&self, margin: f32) -> Aabb3 {
+        Aabb3::new(self.min - margin, self.max + margin)
+    }
+
+
+    /// The point in the box closest to p, p itself when it is inside
+    #[inline]
+    pub fn clamp_point(&self, p: Vec3) -> Vec3 {
+        p.clamp_vec(self.min, self.max)
+    }
+
+    /// The squared distance from p to the box, 0 when p is inside
+    #[inline]
+    pub fn distance_2_to_point(&self, p: Vec3) -> f32 {
+        self.clamp_point(p).distance_2(p)
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, p: Vec3) -> f32 {
+        self.distance_2_to_point(p).sqrt()
+    }
+
+
+    /// The box around self after transforming it by m, which is larger than self when m rotates
+    /// Transforms the center and sums the absolute columns of the matrix onto the half extents rather than all 8 corners
+    // Arvo, Transforming Axis-Aligned Bounding Boxes, Graphics Gems 1990
+    #[inline]
+    pub fn transform(&self, m: &Affine3) -> Aabb3 {
+        if self.is_empty() {
+            return *self;
+        }
+        let h = self.half_extents();
+        let half_extents = m.matrix3.x_axis.abs() * h.x + m.matrix3.y_axis.abs() * h.y + m.matrix3.z_axis.abs() * h.z;
+        Aabb3::from_center_half_extents(m.transform_point3(self.center()), half_extents)
+    }
+}
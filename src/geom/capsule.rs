@@ -0,0 +1,59 @@
+use crate::{
+    geom::{
+        Aabb3,
+        Segment3,
+        Sphere,
+    },
+    Vec3,
+};
+
+
+/// Every point within radius of segment, a cylinder with hemispherical caps
+/// The surface counts as inside, so capsules that only touch still intersect
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capsule {
+    pub segment: Segment3,
+    pub radius: f32,
+}
+
+impl Capsule {
+    /// Requires: radius should be non-negative
+    #[inline]
+    pub const fn new(segment: Segment3, radius: f32) -> Capsule {
+        Capsule { segment, radius }
+    }
+
+    /// The capsule with its segment running between the centers of the caps a and b
+    #[inline]
+    pub const fn from_points(a: Vec3, b: Vec3, radius: f32) -> Capsule {
+        Capsule::new(Segment3::new(a, b), radius)
+    }
+
+    #[inline]
+    pub fn contains_point(&self, p: Vec3) -> bool {
+        self.segment.distance_to_point(p) <= self.radius
+    }
+
+    #[inline]
+    pub fn intersects_capsule(&self, other: &Capsule) -> bool {
+        self.segment.distance_to_segment(&other.segment) <= self.radius + other.radius
+    }
+
+    #[inline]
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.segment.distance_to_point(sphere.center) <= self.radius + sphere.radius
+    }
+
+    /// Checks if the capsule overlaps or touches the box
+    #[inline]
+    pub fn intersects_aabb(&self, aabb: &Aabb3) -> bool {
+        self.segment.distance_2_to_aabb(aabb) <= self.radius * self.radius
+    }
+
+    /// The smallest box containing the capsule
+    #[inline]
+    pub fn aabb(&self) -> Aabb3 {
+        Aabb3::from_corners(self.segment.a, self.segment.b).inflate(self.radius)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    Dir3,
+    MathError,
+    Vec3,
+    VecExt,
+};
+
+
+/// The plane of points p where normal · p + d = 0
+/// Points on the side normal points to have positive signed distance
+/// Planes built from points or a Dir3 are normalized, ones extracted from matrices usually need normalize first
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane {
+    pub normal: Vec3,
+    pub d: f32,
+}
+
+impl Plane {
+    #[inline]
+    pub const fn new(normal: Vec3, d: f32) -> Plane {
+        Plane { normal, d }
+    }
+
+    /// The plane through point facing along normal
+    #[inline]
+    pub fn from_point_normal(point: Vec3, normal: Dir3) -> Plane {
+        Plane::new(*normal, -normal.dot(point))
+    }
+
+    /// The plane through a, b and c, facing along (b - a) × (c - a)
+    /// Errors with Degenerate if the points are collinear or coincident
+    #[inline]
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Result<Plane, MathError> {
+        let normal = Dir3::new((b - a).cross(c - a)).map_err(|_| MathError::Degenerate)?;
+        Ok(Plane::from_point_normal(a, normal))
+    }
+
+    /// Scales normal to unit length, and d along with it so the plane stays put
+    /// Requires: normal should be non-zero
+    #[inline]
+    pub fn normalize(&self) -> Plane {
+        let inv_len = self.normal.length_recip();
+        Plane::new(self.normal * inv_len, self.d * inv_len)
+    }
+
+
+    /// The distance from the plane to p, negative behind it
+    /// Requires: self should be normalized, otherwise the result is scaled by the length of normal
+    #[inline]
+    pub fn signed_distance(&self, p: Vec3) -> f32 {
+        self.normal.dot(p) + self.d
+    }
+
+    /// The closest point on the plane to p
+    /// Requires: self should be normalized
+    #[inline]
+    pub fn project_point(&self, p: Vec3) -> Vec3 {
+        p - self.normal * self.signed_distance(p)
+    }
+
+    /// Some point on the plane, the one closest to the origin
+    /// Requires: self should be normalized
+    #[inline]
+    pub fn point(&self) -> Vec3 {
+        self.normal * -self.d
+    }
+
+    /// The same plane facing the other way
+    #[inline]
+    pub fn flip(&self) -> Plane {
+        Plane::new(-self.normal, -self.d)
+    }
+}
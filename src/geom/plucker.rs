@@ -0,0 +1,90 @@
+use crate::{
+    Vec3,
+    VecExt,
+};
+
+
+/// An infinite directed line in Plücker coordinates
+/// direction is the line's direction, moment is p x direction for any point p on the line
+/// Relative orientation tests between lines need no division, which keeps edge tests robust
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PluckerLine {
+    pub direction: Vec3,
+    pub moment: Vec3,
+}
+
+impl PluckerLine {
+    /// The line through p and q, directed from p to q
+    #[inline]
+    pub fn from_points(p: Vec3, q: Vec3) -> PluckerLine {
+        PluckerLine::from_point_dir(p, q - p)
+    }
+
+    /// The line through p along dir
+    #[inline]
+    pub fn from_point_dir(p: Vec3, dir: Vec3) -> PluckerLine {
+        PluckerLine { direction: dir, moment: p.cross(dir) }
+    }
+
+    /// The permuted inner product of self and other
+    /// 0 if the lines intersect or are parallel, otherwise the sign says which way
+    /// other passes self, and flips if either line is reversed
+    #[inline]
+    pub fn side(&self, other: &PluckerLine) -> f32 {
+        self.direction.dot(other.moment) + other.direction.dot(self.moment)
+    }
+
+    /// The point on the line closest to the origin
+    /// Requires: direction should be non-zero
+    #[inline]
+    pub fn closest_point_to_origin(&self) -> Vec3 {
+        self.direction.cross(self.moment) / self.direction.length_2()
+    }
+
+    /// Shortest distance between the two lines
+    /// Requires: both directions should be non-zero
+    #[inline]
+    pub fn distance(&self, other: &PluckerLine) -> f32 {
+        let cross = self.direction.cross(other.direction);
+        let cross_len_2 = cross.length_2();
+        // relative to the directions so the parallel check does not depend on their lengths
+        if cross_len_2 > f32::EPSILON * self.direction.length_2() * other.direction.length_2() {
+            self.side(other).abs() / cross_len_2.sqrt()
+        } else {
+            let offset = other.closest_point_to_origin() - self.closest_point_to_origin();
+            offset.cross(self.direction).length() / self.direction.length()
+        }
+    }
+
+    /// Checks if the line passes through the convex polygon with the given vertices in order
+    /// Either winding works, and hits exactly on an edge count
+    /// Fewer than 3 vertices is not a polygon, and nothing passes through it
+    #[inline]
+    pub fn intersects_polygon(&self, vertices: &[Vec3]) -> bool {
+        if vertices.len() < 3 {
+            return false;
+        }
+        let mut any_pos = false;
+        let mut any_neg = false;
+        for (i, &a) in vertices.iter().enumerate() {
+            let b = vertices[(i + 1) % vertices.len()];
+            let s = self.side(&PluckerLine::from_points(a, b));
+            any_pos |= s > 0.0;
+            any_neg |= s < 0.0;
+        }
+        !(any_pos && any_neg)
+    }
+
+    /// Checks if the line passes through the convex quad a, b, c, d
+    #[inline]
+    pub fn intersects_quad(&self, quad: [Vec3; 4]) -> bool {
+        self.intersects_polygon(&quad)
+    }
+
+    /// Checks if the line passes through the triangle a, b, c
+    #[inline]
+    pub fn intersects_triangle(&self, tri: [Vec3; 3]) -> bool {
+        self.intersects_polygon(&tri)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::Vec2;
+
+
+/// Checks if p is inside the polygon with the given vertices in order, either winding, the last connecting back to the first
+/// Points exactly on an edge or vertex count as inside
+/// Self-intersecting polygons use the nonzero rule, and fewer than 3 vertices only contain points on their edges
+// Sunday's winding number, with the on-edge test done first and exactly so boundary points never depend on
+// which way the half-open crossing rule happens to round
+#[inline]
+pub fn contains_point(vertices: &[Vec2], p: Vec2) -> bool {
+    let Some(&last) = vertices.last() else {
+        return false;
+    };
+    let mut winding = 0i32;
+    let mut a = last;
+    for &b in vertices {
+        // > 0 when p is left of the edge from a to b
+        let side = (b - a).perp_dot(p - a);
+        if side == 0.0
+            && p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x)
+            && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+        {
+            return true;
+        }
+        // each edge covers the half-open span [low y, high y), so a ray through a vertex counts it once
+        if a.y <= p.y {
+            if b.y > p.y && side > 0.0 {
+                winding += 1;
+            }
+        } else if b.y <= p.y && side < 0.0 {
+            winding -= 1;
+        }
+        a = b;
+    }
+    winding != 0
+}
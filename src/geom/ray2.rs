@@ -0,0 +1,151 @@
+use crate::{
+    geom::{
+        Aabb2,
+        Segment2,
+    },
+    Affine2,
+    Vec2,
+    VecExt,
+};
+
+
+/// A half-line in the plane starting at origin and extending along dir, the 2D counterpart of Ray3
+/// dir need not be normalized, t values are measured in multiples of it
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Ray2Data", into = "Ray2Data"),
+)]
+pub struct Ray2 {
+    pub origin: Vec2,
+    dir: Vec2,
+    inv_dir: Vec2,
+}
+
+impl Ray2 {
+    /// Requires: dir should be non-zero
+    #[inline]
+    pub fn new(origin: Vec2, dir: Vec2) -> Ray2 {
+        Ray2 { origin, dir, inv_dir: Ray2::recip(dir) }
+    }
+
+    /// The ray from `from` through `to`, so at(1.0) is `to`
+    /// Requires: from != to
+    #[inline]
+    pub fn from_points(from: Vec2, to: Vec2) -> Ray2 {
+        Ray2::new(from, to - from)
+    }
+
+    #[inline]
+    pub fn dir(&self) -> Vec2 {
+        self.dir
+    }
+
+    /// Replaces the direction, keeping the cached reciprocal in sync
+    #[inline]
+    pub fn set_dir(&mut self, dir: Vec2) {
+        self.dir = dir;
+        self.inv_dir = Ray2::recip(dir);
+    }
+
+    /// The componentwise reciprocal of dir, infinite for zero components
+    #[inline]
+    pub fn inverse_dir(&self) -> Vec2 {
+        self.inv_dir
+    }
+
+    /// The point origin + dir * t
+    #[inline]
+    pub fn at(&self, t: f32) -> Vec2 {
+        self.origin + self.dir * t
+    }
+
+    /// Moves the ray into the space of m
+    /// dir is not renormalized, so a hit at t in one space is at the same t in the other
+    #[inline]
+    pub fn transform(&self, m: &Affine2) -> Ray2 {
+        Ray2::new(m.transform_point2(self.origin), m.transform_vector2(self.dir))
+    }
+
+
+    /// The t where the ray crosses the segment, or None if it misses
+    /// A ray running along a collinear segment is treated as a miss
+    #[inline]
+    pub fn intersect_segment(&self, segment: &Segment2) -> Option<f32> {
+        let edge = segment.b - segment.a;
+        let denom = self.dir.perp_dot(edge);
+        if denom == 0.0 {
+            return None;
+        }
+        let to_a = segment.a - self.origin;
+        let t = to_a.perp_dot(edge) / denom;
+        let s = to_a.perp_dot(self.dir) / denom;
+        (t >= 0.0 && (0.0..=1.0).contains(&s)).then_some(t)
+    }
+
+    /// The t values where the ray enters and leaves the circle, or None if it misses or the circle is behind it
+    /// The entry is negative when the ray starts inside
+    #[inline]
+    pub fn intersect_circle(&self, center: Vec2, radius: f32) -> Option<(f32, f32)> {
+        let oc = self.origin - center;
+        let a = self.dir.length_2();
+        let half_b = oc.dot(self.dir);
+        let c = oc.length_2() - radius * radius;
+        let disc = half_b * half_b - a * c;
+        if disc < 0.0 {
+            return None;
+        }
+        let root = disc.sqrt();
+        let t_exit = (-half_b + root) / a;
+        (t_exit >= 0.0).then_some(((-half_b - root) / a, t_exit))
+    }
+
+    /// The t values where the ray enters and leaves the rectangle, or None if it misses
+    /// The entry is clamped to 0, and rays lying exactly along an edge count as hitting, as in Ray3::intersect_aabb
+    #[inline]
+    pub fn intersect_aabb(&self, aabb: &Aabb2) -> Option<(f32, f32)> {
+        let mut t_min = 0.0f32;
+        let mut t_max = f32::INFINITY;
+        for axis in 0..2 {
+            let t1 = (aabb.min[axis] - self.origin[axis]) * self.inv_dir[axis];
+            let t2 = (aabb.max[axis] - self.origin[axis]) * self.inv_dir[axis];
+            let parallel_inside = t1.is_nan() || t2.is_nan();
+            let near = if parallel_inside { f32::NEG_INFINITY } else { t1.min(t2) };
+            let far = if parallel_inside { f32::INFINITY } else { t1.max(t2) };
+            t_min = t_min.max(near);
+            t_max = t_max.min(far);
+        }
+        (t_min <= t_max).then_some((t_min, t_max))
+    }
+
+    // Built directly rather than through Vec2::new, see Ray3
+    #[inline]
+    fn recip(dir: Vec2) -> Vec2 {
+        Vec2 { x: 1.0 / dir.x, y: 1.0 / dir.y }
+    }
+}
+
+
+// Serialized as just origin and dir, the cached reciprocal is rebuilt on load
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Ray2")]
+struct Ray2Data {
+    origin: Vec2,
+    dir: Vec2,
+}
+
+#[cfg(feature = "serde")]
+impl From<Ray2Data> for Ray2 {
+    fn from(data: Ray2Data) -> Ray2 {
+        Ray2::new(data.origin, data.dir)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Ray2> for Ray2Data {
+    fn from(ray: Ray2) -> Ray2Data {
+        Ray2Data { origin: ray.origin, dir: ray.dir }
+    }
+}
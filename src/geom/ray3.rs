@@ -0,0 +1,248 @@
+use crate::{
+    geom::{
+        Aabb3,
+        Capsule,
+        Plane,
+        Sphere,
+    },
+    Affine3,
+    Vec3,
+    VecExt,
+};
+
+
+/// Where a ray passes through a solid, with the outward unit normals of the surface where it enters and leaves
+/// t_enter is negative when the ray starts inside
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RayHit {
+    pub t_enter: f32,
+    pub t_exit: f32,
+    pub normal_enter: Vec3,
+    pub normal_exit: Vec3,
+}
+
+
+/// A half-line starting at origin and extending along dir
+/// dir need not be normalized, t values are measured in multiples of it
+/// The componentwise reciprocal of dir is computed once here for the slab tests that use it
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "Ray3Data", into = "Ray3Data"),
+)]
+pub struct Ray3 {
+    pub origin: Vec3,
+    dir: Vec3,
+    inv_dir: Vec3,
+}
+
+impl Ray3 {
+    /// Requires: dir should be non-zero
+    #[inline]
+    pub fn new(origin: Vec3, dir: Vec3) -> Ray3 {
+        Ray3 { origin, dir, inv_dir: Ray3::recip(dir) }
+    }
+
+    /// The ray from `from` through `to`, so at(1.0) is `to`
+    /// Requires: from != to
+    #[inline]
+    pub fn from_points(from: Vec3, to: Vec3) -> Ray3 {
+        Ray3::new(from, to - from)
+    }
+
+    #[inline]
+    pub fn dir(&self) -> Vec3 {
+        self.dir
+    }
+
+    /// Replaces the direction, keeping the cached reciprocal in sync
+    #[inline]
+    pub fn set_dir(&mut self, dir: Vec3) {
+        self.dir = dir;
+        self.inv_dir = Ray3::recip(dir);
+    }
+
+    /// The componentwise reciprocal of dir, infinite for zero components
+    #[inline]
+    pub fn inverse_dir(&self) -> Vec3 {
+        self.inv_dir
+    }
+
+    /// The point origin + dir * t
+    #[inline]
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.origin + self.dir * t
+    }
+
+    /// Moves the ray into the space of m
+    /// dir is not renormalized, so a hit at t in one space is at the same t in the other
+    #[inline]
+    pub fn transform(&self, m: &Affine3) -> Ray3 {
+        Ray3::new(m.transform_point3(self.origin), m.transform_vector3(self.dir))
+    }
+
+    /// The t values where the ray enters and leaves the box, or None if it misses
+    /// The entry is clamped to 0, so a ray starting inside gets (0, exit)
+    /// Rays lying exactly in the plane of a face count as hitting it
+    // The slab method, with the componentwise work done on f32s since the reciprocal can be infinite
+    #[inline]
+    pub fn intersect_aabb(&self, aabb: &Aabb3) -> Option<(f32, f32)> {
+        let mut t_min = 0.0f32;
+        let mut t_max = f32::INFINITY;
+        for axis in 0..3 {
+            let t1 = (aabb.min[axis] - self.origin[axis]) * self.inv_dir[axis];
+            let t2 = (aabb.max[axis] - self.origin[axis]) * self.inv_dir[axis];
+            // A ray parallel to a slab and lying in one of its planes makes 0 * inf = NaN
+            // It stays inside that slab forever, so the slab places no limit on t (these compile to selects)
+            let parallel_inside = t1.is_nan() || t2.is_nan();
+            let near = if parallel_inside { f32::NEG_INFINITY } else { t1.min(t2) };
+            let far = if parallel_inside { f32::INFINITY } else { t1.max(t2) };
+            t_min = t_min.max(near);
+            t_max = t_max.min(far);
+        }
+        (t_min <= t_max).then_some((t_min, t_max))
+    }
+
+    /// The t where the ray crosses the plane, or None if it is parallel to it or the plane is behind it
+    /// Either side of the plane can be hit
+    #[inline]
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<f32> {
+        let denom = plane.normal.dot(self.dir);
+        if denom == 0.0 {
+            return None;
+        }
+        let t = -(plane.normal.dot(self.origin) + plane.d) / denom;
+        (t >= 0.0).then_some(t)
+    }
+
+    /// Where the ray passes through the sphere, or None if it misses or the sphere is behind it
+    /// Requires: sphere.radius should be positive
+    #[inline]
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<RayHit> {
+        let (t_enter, t_exit) = self.sphere_interval(sphere.center, sphere.radius)?;
+        if t_exit < 0.0 {
+            return None;
+        }
+        let normal = |t: f32| (self.at(t) - sphere.center) / sphere.radius;
+        Some(RayHit { t_enter, t_exit, normal_enter: normal(t_enter), normal_exit: normal(t_exit) })
+    }
+
+    /// Where the ray passes through the capsule, or None if it misses or the capsule is behind it
+    /// Requires: capsule.radius should be positive
+    // The capsule is convex and the union of its two cap spheres and the solid cylinder between them,
+    // so the ray's span through it runs from the earliest entry into any of those to the latest exit
+    #[inline]
+    pub fn intersect_capsule(&self, capsule: &Capsule) -> Option<RayHit> {
+        let (a, b, r) = (capsule.segment.a, capsule.segment.b, capsule.radius);
+        let spans = [
+            self.sphere_interval(a, r),
+            self.sphere_interval(b, r),
+            self.cylinder_interval(a, b, r),
+        ];
+        let (t_enter, t_exit) = spans
+            .into_iter()
+            .flatten()
+            .reduce(|(e0, x0), (e1, x1)| (e0.min(e1), x0.max(x1)))?;
+        if t_exit < 0.0 {
+            return None;
+        }
+        // every surface point is radius from the closest point on the segment, in the direction of the normal
+        let normal = |t: f32| {
+            let p = self.at(t);
+            (p - capsule.segment.closest_point(p)) / r
+        };
+        Some(RayHit { t_enter, t_exit, normal_enter: normal(t_enter), normal_exit: normal(t_exit) })
+    }
+
+    // The t values where the whole line crosses the sphere, if it does
+    #[inline]
+    fn sphere_interval(&self, center: Vec3, radius: f32) -> Option<(f32, f32)> {
+        let oc = self.origin - center;
+        let a = self.dir.length_2();
+        let half_b = oc.dot(self.dir);
+        let c = oc.length_2() - radius * radius;
+        let disc = half_b * half_b - a * c;
+        if disc < 0.0 {
+            return None;
+        }
+        let root = disc.sqrt();
+        Some(((-half_b - root) / a, (-half_b + root) / a))
+    }
+
+    // The t values where the whole line is inside the solid cylinder of radius around the segment from a to b
+    #[inline]
+    fn cylinder_interval(&self, a: Vec3, b: Vec3, radius: f32) -> Option<(f32, f32)> {
+        let ba = b - a;
+        let ba_2 = ba.length_2();
+        if ba_2 <= f32::EPSILON {
+            return None;
+        }
+        let oc = self.origin - a;
+        // between the end caps, where the position along the axis is in [0, ba_2]
+        let along_o = oc.dot(ba);
+        let along_d = self.dir.dot(ba);
+        let (mut lo, mut hi) = if along_d != 0.0 {
+            let t0 = -along_o / along_d;
+            let t1 = (ba_2 - along_o) / along_d;
+            (t0.min(t1), t0.max(t1))
+        } else if along_o >= 0.0 && along_o <= ba_2 {
+            (f32::NEG_INFINITY, f32::INFINITY)
+        } else {
+            return None;
+        };
+        // within radius of the infinite axis line, solved with the axis components removed
+        let d_perp = self.dir - ba * (along_d / ba_2);
+        let oc_perp = oc - ba * (along_o / ba_2);
+        let qa = d_perp.length_2();
+        let half_qb = oc_perp.dot(d_perp);
+        let qc = oc_perp.length_2() - radius * radius;
+        if qa <= f32::EPSILON * self.dir.length_2() {
+            // parallel to the axis, so the distance to it never changes
+            if qc > 0.0 {
+                return None;
+            }
+        } else {
+            let disc = half_qb * half_qb - qa * qc;
+            if disc < 0.0 {
+                return None;
+            }
+            let root = disc.sqrt();
+            lo = lo.max((-half_qb - root) / qa);
+            hi = hi.min((-half_qb + root) / qa);
+        }
+        (lo <= hi).then_some((lo, hi))
+    }
+
+    // Built directly rather than through Vec3::new, since axis-aligned rays have infinite components
+    // that the validate feature would otherwise reject
+    #[inline]
+    fn recip(dir: Vec3) -> Vec3 {
+        Vec3 { x: 1.0 / dir.x, y: 1.0 / dir.y, z: 1.0 / dir.z }
+    }
+}
+
+
+// Serialized as just origin and dir, the cached reciprocal is rebuilt on load
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Ray3")]
+struct Ray3Data {
+    origin: Vec3,
+    dir: Vec3,
+}
+
+#[cfg(feature = "serde")]
+impl From<Ray3Data> for Ray3 {
+    fn from(data: Ray3Data) -> Ray3 {
+        Ray3::new(data.origin, data.dir)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Ray3> for Ray3Data {
+    fn from(ray: Ray3) -> Ray3Data {
+        Ray3Data { origin: ray.origin, dir: ray.dir }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    geom::Aabb3,
+    F32Ext,
+    Vec3,
+    VecExt,
+};
+
+
+/// The line segment from a to b
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment3 {
+    pub a: Vec3,
+    pub b: Vec3,
+}
+
+impl Segment3 {
+    #[inline]
+    pub const fn new(a: Vec3, b: Vec3) -> Segment3 {
+        Segment3 { a, b }
+    }
+
+    #[inline]
+    pub fn length(&self) -> f32 {
+        self.a.distance(self.b)
+    }
+
+    #[inline]
+    pub fn length_2(&self) -> f32 {
+        self.a.distance_2(self.b)
+    }
+
+    /// The point a + (b - a) * t
+    #[inline]
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.a.lerp(self.b, t)
+    }
+
+    /// The t in [0, 1] of the point on the segment closest to p
+    /// A zero length segment gives 0
+    #[inline]
+    pub fn closest_t(&self, p: Vec3) -> f32 {
+        let ab = self.b - self.a;
+        let len_2 = ab.length_2();
+        if len_2 <= f32::EPSILON {
+            return 0.0;
+        }
+        ((p - self.a).dot(ab) / len_2).saturate()
+    }
+
+    /// The point on the segment closest to p
+    #[inline]
+    pub fn closest_point(&self, p: Vec3) -> Vec3 {
+        self.at(self.closest_t(p))
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, p: Vec3) -> f32 {
+        self.closest_point(p).distance(p)
+    }
+
+    /// The pair of points, one on self and one on other, that are closest to each other
+    /// Parallel segments have many such pairs, and one of them is returned
+    // Ericson, Real-Time Collision Detection 5.1.9
+    #[inline]
+    pub fn closest_points_between_segments(&self, other: &Segment3) -> (Vec3, Vec3) {
+        let d1 = self.b - self.a;
+        let d2 = other.b - other.a;
+        let r = self.a - other.a;
+        let a = d1.length_2();
+        let e = d2.length_2();
+        let f = d2.dot(r);
+
+        let (s, t) = if a <= f32::EPSILON && e <= f32::EPSILON {
+            // both are points
+            (0.0, 0.0)
+        } else if a <= f32::EPSILON {
+            // self is a point
+            (0.0, (f / e).saturate())
+        } else {
+            let c = d1.dot(r);
+            if e <= f32::EPSILON {
+                // other is a point
+                ((-c / a).saturate(), 0.0)
+            } else {
+                let b = d1.dot(d2);
+                let denom = a * e - b * b;
+                // parallel segments have no unique answer, so start from self.a
+                let s = if denom != 0.0 { ((b * f - c * e) / denom).saturate() } else { 0.0 };
+                let t = (b * s + f) / e;
+                // clamping t may move the closest point on self, so recompute s from the clamped t
+                if t < 0.0 {
+                    ((-c / a).saturate(), 0.0)
+                } else if t > 1.0 {
+                    (((b - c) / a).saturate(), 1.0)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.at(s), other.at(t))
+    }
+
+    /// The shortest distance between any point on self and any point on other
+    #[inline]
+    pub fn distance_to_segment(&self, other: &Segment3) -> f32 {
+        let (p, q) = self.closest_points_between_segments(other);
+        p.distance(q)
+    }
+
+    /// The squared distance between the closest points of the segment and the box, 0 if they touch
+    /// Exact, since the distance along the segment is a piecewise quadratic with a piece between each slab crossing
+    #[inline]
+    pub fn distance_2_to_aabb(&self, aabb: &Aabb3) -> f32 {
+        let d = self.b - self.a;
+        // the t values where the segment crosses a face plane of the box split it into the pieces
+        let mut ts = [0.0; 8];
+        ts[1] = 1.0;
+        let mut n = 2;
+        for axis in 0..3 {
+            if d[axis] != 0.0 {
+                for bound in [aabb.min[axis], aabb.max[axis]] {
+                    let t = (bound - self.a[axis]) / d[axis];
+                    if t > 0.0 && t < 1.0 {
+                        ts[n] = t;
+                        n += 1;
+                    }
+                }
+            }
+        }
+        let ts = &mut ts[..n];
+        ts.sort_unstable_by(f32::total_cmp);
+
+        let mut best = f32::INFINITY;
+        for w in ts.windows(2) {
+            let (t0, t1) = (w[0], w[1]);
+            // within a piece each axis is always below, inside or above the box, so the squared distance is one quadratic
+            let mid = self.at((t0 + t1) * 0.5);
+            let target = aabb.clamp_point(mid);
+            let (mut qa, mut qb) = (0.0, 0.0);
+            for axis in 0..3 {
+                if target[axis] != mid[axis] {
+                    qa += d[axis] * d[axis];
+                    qb += d[axis] * (self.a[axis] - target[axis]);
+                }
+            }
+            let t = if qa > 0.0 { (-qb / qa).clamp(t0, t1) } else { t0 };
+            best = best.min(aabb.distance_2_to_point(self.at(t)));
+        }
+        best
+    }
+}
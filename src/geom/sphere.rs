@@ -0,0 +1,54 @@
+use crate::{
+    geom::Aabb3,
+    Transform,
+    Vec3,
+    VecExt,
+};
+
+
+/// A solid ball, every point within radius of center
+/// The surface counts as inside, so spheres that only touch still intersect
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl Sphere {
+    /// Requires: radius should be non-negative
+    #[inline]
+    pub const fn new(center: Vec3, radius: f32) -> Sphere {
+        Sphere { center, radius }
+    }
+
+    #[inline]
+    pub fn contains_point(&self, p: Vec3) -> bool {
+        self.center.distance_2(p) <= self.radius * self.radius
+    }
+
+    #[inline]
+    pub fn intersects_sphere(&self, other: &Sphere) -> bool {
+        let r = self.radius + other.radius;
+        self.center.distance_2(other.center) <= r * r
+    }
+
+    /// Checks if the sphere overlaps or touches the box
+    #[inline]
+    pub fn intersects_aabb(&self, aabb: &Aabb3) -> bool {
+        aabb.distance_2_to_point(self.center) <= self.radius * self.radius
+    }
+
+    /// The smallest box containing the sphere
+    #[inline]
+    pub fn aabb(&self) -> Aabb3 {
+        Aabb3::from_center_half_extents(self.center, Vec3::splat(self.radius))
+    }
+
+    /// The sphere after transforming it by t
+    /// Non-uniform scale would make an ellipsoid, so the radius grows by the largest scale factor to still bound it
+    #[inline]
+    pub fn transformed(&self, t: &Transform) -> Sphere {
+        Sphere::new(t.transform_point(self.center), self.radius * t.scale.abs().max_element())
+    }
+}
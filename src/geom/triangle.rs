@@ -0,0 +1,128 @@
+use crate::{
+    Vec3,
+    VecExt,
+};
+
+
+/// A triangle with corners a, b and c
+/// Its front faces along (b - a) × (c - a)
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Triangle {
+    pub a: Vec3,
+    pub b: Vec3,
+    pub c: Vec3,
+}
+
+impl Triangle {
+    #[inline]
+    pub const fn new(a: Vec3, b: Vec3, c: Vec3) -> Triangle {
+        Triangle { a, b, c }
+    }
+
+    /// (b - a) × (c - a), facing along the normal with a length of twice the area
+    /// Summing these over a vertex's triangles gives the usual area weighted vertex normal
+    #[inline]
+    pub fn scaled_normal(&self) -> Vec3 {
+        (self.b - self.a).cross(self.c - self.a)
+    }
+
+    /// The unit normal
+    /// Requires: the corners should not be collinear
+    #[inline]
+    pub fn normal(&self) -> Vec3 {
+        self.scaled_normal().normalize()
+    }
+
+    #[inline]
+    pub fn area(&self) -> f32 {
+        self.scaled_normal().length() * 0.5
+    }
+
+    /// The average of the corners, the triangle's center of mass
+    #[inline]
+    pub fn centroid(&self) -> Vec3 {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    /// The weights (u, v, w) with p = u * a + v * b + w * c, for p projected onto the triangle's plane
+    /// Requires: the corners should not be collinear
+    #[inline]
+    pub fn barycentric(&self, p: Vec3) -> Vec3 {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let ap = p - self.a;
+        let d00 = ab.dot(ab);
+        let d01 = ab.dot(ac);
+        let d11 = ac.dot(ac);
+        let d20 = ap.dot(ab);
+        let d21 = ap.dot(ac);
+        let inv_denom = 1.0 / (d00 * d11 - d01 * d01);
+        let v = (d11 * d20 - d01 * d21) * inv_denom;
+        let w = (d00 * d21 - d01 * d20) * inv_denom;
+        Vec3::new(1.0 - v - w, v, w)
+    }
+
+    /// Checks if p lands inside the triangle, edges included, after projecting it onto the triangle's plane
+    /// Requires: the corners should not be collinear
+    #[inline]
+    pub fn contains_point_projected(&self, p: Vec3) -> bool {
+        let bary = self.barycentric(p);
+        bary.x >= 0.0 && bary.y >= 0.0 && bary.z >= 0.0
+    }
+
+    /// The point on the triangle, edges and interior, closest to p
+    // Ericson, Real-Time Collision Detection 5.1.5
+    #[inline]
+    pub fn closest_point(&self, p: Vec3) -> Vec3 {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+
+        // vertex region outside a
+        let ap = p - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return a;
+        }
+
+        // vertex region outside b
+        let bp = p - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return b;
+        }
+
+        // edge region of ab
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        // vertex region outside c
+        let cp = p - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return c;
+        }
+
+        // edge region of ac
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        // edge region of bc
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        // inside the face
+        let denom = 1.0 / (va + vb + vc);
+        a + ab * (vb * denom) + ac * (vc * denom)
+    }
+}
@@ -0,0 +1,27 @@
+pub mod grid2;
+pub use grid2::*;
+
+pub mod grid3;
+pub use grid3::*;
+
+
+/// Splits a continuous grid coordinate into the two neighboring sample indices along one axis
+/// and the interpolation factor between them
+/// Coordinates outside [0, n - 1] clamp to the edge samples
+#[inline]
+fn axis_cell(g: f32, n: i32) -> (i32, i32, f32) {
+    let last = n - 1;
+    let g = g.clamp(0.0, last as f32);
+    // g is non-negative so truncation is floor
+    let i0 = (g as i32).min(last);
+    let i1 = (i0 + 1).min(last);
+    (i0, i1, g - i0 as f32)
+}
+
+/// Number of cells in a grid with the given per-axis counts
+/// Panics if any count is not positive
+#[inline]
+fn cell_count(counts: &[i32]) -> usize {
+    assert!(counts.iter().all(|&n| n > 0), "grid dimensions must be positive");
+    counts.iter().map(|&n| n as usize).product()
+}
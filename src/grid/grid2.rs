@@ -0,0 +1,164 @@
+use crate::{
+    bilerp,
+    grid::{
+        axis_cell,
+        cell_count,
+    },
+    IVec2,
+    MathError,
+    Vec2,
+};
+use std::{
+    ops::{
+        Add,
+        Mul,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// A dense 2D grid of values with a mapping to world space
+/// Sample (x, y) sits at origin + <x y> * cell_size in world space
+/// Stored row-major, x varies fastest
+#[derive(Clone, PartialEq, Debug)]
+pub struct Grid2<T> {
+    size: IVec2,
+    origin: Vec2,
+    cell_size: f32,
+    data: Vec<T>,
+}
+
+impl<T> Grid2<T> {
+    /// Creates a grid of size.x * size.y samples all set to fill
+    /// Panics if any dimension is not positive
+    /// Requires: cell_size should be positive
+    #[inline]
+    pub fn new(size: IVec2, origin: Vec2, cell_size: f32, fill: T) -> Grid2<T>
+    where
+        T: Clone,
+    {
+        let len = cell_count(&[size.x, size.y]);
+        Grid2 { size, origin, cell_size, data: vec![fill; len] }
+    }
+
+    /// Creates a grid from row-major data
+    /// Fails if data does not hold exactly size.x * size.y values
+    /// Panics if any dimension is not positive
+    /// Requires: cell_size should be positive
+    #[inline]
+    pub fn from_vec(size: IVec2, origin: Vec2, cell_size: f32, data: Vec<T>) -> Result<Grid2<T>, MathError> {
+        let len = cell_count(&[size.x, size.y]);
+        if data.len() != len {
+            return Err(MathError::SliceLength { expected: len, found: data.len() });
+        }
+        Ok(Grid2 { size, origin, cell_size, data })
+    }
+
+    /// Number of samples along each axis
+    #[inline]
+    pub fn size(&self) -> IVec2 {
+        self.size
+    }
+
+    /// World position of sample (0, 0)
+    #[inline]
+    pub fn origin(&self) -> Vec2 {
+        self.origin
+    }
+
+    /// World distance between neighboring samples
+    #[inline]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// The samples in row-major order
+    #[inline]
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    #[inline]
+    pub fn in_bounds(&self, cell: IVec2) -> bool {
+        (0..self.size.x).contains(&cell.x) && (0..self.size.y).contains(&cell.y)
+    }
+
+    #[inline]
+    fn linear_index(&self, cell: IVec2) -> Option<usize> {
+        // in bounds means every component is non-negative, and indexing in usize keeps large grids from overflowing i32
+        self.in_bounds(cell).then(|| cell.y as usize * self.size.x as usize + cell.x as usize)
+    }
+
+    /// Returns the sample at cell, or None if it is out of bounds
+    #[inline]
+    pub fn get(&self, cell: IVec2) -> Option<&T> {
+        self.linear_index(cell).map(|i| &self.data[i])
+    }
+
+    /// Returns the sample at cell mutably, or None if it is out of bounds
+    #[inline]
+    pub fn get_mut(&mut self, cell: IVec2) -> Option<&mut T> {
+        self.linear_index(cell).map(|i| &mut self.data[i])
+    }
+
+    /// World position of a sample
+    #[inline]
+    pub fn cell_to_world(&self, cell: IVec2) -> Vec2 {
+        self.origin + cell.as_vec2() * self.cell_size
+    }
+
+    /// Continuous grid coordinates of a world position, sample centers land on integers
+    #[inline]
+    pub fn world_to_grid(&self, p: Vec2) -> Vec2 {
+        (p - self.origin) / self.cell_size
+    }
+
+    /// Bilinearly interpolates the samples around a world position
+    /// Positions outside the grid clamp to the nearest edge
+    #[inline]
+    pub fn sample(&self, p: Vec2) -> T
+    where
+        T: Copy + Add<Output = T> + Mul<f32, Output = T>,
+    {
+        let g = self.world_to_grid(p);
+        let (x0, x1, tx) = axis_cell(g.x, self.size.x);
+        let (y0, y1, ty) = axis_cell(g.y, self.size.y);
+        bilerp(
+            self[IVec2::new(x0, y0)],
+            self[IVec2::new(x1, y0)],
+            self[IVec2::new(x0, y1)],
+            self[IVec2::new(x1, y1)],
+            Vec2::new(tx, ty),
+        )
+    }
+}
+
+
+impl<T> Index<IVec2> for Grid2<T> {
+    type Output = T;
+    /// Panics if cell is out of bounds
+    #[inline]
+    fn index(&self, cell: IVec2) -> &T {
+        match self.get(cell) {
+            Some(v) => v,
+            None => panic!("cell {cell} out of bounds for grid of size {}", self.size),
+        }
+    }
+}
+impl<T> IndexMut<IVec2> for Grid2<T> {
+    /// Panics if cell is out of bounds
+    #[inline]
+    fn index_mut(&mut self, cell: IVec2) -> &mut T {
+        let size = self.size;
+        match self.get_mut(cell) {
+            Some(v) => v,
+            None => panic!("cell {cell} out of bounds for grid of size {size}"),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    trilerp,
+    grid::{
+        axis_cell,
+        cell_count,
+    },
+    IVec3,
+    MathError,
+    Vec3,
+};
+use std::{
+    ops::{
+        Add,
+        Mul,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// A dense 3D grid of values with a mapping to world space
+/// Sample (x, y, z) sits at origin + <x y z> * cell_size in world space
+/// Stored with x varying fastest, then y, then z
+#[derive(Clone, PartialEq, Debug)]
+pub struct Grid3<T> {
+    size: IVec3,
+    origin: Vec3,
+    cell_size: f32,
+    data: Vec<T>,
+}
+
+impl<T> Grid3<T> {
+    /// Creates a grid of size.x * size.y * size.z samples all set to fill
+    /// Panics if any dimension is not positive
+    /// Requires: cell_size should be positive
+    #[inline]
+    pub fn new(size: IVec3, origin: Vec3, cell_size: f32, fill: T) -> Grid3<T>
+    where
+        T: Clone,
+    {
+        let len = cell_count(&[size.x, size.y, size.z]);
+        Grid3 { size, origin, cell_size, data: vec![fill; len] }
+    }
+
+    /// Creates a grid from data laid out x fastest, then y, then z
+    /// Fails if data does not hold exactly size.x * size.y * size.z values
+    /// Panics if any dimension is not positive
+    /// Requires: cell_size should be positive
+    #[inline]
+    pub fn from_vec(size: IVec3, origin: Vec3, cell_size: f32, data: Vec<T>) -> Result<Grid3<T>, MathError> {
+        let len = cell_count(&[size.x, size.y, size.z]);
+        if data.len() != len {
+            return Err(MathError::SliceLength { expected: len, found: data.len() });
+        }
+        Ok(Grid3 { size, origin, cell_size, data })
+    }
+
+    /// Number of samples along each axis
+    #[inline]
+    pub fn size(&self) -> IVec3 {
+        self.size
+    }
+
+    /// World position of sample (0, 0, 0)
+    #[inline]
+    pub fn origin(&self) -> Vec3 {
+        self.origin
+    }
+
+    /// World distance between neighboring samples
+    #[inline]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// The samples laid out x fastest, then y, then z
+    #[inline]
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    #[inline]
+    pub fn in_bounds(&self, cell: IVec3) -> bool {
+        (0..self.size.x).contains(&cell.x)
+            && (0..self.size.y).contains(&cell.y)
+            && (0..self.size.z).contains(&cell.z)
+    }
+
+    #[inline]
+    fn linear_index(&self, cell: IVec3) -> Option<usize> {
+        // in bounds means every component is non-negative, and indexing in usize keeps large grids from overflowing i32
+        self.in_bounds(cell).then(|| {
+            let (size_x, size_y) = (self.size.x as usize, self.size.y as usize);
+            (cell.z as usize * size_y + cell.y as usize) * size_x + cell.x as usize
+        })
+    }
+
+    /// Returns the sample at cell, or None if it is out of bounds
+    #[inline]
+    pub fn get(&self, cell: IVec3) -> Option<&T> {
+        self.linear_index(cell).map(|i| &self.data[i])
+    }
+
+    /// Returns the sample at cell mutably, or None if it is out of bounds
+    #[inline]
+    pub fn get_mut(&mut self, cell: IVec3) -> Option<&mut T> {
+        self.linear_index(cell).map(|i| &mut self.data[i])
+    }
+
+    /// World position of a sample
+    #[inline]
+    pub fn cell_to_world(&self, cell: IVec3) -> Vec3 {
+        self.origin + cell.as_vec3() * self.cell_size
+    }
+
+    /// Continuous grid coordinates of a world position, sample centers land on integers
+    #[inline]
+    pub fn world_to_grid(&self, p: Vec3) -> Vec3 {
+        (p - self.origin) / self.cell_size
+    }
+
+    /// Trilinearly interpolates the samples around a world position
+    /// Positions outside the grid clamp to the nearest face
+    #[inline]
+    pub fn sample(&self, p: Vec3) -> T
+    where
+        T: Copy + Add<Output = T> + Mul<f32, Output = T>,
+    {
+        let g = self.world_to_grid(p);
+        let (x0, x1, tx) = axis_cell(g.x, self.size.x);
+        let (y0, y1, ty) = axis_cell(g.y, self.size.y);
+        let (z0, z1, tz) = axis_cell(g.z, self.size.z);
+        trilerp(
+            [
+                self[IVec3::new(x0, y0, z0)],
+                self[IVec3::new(x1, y0, z0)],
+                self[IVec3::new(x0, y1, z0)],
+                self[IVec3::new(x1, y1, z0)],
+                self[IVec3::new(x0, y0, z1)],
+                self[IVec3::new(x1, y0, z1)],
+                self[IVec3::new(x0, y1, z1)],
+                self[IVec3::new(x1, y1, z1)],
+            ],
+            Vec3::new(tx, ty, tz),
+        )
+    }
+}
+
+
+impl<T> Index<IVec3> for Grid3<T> {
+    type Output = T;
+    /// Panics if cell is out of bounds
+    #[inline]
+    fn index(&self, cell: IVec3) -> &T {
+        match self.get(cell) {
+            Some(v) => v,
+            None => panic!("cell {cell} out of bounds for grid of size {}", self.size),
+        }
+    }
+}
+impl<T> IndexMut<IVec3> for Grid3<T> {
+    /// Panics if cell is out of bounds
+    #[inline]
+    fn index_mut(&mut self, cell: IVec3) -> &mut T {
+        let size = self.size;
+        match self.get_mut(cell) {
+            Some(v) => v,
+            None => panic!("cell {cell} out of bounds for grid of size {size}"),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub mod ivec2;
+pub use ivec2::*;
+
+pub mod ivec3;
+pub use ivec3::*;
+
+pub mod ivec4;
+pub use ivec4::*;
@@ -0,0 +1,794 @@
+use crate::{
+    F32Ext,
+    Vec2,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+    ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+        Mul,
+        MulAssign,
+        Div,
+        DivAssign,
+        Rem,
+        RemAssign,
+        Neg,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// An integer vector in 2-space
+/// Operators follow i32 semantics, so they panic on overflow in debug builds
+/// Use the wrapping_ and saturating_ methods where overflow is expected
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct IVec2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl IVec2 {
+    /// The default IVec2 with all 0's
+    pub const ZERO: IVec2 = IVec2::splat(0);
+
+    /// The IVec2 with all 1's
+    pub const ONE: IVec2 = IVec2::splat(1);
+
+    /// The positive x-axis basis vector
+    pub const X: IVec2 = IVec2::new(1, 0);
+
+    /// The positive y-axis basis vector
+    pub const Y: IVec2 = IVec2::new(0, 1);
+
+
+    /// Standard constructor for <x y>
+    #[inline]
+    pub const fn new(x: i32, y: i32) -> IVec2 {
+        IVec2 { x, y }
+    }
+
+    #[inline]
+    pub const fn splat(v: i32) -> IVec2 {
+        IVec2::new(v, v)
+    }
+
+    /// Returns a copy of self with the x component replaced
+    #[inline]
+    pub const fn with_x(mut self, x: i32) -> IVec2 {
+        self.x = x;
+        self
+    }
+
+    /// Returns a copy of self with the y component replaced
+    #[inline]
+    pub const fn with_y(mut self, y: i32) -> IVec2 {
+        self.y = y;
+        self
+    }
+
+
+    /// Converts from a Vec2 rounding each component towards negative infinity
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn from_floor(v: Vec2) -> IVec2 {
+        IVec2::new(
+            v.x.fast_floor_i32(),
+            v.y.fast_floor_i32(),
+        )
+    }
+
+    /// Converts from a Vec2 rounding each component to the nearest integer, ties to even
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn from_round(v: Vec2) -> IVec2 {
+        IVec2::new(
+            v.x.round_half_even_i32(),
+            v.y.round_half_even_i32(),
+        )
+    }
+
+    /// Converts from a Vec2 rounding each component towards positive infinity
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn from_ceil(v: Vec2) -> IVec2 {
+        IVec2::new(
+            v.x.fast_ceil_i32(),
+            v.y.fast_ceil_i32(),
+        )
+    }
+
+    /// Converts to a Vec2
+    /// Components beyond +-2^24 are rounded to the nearest representable f32
+    #[inline]
+    pub fn as_vec2(self) -> Vec2 {
+        Vec2::new(
+            self.x as f32,
+            self.y as f32,
+        )
+    }
+
+
+    /// Componentwise minimum
+    #[inline]
+    pub fn min(self, rhs: IVec2) -> IVec2 {
+        IVec2::new(
+            self.x.min(rhs.x),
+            self.y.min(rhs.y),
+        )
+    }
+
+    /// Componentwise maximum
+    #[inline]
+    pub fn max(self, rhs: IVec2) -> IVec2 {
+        IVec2::new(
+            self.x.max(rhs.x),
+            self.y.max(rhs.y),
+        )
+    }
+
+    /// Clamps each component between the corresponding components of min and max
+    /// Requires: min <= max componentwise
+    #[inline]
+    pub fn clamp(self, min: IVec2, max: IVec2) -> IVec2 {
+        self.max(min).min(max)
+    }
+
+    /// The smallest component
+    #[inline]
+    pub fn min_element(self) -> i32 {
+        self.x.min(self.y)
+    }
+
+    /// The largest component
+    #[inline]
+    pub fn max_element(self) -> i32 {
+        self.x.max(self.y)
+    }
+
+    /// Componentwise absolute value
+    /// Panics in debug builds if a component is i32::MIN
+    #[inline]
+    pub fn abs(self) -> IVec2 {
+        IVec2::new(
+            self.x.abs(),
+            self.y.abs(),
+        )
+    }
+
+    /// Componentwise sign, -1, 0 or 1
+    #[inline]
+    pub fn signum(self) -> IVec2 {
+        IVec2::new(
+            self.x.signum(),
+            self.y.signum(),
+        )
+    }
+
+    /// The sum of the products of corresponding components
+    #[inline]
+    pub fn dot(self, rhs: IVec2) -> i32 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// The sum of the absolute differences of corresponding components, the taxicab distance
+    #[inline]
+    pub fn manhattan_distance(self, rhs: IVec2) -> u32 {
+        self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y)
+    }
+
+    /// Adds componentwise, wrapping around at the i32 bounds
+    #[inline]
+    pub fn wrapping_add(self, rhs: IVec2) -> IVec2 {
+        IVec2::new(
+            self.x.wrapping_add(rhs.x),
+            self.y.wrapping_add(rhs.y),
+        )
+    }
+
+    /// Subtracts componentwise, wrapping around at the i32 bounds
+    #[inline]
+    pub fn wrapping_sub(self, rhs: IVec2) -> IVec2 {
+        IVec2::new(
+            self.x.wrapping_sub(rhs.x),
+            self.y.wrapping_sub(rhs.y),
+        )
+    }
+
+    /// Multiplies componentwise, wrapping around at the i32 bounds
+    #[inline]
+    pub fn wrapping_mul(self, rhs: IVec2) -> IVec2 {
+        IVec2::new(
+            self.x.wrapping_mul(rhs.x),
+            self.y.wrapping_mul(rhs.y),
+        )
+    }
+
+    /// Adds componentwise, clamping to the i32 bounds
+    #[inline]
+    pub fn saturating_add(self, rhs: IVec2) -> IVec2 {
+        IVec2::new(
+            self.x.saturating_add(rhs.x),
+            self.y.saturating_add(rhs.y),
+        )
+    }
+
+    /// Subtracts componentwise, clamping to the i32 bounds
+    #[inline]
+    pub fn saturating_sub(self, rhs: IVec2) -> IVec2 {
+        IVec2::new(
+            self.x.saturating_sub(rhs.x),
+            self.y.saturating_sub(rhs.y),
+        )
+    }
+
+    /// Multiplies componentwise, clamping to the i32 bounds
+    #[inline]
+    pub fn saturating_mul(self, rhs: IVec2) -> IVec2 {
+        IVec2::new(
+            self.x.saturating_mul(rhs.x),
+            self.y.saturating_mul(rhs.y),
+        )
+    }
+}
+
+
+impl Debug for IVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IVec2")
+            .field(&self.x)
+            .field(&self.y)
+            .finish()
+    }
+}
+impl Display for IVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .finish()
+    }
+}
+
+
+// IVec2 + IVec2
+impl Add<IVec2> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn add(self, rhs: IVec2) -> Self::Output {
+        IVec2::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+        )
+    }
+}
+impl Add<&IVec2> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn add(self, rhs: &IVec2) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<IVec2> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn add(self, rhs: IVec2) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&IVec2> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn add(self, rhs: &IVec2) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// IVec2 + i32
+impl Add<i32> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn add(self, rhs: i32) -> Self::Output {
+        IVec2::new(
+            self.x + rhs,
+            self.y + rhs,
+        )
+    }
+}
+impl Add<&i32> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn add(self, rhs: &i32) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<i32> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn add(self, rhs: i32) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&i32> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn add(self, rhs: &i32) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// IVec2 += IVec2
+impl AddAssign<IVec2> for IVec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: IVec2) {
+        *self = *self + rhs;
+    }
+}
+impl AddAssign<&IVec2> for IVec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &IVec2) {
+        *self += *rhs;
+    }
+}
+
+// IVec2 += i32
+impl AddAssign<i32> for IVec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: i32) {
+        *self = *self + rhs;
+    }
+}
+impl AddAssign<&i32> for IVec2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &i32) {
+        *self += *rhs;
+    }
+}
+
+
+// IVec2 - IVec2
+impl Sub<IVec2> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn sub(self, rhs: IVec2) -> Self::Output {
+        IVec2::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+        )
+    }
+}
+impl Sub<&IVec2> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn sub(self, rhs: &IVec2) -> Self::Output {
+        self - *rhs
+    }
+}
+impl Sub<IVec2> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn sub(self, rhs: IVec2) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&IVec2> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn sub(self, rhs: &IVec2) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+// IVec2 - i32
+impl Sub<i32> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn sub(self, rhs: i32) -> Self::Output {
+        IVec2::new(
+            self.x - rhs,
+            self.y - rhs,
+        )
+    }
+}
+impl Sub<&i32> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn sub(self, rhs: &i32) -> Self::Output {
+        self - *rhs
+    }
+}
+impl Sub<i32> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn sub(self, rhs: i32) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&i32> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn sub(self, rhs: &i32) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+// IVec2 -= IVec2
+impl SubAssign<IVec2> for IVec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: IVec2) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&IVec2> for IVec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &IVec2) {
+        *self -= *rhs;
+    }
+}
+
+// IVec2 -= i32
+impl SubAssign<i32> for IVec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: i32) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&i32> for IVec2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &i32) {
+        *self -= *rhs;
+    }
+}
+
+
+// IVec2 * IVec2
+impl Mul<IVec2> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn mul(self, rhs: IVec2) -> Self::Output {
+        IVec2::new(
+            self.x * rhs.x,
+            self.y * rhs.y,
+        )
+    }
+}
+impl Mul<&IVec2> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn mul(self, rhs: &IVec2) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<IVec2> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn mul(self, rhs: IVec2) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&IVec2> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn mul(self, rhs: &IVec2) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// IVec2 * i32
+impl Mul<i32> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        IVec2::new(
+            self.x * rhs,
+            self.y * rhs,
+        )
+    }
+}
+impl Mul<&i32> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn mul(self, rhs: &i32) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<i32> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&i32> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn mul(self, rhs: &i32) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// IVec2 *= IVec2
+impl MulAssign<IVec2> for IVec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: IVec2) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&IVec2> for IVec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &IVec2) {
+        *self *= *rhs;
+    }
+}
+
+// IVec2 *= i32
+impl MulAssign<i32> for IVec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&i32> for IVec2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &i32) {
+        *self *= *rhs;
+    }
+}
+
+
+// IVec2 / IVec2
+impl Div<IVec2> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn div(self, rhs: IVec2) -> Self::Output {
+        IVec2::new(
+            self.x / rhs.x,
+            self.y / rhs.y,
+        )
+    }
+}
+impl Div<&IVec2> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn div(self, rhs: &IVec2) -> Self::Output {
+        self / *rhs
+    }
+}
+impl Div<IVec2> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn div(self, rhs: IVec2) -> Self::Output {
+        *self / rhs
+    }
+}
+impl Div<&IVec2> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn div(self, rhs: &IVec2) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+// IVec2 / i32
+impl Div<i32> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn div(self, rhs: i32) -> Self::Output {
+        IVec2::new(
+            self.x / rhs,
+            self.y / rhs,
+        )
+    }
+}
+impl Div<&i32> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn div(self, rhs: &i32) -> Self::Output {
+        self / *rhs
+    }
+}
+impl Div<i32> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn div(self, rhs: i32) -> Self::Output {
+        *self / rhs
+    }
+}
+impl Div<&i32> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn div(self, rhs: &i32) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+// IVec2 /= IVec2
+impl DivAssign<IVec2> for IVec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: IVec2) {
+        *self = *self / rhs;
+    }
+}
+impl DivAssign<&IVec2> for IVec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &IVec2) {
+        *self /= *rhs;
+    }
+}
+
+// IVec2 /= i32
+impl DivAssign<i32> for IVec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: i32) {
+        *self = *self / rhs;
+    }
+}
+impl DivAssign<&i32> for IVec2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &i32) {
+        *self /= *rhs;
+    }
+}
+
+
+// IVec2 % IVec2
+impl Rem<IVec2> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn rem(self, rhs: IVec2) -> Self::Output {
+        IVec2::new(
+            self.x % rhs.x,
+            self.y % rhs.y,
+        )
+    }
+}
+impl Rem<&IVec2> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn rem(self, rhs: &IVec2) -> Self::Output {
+        self % *rhs
+    }
+}
+impl Rem<IVec2> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn rem(self, rhs: IVec2) -> Self::Output {
+        *self % rhs
+    }
+}
+impl Rem<&IVec2> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn rem(self, rhs: &IVec2) -> Self::Output {
+        *self % *rhs
+    }
+}
+
+// IVec2 % i32
+impl Rem<i32> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn rem(self, rhs: i32) -> Self::Output {
+        IVec2::new(
+            self.x % rhs,
+            self.y % rhs,
+        )
+    }
+}
+impl Rem<&i32> for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn rem(self, rhs: &i32) -> Self::Output {
+        self % *rhs
+    }
+}
+impl Rem<i32> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn rem(self, rhs: i32) -> Self::Output {
+        *self % rhs
+    }
+}
+impl Rem<&i32> for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn rem(self, rhs: &i32) -> Self::Output {
+        *self % *rhs
+    }
+}
+
+// IVec2 %= IVec2
+impl RemAssign<IVec2> for IVec2 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: IVec2) {
+        *self = *self % rhs;
+    }
+}
+impl RemAssign<&IVec2> for IVec2 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &IVec2) {
+        *self %= *rhs;
+    }
+}
+
+// IVec2 %= i32
+impl RemAssign<i32> for IVec2 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: i32) {
+        *self = *self % rhs;
+    }
+}
+impl RemAssign<&i32> for IVec2 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &i32) {
+        *self %= *rhs;
+    }
+}
+
+
+// -IVec2
+impl Neg for IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        IVec2::new(
+            -self.x,
+            -self.y,
+        )
+    }
+}
+impl Neg for &IVec2 {
+    type Output = IVec2;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+
+impl Index<usize> for IVec2 {
+    type Output = i32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Cannot index into a IVec2 at i > 1"),
+        }
+    }
+}
+
+impl IndexMut<usize> for IVec2 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Cannot index into a IVec2 at i > 1"),
+        }
+    }
+}
+
+
+impl From<[i32; 2]> for IVec2 {
+    #[inline]
+    fn from(arr: [i32; 2]) -> IVec2 {
+        IVec2::new(arr[0], arr[1])
+    }
+}
+
+impl From<IVec2> for [i32; 2] {
+    #[inline]
+    fn from(v: IVec2) -> [i32; 2] {
+        [v.x, v.y]
+    }
+}
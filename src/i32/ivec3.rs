@@ -0,0 +1,834 @@
+use crate::{
+    F32Ext,
+    Vec3,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+    ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+        Mul,
+        MulAssign,
+        Div,
+        DivAssign,
+        Rem,
+        RemAssign,
+        Neg,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// An integer vector in 3-space
+/// Operators follow i32 semantics, so they panic on overflow in debug builds
+/// Use the wrapping_ and saturating_ methods where overflow is expected
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct IVec3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl IVec3 {
+    /// The default IVec3 with all 0's
+    pub const ZERO: IVec3 = IVec3::splat(0);
+
+    /// The IVec3 with all 1's
+    pub const ONE: IVec3 = IVec3::splat(1);
+
+    /// The positive x-axis basis vector
+    pub const X: IVec3 = IVec3::new(1, 0, 0);
+
+    /// The positive y-axis basis vector
+    pub const Y: IVec3 = IVec3::new(0, 1, 0);
+
+    /// The positive z-axis basis vector
+    pub const Z: IVec3 = IVec3::new(0, 0, 1);
+
+
+    /// Standard constructor for <x y z>
+    #[inline]
+    pub const fn new(x: i32, y: i32, z: i32) -> IVec3 {
+        IVec3 { x, y, z }
+    }
+
+    #[inline]
+    pub const fn splat(v: i32) -> IVec3 {
+        IVec3::new(v, v, v)
+    }
+
+    /// Returns a copy of self with the x component replaced
+    #[inline]
+    pub const fn with_x(mut self, x: i32) -> IVec3 {
+        self.x = x;
+        self
+    }
+
+    /// Returns a copy of self with the y component replaced
+    #[inline]
+    pub const fn with_y(mut self, y: i32) -> IVec3 {
+        self.y = y;
+        self
+    }
+
+    /// Returns a copy of self with the z component replaced
+    #[inline]
+    pub const fn with_z(mut self, z: i32) -> IVec3 {
+        self.z = z;
+        self
+    }
+
+
+    /// Converts from a Vec3 rounding each component towards negative infinity
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn from_floor(v: Vec3) -> IVec3 {
+        IVec3::new(
+            v.x.fast_floor_i32(),
+            v.y.fast_floor_i32(),
+            v.z.fast_floor_i32(),
+        )
+    }
+
+    /// Converts from a Vec3 rounding each component to the nearest integer, ties to even
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn from_round(v: Vec3) -> IVec3 {
+        IVec3::new(
+            v.x.round_half_even_i32(),
+            v.y.round_half_even_i32(),
+            v.z.round_half_even_i32(),
+        )
+    }
+
+    /// Converts from a Vec3 rounding each component towards positive infinity
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn from_ceil(v: Vec3) -> IVec3 {
+        IVec3::new(
+            v.x.fast_ceil_i32(),
+            v.y.fast_ceil_i32(),
+            v.z.fast_ceil_i32(),
+        )
+    }
+
+    /// Converts to a Vec3
+    /// Components beyond +-2^24 are rounded to the nearest representable f32
+    #[inline]
+    pub fn as_vec3(self) -> Vec3 {
+        Vec3::new(
+            self.x as f32,
+            self.y as f32,
+            self.z as f32,
+        )
+    }
+
+
+    /// Componentwise minimum
+    #[inline]
+    pub fn min(self, rhs: IVec3) -> IVec3 {
+        IVec3::new(
+            self.x.min(rhs.x),
+            self.y.min(rhs.y),
+            self.z.min(rhs.z),
+        )
+    }
+
+    /// Componentwise maximum
+    #[inline]
+    pub fn max(self, rhs: IVec3) -> IVec3 {
+        IVec3::new(
+            self.x.max(rhs.x),
+            self.y.max(rhs.y),
+            self.z.max(rhs.z),
+        )
+    }
+
+    /// Clamps each component between the corresponding components of min and max
+    /// Requires: min <= max componentwise
+    #[inline]
+    pub fn clamp(self, min: IVec3, max: IVec3) -> IVec3 {
+        self.max(min).min(max)
+    }
+
+    /// The smallest component
+    #[inline]
+    pub fn min_element(self) -> i32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// The largest component
+    #[inline]
+    pub fn max_element(self) -> i32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    /// Componentwise absolute value
+    /// Panics in debug builds if a component is i32::MIN
+    #[inline]
+    pub fn abs(self) -> IVec3 {
+        IVec3::new(
+            self.x.abs(),
+            self.y.abs(),
+            self.z.abs(),
+        )
+    }
+
+    /// Componentwise sign, -1, 0 or 1
+    #[inline]
+    pub fn signum(self) -> IVec3 {
+        IVec3::new(
+            self.x.signum(),
+            self.y.signum(),
+            self.z.signum(),
+        )
+    }
+
+    /// The sum of the products of corresponding components
+    #[inline]
+    pub fn dot(self, rhs: IVec3) -> i32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// The sum of the absolute differences of corresponding components, the taxicab distance
+    #[inline]
+    pub fn manhattan_distance(self, rhs: IVec3) -> u32 {
+        self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y) + self.z.abs_diff(rhs.z)
+    }
+
+    /// Adds componentwise, wrapping around at the i32 bounds
+    #[inline]
+    pub fn wrapping_add(self, rhs: IVec3) -> IVec3 {
+        IVec3::new(
+            self.x.wrapping_add(rhs.x),
+            self.y.wrapping_add(rhs.y),
+            self.z.wrapping_add(rhs.z),
+        )
+    }
+
+    /// Subtracts componentwise, wrapping around at the i32 bounds
+    #[inline]
+    pub fn wrapping_sub(self, rhs: IVec3) -> IVec3 {
+        IVec3::new(
+            self.x.wrapping_sub(rhs.x),
+            self.y.wrapping_sub(rhs.y),
+            self.z.wrapping_sub(rhs.z),
+        )
+    }
+
+    /// Multiplies componentwise, wrapping around at the i32 bounds
+    #[inline]
+    pub fn wrapping_mul(self, rhs: IVec3) -> IVec3 {
+        IVec3::new(
+            self.x.wrapping_mul(rhs.x),
+            self.y.wrapping_mul(rhs.y),
+            self.z.wrapping_mul(rhs.z),
+        )
+    }
+
+    /// Adds componentwise, clamping to the i32 bounds
+    #[inline]
+    pub fn saturating_add(self, rhs: IVec3) -> IVec3 {
+        IVec3::new(
+            self.x.saturating_add(rhs.x),
+            self.y.saturating_add(rhs.y),
+            self.z.saturating_add(rhs.z),
+        )
+    }
+
+    /// Subtracts componentwise, clamping to the i32 bounds
+    #[inline]
+    pub fn saturating_sub(self, rhs: IVec3) -> IVec3 {
+        IVec3::new(
+            self.x.saturating_sub(rhs.x),
+            self.y.saturating_sub(rhs.y),
+            self.z.saturating_sub(rhs.z),
+        )
+    }
+
+    /// Multiplies componentwise, clamping to the i32 bounds
+    #[inline]
+    pub fn saturating_mul(self, rhs: IVec3) -> IVec3 {
+        IVec3::new(
+            self.x.saturating_mul(rhs.x),
+            self.y.saturating_mul(rhs.y),
+            self.z.saturating_mul(rhs.z),
+        )
+    }
+}
+
+
+impl Debug for IVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IVec3")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .finish()
+    }
+}
+impl Display for IVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .entry(&self.z)
+            .finish()
+    }
+}
+
+
+// IVec3 + IVec3
+impl Add<IVec3> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn add(self, rhs: IVec3) -> Self::Output {
+        IVec3::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        )
+    }
+}
+impl Add<&IVec3> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn add(self, rhs: &IVec3) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<IVec3> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn add(self, rhs: IVec3) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&IVec3> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn add(self, rhs: &IVec3) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// IVec3 + i32
+impl Add<i32> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn add(self, rhs: i32) -> Self::Output {
+        IVec3::new(
+            self.x + rhs,
+            self.y + rhs,
+            self.z + rhs,
+        )
+    }
+}
+impl Add<&i32> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn add(self, rhs: &i32) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<i32> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn add(self, rhs: i32) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&i32> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn add(self, rhs: &i32) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// IVec3 += IVec3
+impl AddAssign<IVec3> for IVec3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: IVec3) {
+        *self = *self + rhs;
+    }
+}
+impl AddAssign<&IVec3> for IVec3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &IVec3) {
+        *self += *rhs;
+    }
+}
+
+// IVec3 += i32
+impl AddAssign<i32> for IVec3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: i32) {
+        *self = *self + rhs;
+    }
+}
+impl AddAssign<&i32> for IVec3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &i32) {
+        *self += *rhs;
+    }
+}
+
+
+// IVec3 - IVec3
+impl Sub<IVec3> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn sub(self, rhs: IVec3) -> Self::Output {
+        IVec3::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+        )
+    }
+}
+impl Sub<&IVec3> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn sub(self, rhs: &IVec3) -> Self::Output {
+        self - *rhs
+    }
+}
+impl Sub<IVec3> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn sub(self, rhs: IVec3) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&IVec3> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn sub(self, rhs: &IVec3) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+// IVec3 - i32
+impl Sub<i32> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn sub(self, rhs: i32) -> Self::Output {
+        IVec3::new(
+            self.x - rhs,
+            self.y - rhs,
+            self.z - rhs,
+        )
+    }
+}
+impl Sub<&i32> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn sub(self, rhs: &i32) -> Self::Output {
+        self - *rhs
+    }
+}
+impl Sub<i32> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn sub(self, rhs: i32) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&i32> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn sub(self, rhs: &i32) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+// IVec3 -= IVec3
+impl SubAssign<IVec3> for IVec3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: IVec3) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&IVec3> for IVec3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &IVec3) {
+        *self -= *rhs;
+    }
+}
+
+// IVec3 -= i32
+impl SubAssign<i32> for IVec3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: i32) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&i32> for IVec3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &i32) {
+        *self -= *rhs;
+    }
+}
+
+
+// IVec3 * IVec3
+impl Mul<IVec3> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: IVec3) -> Self::Output {
+        IVec3::new(
+            self.x * rhs.x,
+            self.y * rhs.y,
+            self.z * rhs.z,
+        )
+    }
+}
+impl Mul<&IVec3> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: &IVec3) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<IVec3> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: IVec3) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&IVec3> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: &IVec3) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// IVec3 * i32
+impl Mul<i32> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        IVec3::new(
+            self.x * rhs,
+            self.y * rhs,
+            self.z * rhs,
+        )
+    }
+}
+impl Mul<&i32> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: &i32) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<i32> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&i32> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn mul(self, rhs: &i32) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// IVec3 *= IVec3
+impl MulAssign<IVec3> for IVec3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: IVec3) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&IVec3> for IVec3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &IVec3) {
+        *self *= *rhs;
+    }
+}
+
+// IVec3 *= i32
+impl MulAssign<i32> for IVec3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&i32> for IVec3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &i32) {
+        *self *= *rhs;
+    }
+}
+
+
+// IVec3 / IVec3
+impl Div<IVec3> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn div(self, rhs: IVec3) -> Self::Output {
+        IVec3::new(
+            self.x / rhs.x,
+            self.y / rhs.y,
+            self.z / rhs.z,
+        )
+    }
+}
+impl Div<&IVec3> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn div(self, rhs: &IVec3) -> Self::Output {
+        self / *rhs
+    }
+}
+impl Div<IVec3> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn div(self, rhs: IVec3) -> Self::Output {
+        *self / rhs
+    }
+}
+impl Div<&IVec3> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn div(self, rhs: &IVec3) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+// IVec3 / i32
+impl Div<i32> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn div(self, rhs: i32) -> Self::Output {
+        IVec3::new(
+            self.x / rhs,
+            self.y / rhs,
+            self.z / rhs,
+        )
+    }
+}
+impl Div<&i32> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn div(self, rhs: &i32) -> Self::Output {
+        self / *rhs
+    }
+}
+impl Div<i32> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn div(self, rhs: i32) -> Self::Output {
+        *self / rhs
+    }
+}
+impl Div<&i32> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn div(self, rhs: &i32) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+// IVec3 /= IVec3
+impl DivAssign<IVec3> for IVec3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: IVec3) {
+        *self = *self / rhs;
+    }
+}
+impl DivAssign<&IVec3> for IVec3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &IVec3) {
+        *self /= *rhs;
+    }
+}
+
+// IVec3 /= i32
+impl DivAssign<i32> for IVec3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: i32) {
+        *self = *self / rhs;
+    }
+}
+impl DivAssign<&i32> for IVec3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &i32) {
+        *self /= *rhs;
+    }
+}
+
+
+// IVec3 % IVec3
+impl Rem<IVec3> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn rem(self, rhs: IVec3) -> Self::Output {
+        IVec3::new(
+            self.x % rhs.x,
+            self.y % rhs.y,
+            self.z % rhs.z,
+        )
+    }
+}
+impl Rem<&IVec3> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn rem(self, rhs: &IVec3) -> Self::Output {
+        self % *rhs
+    }
+}
+impl Rem<IVec3> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn rem(self, rhs: IVec3) -> Self::Output {
+        *self % rhs
+    }
+}
+impl Rem<&IVec3> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn rem(self, rhs: &IVec3) -> Self::Output {
+        *self % *rhs
+    }
+}
+
+// IVec3 % i32
+impl Rem<i32> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn rem(self, rhs: i32) -> Self::Output {
+        IVec3::new(
+            self.x % rhs,
+            self.y % rhs,
+            self.z % rhs,
+        )
+    }
+}
+impl Rem<&i32> for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn rem(self, rhs: &i32) -> Self::Output {
+        self % *rhs
+    }
+}
+impl Rem<i32> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn rem(self, rhs: i32) -> Self::Output {
+        *self % rhs
+    }
+}
+impl Rem<&i32> for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn rem(self, rhs: &i32) -> Self::Output {
+        *self % *rhs
+    }
+}
+
+// IVec3 %= IVec3
+impl RemAssign<IVec3> for IVec3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: IVec3) {
+        *self = *self % rhs;
+    }
+}
+impl RemAssign<&IVec3> for IVec3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &IVec3) {
+        *self %= *rhs;
+    }
+}
+
+// IVec3 %= i32
+impl RemAssign<i32> for IVec3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: i32) {
+        *self = *self % rhs;
+    }
+}
+impl RemAssign<&i32> for IVec3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &i32) {
+        *self %= *rhs;
+    }
+}
+
+
+// -IVec3
+impl Neg for IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        IVec3::new(
+            -self.x,
+            -self.y,
+            -self.z,
+        )
+    }
+}
+impl Neg for &IVec3 {
+    type Output = IVec3;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+
+impl Index<usize> for IVec3 {
+    type Output = i32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Cannot index into a IVec3 at i > 2"),
+        }
+    }
+}
+
+impl IndexMut<usize> for IVec3 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Cannot index into a IVec3 at i > 2"),
+        }
+    }
+}
+
+
+impl From<[i32; 3]> for IVec3 {
+    #[inline]
+    fn from(arr: [i32; 3]) -> IVec3 {
+        IVec3::new(arr[0], arr[1], arr[2])
+    }
+}
+
+impl From<IVec3> for [i32; 3] {
+    #[inline]
+    fn from(v: IVec3) -> [i32; 3] {
+        [v.x, v.y, v.z]
+    }
+}
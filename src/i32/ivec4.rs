@@ -0,0 +1,874 @@
+use crate::{
+    F32Ext,
+    Vec4,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+    ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+        Mul,
+        MulAssign,
+        Div,
+        DivAssign,
+        Rem,
+        RemAssign,
+        Neg,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// An integer vector in 4-space
+/// Operators follow i32 semantics, so they panic on overflow in debug builds
+/// Use the wrapping_ and saturating_ methods where overflow is expected
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct IVec4 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub w: i32,
+}
+
+impl IVec4 {
+    /// The default IVec4 with all 0's
+    pub const ZERO: IVec4 = IVec4::splat(0);
+
+    /// The IVec4 with all 1's
+    pub const ONE: IVec4 = IVec4::splat(1);
+
+    /// The positive x-axis basis vector
+    pub const X: IVec4 = IVec4::new(1, 0, 0, 0);
+
+    /// The positive y-axis basis vector
+    pub const Y: IVec4 = IVec4::new(0, 1, 0, 0);
+
+    /// The positive z-axis basis vector
+    pub const Z: IVec4 = IVec4::new(0, 0, 1, 0);
+
+    /// The positive w-axis basis vector
+    pub const W: IVec4 = IVec4::new(0, 0, 0, 1);
+
+
+    /// Standard constructor for <x y z w>
+    #[inline]
+    pub const fn new(x: i32, y: i32, z: i32, w: i32) -> IVec4 {
+        IVec4 { x, y, z, w }
+    }
+
+    #[inline]
+    pub const fn splat(v: i32) -> IVec4 {
+        IVec4::new(v, v, v, v)
+    }
+
+    /// Returns a copy of self with the x component replaced
+    #[inline]
+    pub const fn with_x(mut self, x: i32) -> IVec4 {
+        self.x = x;
+        self
+    }
+
+    /// Returns a copy of self with the y component replaced
+    #[inline]
+    pub const fn with_y(mut self, y: i32) -> IVec4 {
+        self.y = y;
+        self
+    }
+
+    /// Returns a copy of self with the z component replaced
+    #[inline]
+    pub const fn with_z(mut self, z: i32) -> IVec4 {
+        self.z = z;
+        self
+    }
+
+    /// Returns a copy of self with the w component replaced
+    #[inline]
+    pub const fn with_w(mut self, w: i32) -> IVec4 {
+        self.w = w;
+        self
+    }
+
+
+    /// Converts from a Vec4 rounding each component towards negative infinity
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn from_floor(v: Vec4) -> IVec4 {
+        IVec4::new(
+            v.x.fast_floor_i32(),
+            v.y.fast_floor_i32(),
+            v.z.fast_floor_i32(),
+            v.w.fast_floor_i32(),
+        )
+    }
+
+    /// Converts from a Vec4 rounding each component to the nearest integer, ties to even
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn from_round(v: Vec4) -> IVec4 {
+        IVec4::new(
+            v.x.round_half_even_i32(),
+            v.y.round_half_even_i32(),
+            v.z.round_half_even_i32(),
+            v.w.round_half_even_i32(),
+        )
+    }
+
+    /// Converts from a Vec4 rounding each component towards positive infinity
+    /// Out of range components saturate and NaN becomes 0
+    #[inline]
+    pub fn from_ceil(v: Vec4) -> IVec4 {
+        IVec4::new(
+            v.x.fast_ceil_i32(),
+            v.y.fast_ceil_i32(),
+            v.z.fast_ceil_i32(),
+            v.w.fast_ceil_i32(),
+        )
+    }
+
+    /// Converts to a Vec4
+    /// Components beyond +-2^24 are rounded to the nearest representable f32
+    #[inline]
+    pub fn as_vec4(self) -> Vec4 {
+        Vec4::new(
+            self.x as f32,
+            self.y as f32,
+            self.z as f32,
+            self.w as f32,
+        )
+    }
+
+
+    /// Componentwise minimum
+    #[inline]
+    pub fn min(self, rhs: IVec4) -> IVec4 {
+        IVec4::new(
+            self.x.min(rhs.x),
+            self.y.min(rhs.y),
+            self.z.min(rhs.z),
+            self.w.min(rhs.w),
+        )
+    }
+
+    /// Componentwise maximum
+    #[inline]
+    pub fn max(self, rhs: IVec4) -> IVec4 {
+        IVec4::new(
+            self.x.max(rhs.x),
+            self.y.max(rhs.y),
+            self.z.max(rhs.z),
+            self.w.max(rhs.w),
+        )
+    }
+
+    /// Clamps each component between the corresponding components of min and max
+    /// Requires: min <= max componentwise
+    #[inline]
+    pub fn clamp(self, min: IVec4, max: IVec4) -> IVec4 {
+        self.max(min).min(max)
+    }
+
+    /// The smallest component
+    #[inline]
+    pub fn min_element(self) -> i32 {
+        self.x.min(self.y).min(self.z).min(self.w)
+    }
+
+    /// The largest component
+    #[inline]
+    pub fn max_element(self) -> i32 {
+        self.x.max(self.y).max(self.z).max(self.w)
+    }
+
+    /// Componentwise absolute value
+    /// Panics in debug builds if a component is i32::MIN
+    #[inline]
+    pub fn abs(self) -> IVec4 {
+        IVec4::new(
+            self.x.abs(),
+            self.y.abs(),
+            self.z.abs(),
+            self.w.abs(),
+        )
+    }
+
+    /// Componentwise sign, -1, 0 or 1
+    #[inline]
+    pub fn signum(self) -> IVec4 {
+        IVec4::new(
+            self.x.signum(),
+            self.y.signum(),
+            self.z.signum(),
+            self.w.signum(),
+        )
+    }
+
+    /// The sum of the products of corresponding components
+    #[inline]
+    pub fn dot(self, rhs: IVec4) -> i32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// The sum of the absolute differences of corresponding components, the taxicab distance
+    #[inline]
+    pub fn manhattan_distance(self, rhs: IVec4) -> u32 {
+        self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y) + self.z.abs_diff(rhs.z) + self.w.abs_diff(rhs.w)
+    }
+
+    /// Adds componentwise, wrapping around at the i32 bounds
+    #[inline]
+    pub fn wrapping_add(self, rhs: IVec4) -> IVec4 {
+        IVec4::new(
+            self.x.wrapping_add(rhs.x),
+            self.y.wrapping_add(rhs.y),
+            self.z.wrapping_add(rhs.z),
+            self.w.wrapping_add(rhs.w),
+        )
+    }
+
+    /// Subtracts componentwise, wrapping around at the i32 bounds
+    #[inline]
+    pub fn wrapping_sub(self, rhs: IVec4) -> IVec4 {
+        IVec4::new(
+            self.x.wrapping_sub(rhs.x),
+            self.y.wrapping_sub(rhs.y),
+            self.z.wrapping_sub(rhs.z),
+            self.w.wrapping_sub(rhs.w),
+        )
+    }
+
+    /// Multiplies componentwise, wrapping around at the i32 bounds
+    #[inline]
+    pub fn wrapping_mul(self, rhs: IVec4) -> IVec4 {
+        IVec4::new(
+            self.x.wrapping_mul(rhs.x),
+            self.y.wrapping_mul(rhs.y),
+            self.z.wrapping_mul(rhs.z),
+            self.w.wrapping_mul(rhs.w),
+        )
+    }
+
+    /// Adds componentwise, clamping to the i32 bounds
+    #[inline]
+    pub fn saturating_add(self, rhs: IVec4) -> IVec4 {
+        IVec4::new(
+            self.x.saturating_add(rhs.x),
+            self.y.saturating_add(rhs.y),
+            self.z.saturating_add(rhs.z),
+            self.w.saturating_add(rhs.w),
+        )
+    }
+
+    /// Subtracts componentwise, clamping to the i32 bounds
+    #[inline]
+    pub fn saturating_sub(self, rhs: IVec4) -> IVec4 {
+        IVec4::new(
+            self.x.saturating_sub(rhs.x),
+            self.y.saturating_sub(rhs.y),
+            self.z.saturating_sub(rhs.z),
+            self.w.saturating_sub(rhs.w),
+        )
+    }
+
+    /// Multiplies componentwise, clamping to the i32 bounds
+    #[inline]
+    pub fn saturating_mul(self, rhs: IVec4) -> IVec4 {
+        IVec4::new(
+            self.x.saturating_mul(rhs.x),
+            self.y.saturating_mul(rhs.y),
+            self.z.saturating_mul(rhs.z),
+            self.w.saturating_mul(rhs.w),
+        )
+    }
+}
+
+
+impl Debug for IVec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IVec4")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .field(&self.w)
+            .finish()
+    }
+}
+impl Display for IVec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .entry(&self.z)
+            .entry(&self.w)
+            .finish()
+    }
+}
+
+
+// IVec4 + IVec4
+impl Add<IVec4> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: IVec4) -> Self::Output {
+        IVec4::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+impl Add<&IVec4> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: &IVec4) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: IVec4) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: &IVec4) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// IVec4 + i32
+impl Add<i32> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: i32) -> Self::Output {
+        IVec4::new(
+            self.x + rhs,
+            self.y + rhs,
+            self.z + rhs,
+            self.w + rhs,
+        )
+    }
+}
+impl Add<&i32> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: &i32) -> Self::Output {
+        self + *rhs
+    }
+}
+impl Add<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: i32) -> Self::Output {
+        *self + rhs
+    }
+}
+impl Add<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn add(self, rhs: &i32) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+// IVec4 += IVec4
+impl AddAssign<IVec4> for IVec4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: IVec4) {
+        *self = *self + rhs;
+    }
+}
+impl AddAssign<&IVec4> for IVec4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &IVec4) {
+        *self += *rhs;
+    }
+}
+
+// IVec4 += i32
+impl AddAssign<i32> for IVec4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: i32) {
+        *self = *self + rhs;
+    }
+}
+impl AddAssign<&i32> for IVec4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: &i32) {
+        *self += *rhs;
+    }
+}
+
+
+// IVec4 - IVec4
+impl Sub<IVec4> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: IVec4) -> Self::Output {
+        IVec4::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+impl Sub<&IVec4> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: &IVec4) -> Self::Output {
+        self - *rhs
+    }
+}
+impl Sub<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: IVec4) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: &IVec4) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+// IVec4 - i32
+impl Sub<i32> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: i32) -> Self::Output {
+        IVec4::new(
+            self.x - rhs,
+            self.y - rhs,
+            self.z - rhs,
+            self.w - rhs,
+        )
+    }
+}
+impl Sub<&i32> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: &i32) -> Self::Output {
+        self - *rhs
+    }
+}
+impl Sub<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: i32) -> Self::Output {
+        *self - rhs
+    }
+}
+impl Sub<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn sub(self, rhs: &i32) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+// IVec4 -= IVec4
+impl SubAssign<IVec4> for IVec4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: IVec4) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&IVec4> for IVec4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &IVec4) {
+        *self -= *rhs;
+    }
+}
+
+// IVec4 -= i32
+impl SubAssign<i32> for IVec4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: i32) {
+        *self = *self - rhs;
+    }
+}
+impl SubAssign<&i32> for IVec4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &i32) {
+        *self -= *rhs;
+    }
+}
+
+
+// IVec4 * IVec4
+impl Mul<IVec4> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: IVec4) -> Self::Output {
+        IVec4::new(
+            self.x * rhs.x,
+            self.y * rhs.y,
+            self.z * rhs.z,
+            self.w * rhs.w,
+        )
+    }
+}
+impl Mul<&IVec4> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: &IVec4) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: IVec4) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: &IVec4) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// IVec4 * i32
+impl Mul<i32> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        IVec4::new(
+            self.x * rhs,
+            self.y * rhs,
+            self.z * rhs,
+            self.w * rhs,
+        )
+    }
+}
+impl Mul<&i32> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: &i32) -> Self::Output {
+        self * *rhs
+    }
+}
+impl Mul<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        *self * rhs
+    }
+}
+impl Mul<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn mul(self, rhs: &i32) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+// IVec4 *= IVec4
+impl MulAssign<IVec4> for IVec4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: IVec4) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&IVec4> for IVec4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &IVec4) {
+        *self *= *rhs;
+    }
+}
+
+// IVec4 *= i32
+impl MulAssign<i32> for IVec4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<&i32> for IVec4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &i32) {
+        *self *= *rhs;
+    }
+}
+
+
+// IVec4 / IVec4
+impl Div<IVec4> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: IVec4) -> Self::Output {
+        IVec4::new(
+            self.x / rhs.x,
+            self.y / rhs.y,
+            self.z / rhs.z,
+            self.w / rhs.w,
+        )
+    }
+}
+impl Div<&IVec4> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: &IVec4) -> Self::Output {
+        self / *rhs
+    }
+}
+impl Div<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: IVec4) -> Self::Output {
+        *self / rhs
+    }
+}
+impl Div<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: &IVec4) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+// IVec4 / i32
+impl Div<i32> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: i32) -> Self::Output {
+        IVec4::new(
+            self.x / rhs,
+            self.y / rhs,
+            self.z / rhs,
+            self.w / rhs,
+        )
+    }
+}
+impl Div<&i32> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: &i32) -> Self::Output {
+        self / *rhs
+    }
+}
+impl Div<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: i32) -> Self::Output {
+        *self / rhs
+    }
+}
+impl Div<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn div(self, rhs: &i32) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+// IVec4 /= IVec4
+impl DivAssign<IVec4> for IVec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: IVec4) {
+        *self = *self / rhs;
+    }
+}
+impl DivAssign<&IVec4> for IVec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &IVec4) {
+        *self /= *rhs;
+    }
+}
+
+// IVec4 /= i32
+impl DivAssign<i32> for IVec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: i32) {
+        *self = *self / rhs;
+    }
+}
+impl DivAssign<&i32> for IVec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: &i32) {
+        *self /= *rhs;
+    }
+}
+
+
+// IVec4 % IVec4
+impl Rem<IVec4> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: IVec4) -> Self::Output {
+        IVec4::new(
+            self.x % rhs.x,
+            self.y % rhs.y,
+            self.z % rhs.z,
+            self.w % rhs.w,
+        )
+    }
+}
+impl Rem<&IVec4> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: &IVec4) -> Self::Output {
+        self % *rhs
+    }
+}
+impl Rem<IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: IVec4) -> Self::Output {
+        *self % rhs
+    }
+}
+impl Rem<&IVec4> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: &IVec4) -> Self::Output {
+        *self % *rhs
+    }
+}
+
+// IVec4 % i32
+impl Rem<i32> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: i32) -> Self::Output {
+        IVec4::new(
+            self.x % rhs,
+            self.y % rhs,
+            self.z % rhs,
+            self.w % rhs,
+        )
+    }
+}
+impl Rem<&i32> for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: &i32) -> Self::Output {
+        self % *rhs
+    }
+}
+impl Rem<i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: i32) -> Self::Output {
+        *self % rhs
+    }
+}
+impl Rem<&i32> for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn rem(self, rhs: &i32) -> Self::Output {
+        *self % *rhs
+    }
+}
+
+// IVec4 %= IVec4
+impl RemAssign<IVec4> for IVec4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: IVec4) {
+        *self = *self % rhs;
+    }
+}
+impl RemAssign<&IVec4> for IVec4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &IVec4) {
+        *self %= *rhs;
+    }
+}
+
+// IVec4 %= i32
+impl RemAssign<i32> for IVec4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: i32) {
+        *self = *self % rhs;
+    }
+}
+impl RemAssign<&i32> for IVec4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: &i32) {
+        *self %= *rhs;
+    }
+}
+
+
+// -IVec4
+impl Neg for IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        IVec4::new(
+            -self.x,
+            -self.y,
+            -self.z,
+            -self.w,
+        )
+    }
+}
+impl Neg for &IVec4 {
+    type Output = IVec4;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+
+impl Index<usize> for IVec4 {
+    type Output = i32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("Cannot index into a IVec4 at i > 3"),
+        }
+    }
+}
+
+impl IndexMut<usize> for IVec4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("Cannot index into a IVec4 at i > 3"),
+        }
+    }
+}
+
+
+impl From<[i32; 4]> for IVec4 {
+    #[inline]
+    fn from(arr: [i32; 4]) -> IVec4 {
+        IVec4::new(arr[0], arr[1], arr[2], arr[3])
+    }
+}
+
+impl From<IVec4> for [i32; 4] {
+    #[inline]
+    fn from(v: IVec4) -> [i32; 4] {
+        [v.x, v.y, v.z, v.w]
+    }
+}
@@ -0,0 +1,44 @@
+// Integer helpers for buffer sizing, alignment, and texture mip chains
+// All functions are const so they can be used to size static buffers
+
+
+/// Rounds v up to the next power of two
+/// Returns 1 for v == 0
+/// Requires: v <= 2^31
+#[inline]
+pub const fn next_power_of_two(v: u32) -> u32 {
+    v.next_power_of_two()
+}
+
+/// Returns floor(log2(v))
+/// Requires: v > 0
+#[inline]
+pub const fn ilog2(v: u32) -> u32 {
+    v.ilog2()
+}
+
+/// Divides a by b rounding towards positive infinity
+/// Requires: b > 0
+#[inline]
+pub const fn div_ceil(a: u32, b: u32) -> u32 {
+    a.div_ceil(b)
+}
+
+/// Rounds v up to the next multiple of align
+/// Requires: align is a power of two, and the result fits in a u32 (v <= u32::MAX - (align - 1))
+#[inline]
+pub const fn align_to(v: u32, align: u32) -> u32 {
+    debug_assert!(align.is_power_of_two());
+    (v + (align - 1)) & !(align - 1)
+}
+
+/// Returns the number of levels in a full mip chain for a texture of the given size
+/// Returns 0 if either dimension is 0
+#[inline]
+pub const fn mip_level_count(width: u32, height: u32) -> u32 {
+    if width == 0 || height == 0 {
+        return 0;
+    }
+    let largest = if width > height { width } else { height };
+    ilog2(largest) + 1
+}
@@ -0,0 +1,72 @@
+// Interpolation over a unit square or cube of samples
+// Generic over anything that can be added and scaled by an f32, e.g. f32, Vec2, Vec3, Vec4
+
+use crate::{
+    Vec2,
+    Vec3,
+};
+use std::{
+    ops::{
+        Add,
+        Mul,
+    },
+};
+
+
+#[inline]
+fn lerp<T>(a: T, b: T, t: f32) -> T
+where
+    T: Add<Output = T> + Mul<f32, Output = T>,
+{
+    a * (1.0 - t) + b * t
+}
+
+/// Bilinearly interpolates the corners of a unit square
+/// c00 is at uv = (0, 0), c10 at (1, 0), c01 at (0, 1) and c11 at (1, 1)
+#[inline]
+pub fn bilerp<T>(c00: T, c10: T, c01: T, c11: T, uv: Vec2) -> T
+where
+    T: Add<Output = T> + Mul<f32, Output = T>,
+{
+    lerp(lerp(c00, c10, uv.x), lerp(c01, c11, uv.x), uv.y)
+}
+
+/// Trilinearly interpolates the corners of a unit cube
+/// c is ordered with x varying fastest, then y, then z: c000, c100, c010, c110, c001, c101, c011, c111
+#[inline]
+pub fn trilerp<T>(c: [T; 8], uvw: Vec3) -> T
+where
+    T: Copy + Add<Output = T> + Mul<f32, Output = T>,
+{
+    let [c000, c100, c010, c110, c001, c101, c011, c111] = c;
+    let uv = Vec2::new(uvw.x, uvw.y);
+    lerp(bilerp(c000, c100, c010, c110, uv), bilerp(c001, c101, c011, c111, uv), uvw.z)
+}
+
+/// Catmull-Rom interpolation between p1 (t = 0) and p2 (t = 1), with p0 and p3 shaping the tangents
+/// Passes through every sample, but may overshoot between them
+#[inline]
+pub fn catmull_rom<T>(p0: T, p1: T, p2: T, p3: T, t: f32) -> T
+where
+    T: Copy + Add<Output = T> + Mul<f32, Output = T>,
+{
+    let t2 = t * t;
+    let t3 = t2 * t;
+    // weights of the standard Catmull-Rom basis matrix, they sum to 1
+    let w0 = 0.5 * (-t3 + 2.0 * t2 - t);
+    let w1 = 0.5 * (3.0 * t3 - 5.0 * t2 + 2.0);
+    let w2 = 0.5 * (-3.0 * t3 + 4.0 * t2 + t);
+    let w3 = 0.5 * (t3 - t2);
+    p0 * w0 + p1 * w1 + p2 * w2 + p3 * w3
+}
+
+/// Bicubic Catmull-Rom interpolation of a 4x4 neighborhood of samples, indexed p[y][x]
+/// uv = (0, 0) lands on p[1][1] and uv = (1, 1) on p[2][2]
+#[inline]
+pub fn bicubic<T>(p: [[T; 4]; 4], uv: Vec2) -> T
+where
+    T: Copy + Add<Output = T> + Mul<f32, Output = T>,
+{
+    let row = |r: [T; 4]| catmull_rom(r[0], r[1], r[2], r[3], uv.x);
+    catmull_rom(row(p[0]), row(p[1]), row(p[2]), row(p[3]), uv.y)
+}
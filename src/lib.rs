@@ -1,5 +1,96 @@
 // remove for release
 mod asm_symbols;
 
+mod validate;
+
+#[cfg(feature = "nan_guard")]
+pub mod nan_guard;
+
+pub mod axis;
+pub use axis::Axis;
+
+pub mod error;
+pub use error::MathError;
+
+pub mod scalar;
+pub use scalar::Scalar;
+
 pub mod f32;
 pub use f32::*;
+
+pub mod f64;
+pub use f64::F64Ext;
+
+#[cfg(feature = "f16")]
+pub mod f16;
+#[cfg(feature = "f16")]
+pub use f16::*;
+
+pub mod bool;
+pub use bool::*;
+
+pub mod i32;
+pub use i32::*;
+
+pub mod u32;
+pub use u32::*;
+
+pub mod u8;
+pub use u8::*;
+
+pub mod usize;
+pub use usize::*;
+
+pub mod vector;
+pub use vector::Vector;
+
+pub mod int_math;
+
+#[cfg(feature = "curves")]
+pub mod interp;
+#[cfg(feature = "curves")]
+pub use interp::{bilerp, trilerp, catmull_rom, bicubic};
+
+#[cfg(feature = "physics")]
+pub mod ode;
+
+#[cfg(feature = "physics")]
+pub mod ballistics;
+
+#[cfg(feature = "camera")]
+pub mod camera;
+
+#[cfg(feature = "geo")]
+pub mod geo;
+
+#[cfg(feature = "geometry")]
+pub mod tangent;
+
+#[cfg(feature = "geometry")]
+pub mod envmap;
+
+#[cfg(feature = "geometry")]
+pub mod geom;
+
+#[cfg(feature = "camera")]
+pub mod viewport;
+#[cfg(feature = "camera")]
+pub use viewport::{ClipConvention, Viewport};
+
+#[cfg(feature = "geometry")]
+pub mod mass;
+#[cfg(feature = "geometry")]
+pub use mass::MassProperties;
+
+#[cfg(feature = "grid")]
+pub mod grid;
+#[cfg(feature = "grid")]
+pub use grid::*;
+
+pub mod prelude;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "derive")]
+pub use gdmx_derive::Vector;
@@ -0,0 +1,120 @@
+// Mass properties of solid primitives with uniform density
+// Shapes are centered on the origin, capsules and cylinders are aligned with the y-axis
+
+use crate::{
+    MathError,
+    Mat3,
+    Vec3,
+    VecExt,
+};
+use std::f32::consts::PI;
+
+
+/// Mass, center of mass and inertia tensor of a rigid body
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct MassProperties {
+    pub mass: f32,
+    pub center_of_mass: Vec3,
+    /// The inertia tensor about the center of mass
+    pub inertia: Mat3,
+}
+
+impl MassProperties {
+    /// A box with the given half extents
+    #[inline]
+    pub fn cuboid(half_extents: Vec3, density: f32) -> MassProperties {
+        let size_2 = half_extents * half_extents * 4.0;
+        let mass = density * 8.0 * half_extents.x * half_extents.y * half_extents.z;
+        let inertia = Mat3::from_diagonal(Vec3::new(
+            size_2.y + size_2.z,
+            size_2.x + size_2.z,
+            size_2.x + size_2.y,
+        ) * (mass / 12.0));
+        MassProperties { mass, center_of_mass: Vec3::ZERO, inertia }
+    }
+
+    /// A solid sphere
+    #[inline]
+    pub fn sphere(radius: f32, density: f32) -> MassProperties {
+        let mass = density * 4.0 / 3.0 * PI * radius * radius * radius;
+        let i = 0.4 * mass * radius * radius;
+        MassProperties { mass, center_of_mass: Vec3::ZERO, inertia: Mat3::from_diagonal(Vec3::splat(i)) }
+    }
+
+    /// A solid cylinder along the y-axis with flat caps
+    #[inline]
+    pub fn cylinder(half_height: f32, radius: f32, density: f32) -> MassProperties {
+        let height = 2.0 * half_height;
+        let r_2 = radius * radius;
+        let mass = density * PI * r_2 * height;
+        let axial = 0.5 * mass * r_2;
+        let transverse = mass * (3.0 * r_2 + height * height) / 12.0;
+        MassProperties {
+            mass,
+            center_of_mass: Vec3::ZERO,
+            inertia: Mat3::from_diagonal(Vec3::new(transverse, axial, transverse)),
+        }
+    }
+
+    /// A capsule along the y-axis, half_height is half the length of the cylindrical part
+    #[inline]
+    pub fn capsule(half_height: f32, radius: f32, density: f32) -> MassProperties {
+        let height = 2.0 * half_height;
+        let r_2 = radius * radius;
+        let cylinder_mass = density * PI * r_2 * height;
+        // both hemispherical caps together form one sphere
+        let caps_mass = density * 4.0 / 3.0 * PI * r_2 * radius;
+        let axial = cylinder_mass * r_2 * 0.5 + caps_mass * r_2 * 0.4;
+        // each cap's center of mass sits 3r/8 beyond the end of the cylinder
+        let transverse = cylinder_mass * (height * height / 12.0 + r_2 * 0.25)
+            + caps_mass * (r_2 * 0.4 + height * height * 0.25 + 0.375 * height * radius);
+        MassProperties {
+            mass: cylinder_mass + caps_mass,
+            center_of_mass: Vec3::ZERO,
+            inertia: Mat3::from_diagonal(Vec3::new(transverse, axial, transverse)),
+        }
+    }
+
+    /// A closed convex (or any closed, consistently wound) triangle mesh
+    /// Fails with Degenerate if the mesh encloses no volume
+    /// Requires: triangles should be wound counter-clockwise when viewed from outside,
+    /// and every index should be in bounds of vertices
+    pub fn convex_mesh(vertices: &[Vec3], triangles: &[[u32; 3]], density: f32) -> Result<MassProperties, MathError> {
+        // sum the signed tetrahedra formed by each triangle and the origin
+        // the covariance of the canonical tetrahedron (0, x, y, z) is [2 1 1; 1 2 1; 1 1 2] / 120
+        let canonical = Mat3::from_cols(
+            Vec3::new(2.0, 1.0, 1.0),
+            Vec3::new(1.0, 2.0, 1.0),
+            Vec3::new(1.0, 1.0, 2.0),
+        ) * (1.0 / 120.0);
+
+        let mut volume = 0.0;
+        let mut first_moment = Vec3::ZERO;
+        let mut covariance = Mat3::ZERO;
+        for &[a, b, c] in triangles {
+            let (a, b, c) = (vertices[a as usize], vertices[b as usize], vertices[c as usize]);
+            let m = Mat3::from_cols(a, b, c);
+            let det = m.determinant();
+            volume += det / 6.0;
+            first_moment += (a + b + c) * (det / 24.0);
+            covariance += m * canonical * m.transpose() * det;
+        }
+        if volume <= 0.0 {
+            return Err(MathError::Degenerate);
+        }
+
+        let mass = density * volume;
+        let center_of_mass = first_moment / volume;
+        // move the covariance to the center of mass, then convert it to an inertia tensor
+        let covariance = covariance * density - Mat3::from_outer(center_of_mass, center_of_mass) * mass;
+        let inertia = Mat3::IDENTITY * covariance.trace() - covariance;
+        Ok(MassProperties { mass, center_of_mass, inertia })
+    }
+
+    /// The inertia tensor about an arbitrary point, via the parallel axis theorem
+    #[inline]
+    pub fn inertia_about(&self, point: Vec3) -> Mat3 {
+        let d = self.center_of_mass - point;
+        self.inertia + (Mat3::IDENTITY * d.length_2() - Mat3::from_outer(d, d)) * self.mass
+    }
+}
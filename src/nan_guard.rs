@@ -0,0 +1,111 @@
+// Reports the first operation that turns finite inputs into a NaN or infinity
+// Only compiled with the nan_guard feature, the operators call check through validate::nan_guard!
+
+use crate::{
+    Vec2,
+    Vec3,
+    Vec3A,
+    Vec4,
+    VecExt,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    sync::{
+        Arc,
+        RwLock,
+    },
+};
+
+
+/// Describes a gdmx operation that produced a non-finite result from finite operands
+pub struct NanReport<'a> {
+    /// The operation, e.g. "Vec3 / f32"
+    pub op: &'static str,
+    /// The operands in order
+    pub operands: &'a [&'a dyn Debug],
+    /// The non-finite result
+    pub result: &'a dyn Debug,
+}
+
+impl Display for NanReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} produced {:?} from", self.op, self.result)?;
+        for operand in self.operands {
+            write!(f, " {operand:?}")?;
+        }
+        Ok(())
+    }
+}
+
+
+type NanHook = Arc<dyn Fn(&NanReport) + Send + Sync>;
+
+static HOOK: RwLock<Option<NanHook>> = RwLock::new(None);
+
+/// Installs a hook that is called every time a gdmx operation first produces a NaN or infinity
+/// Replaces any previously installed hook
+/// Requires: the hook should not itself produce non-finite values through gdmx
+pub fn set_nan_hook(hook: impl Fn(&NanReport) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(hook));
+}
+
+/// Removes the installed hook, returning it if there was one
+pub fn take_nan_hook() -> Option<NanHook> {
+    HOOK.write().unwrap_or_else(|e| e.into_inner()).take()
+}
+
+/// A hook that prints each report to stderr
+pub fn eprint_report(report: &NanReport) {
+    eprintln!("gdmx: {report}");
+}
+
+
+/// Values whose finiteness the guard can check
+pub(crate) trait Guarded: Debug {
+    fn all_finite(&self) -> bool;
+}
+
+impl Guarded for f32 {
+    #[inline]
+    fn all_finite(&self) -> bool {
+        self.is_finite()
+    }
+}
+
+macro_rules! impl_guarded {
+    ($($t:ty),+) => {
+        $(
+            impl Guarded for $t {
+                #[inline]
+                fn all_finite(&self) -> bool {
+                    self.is_finite()
+                }
+            }
+        )+
+    };
+}
+impl_guarded!(Vec2, Vec3, Vec3A, Vec4);
+
+/// Calls the hook if result is non-finite while every operand is finite
+#[cold]
+#[inline(never)]
+fn report(op: &'static str, result: &dyn Guarded, operands: &[&dyn Guarded]) {
+    // cloned out so the lock is released before the hook runs, letting it replace itself
+    let hook = HOOK.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(hook) = hook {
+        let operands: Vec<&dyn Debug> = operands.iter().map(|&o| o as &dyn Debug).collect();
+        hook(&NanReport { op, operands: &operands, result });
+    }
+}
+
+#[inline]
+pub(crate) fn check(op: &'static str, result: &dyn Guarded, operands: &[&dyn Guarded]) {
+    if !result.all_finite() && operands.iter().all(|o| o.all_finite()) {
+        report(op, result, operands);
+    }
+}
+
@@ -0,0 +1,104 @@
+// Fixed-step integrators for second order systems, x'' = a(x, x')
+// Generic over any VecExt vector so they work in 2D, 3D and with f64
+
+use crate::{
+    Scalar,
+    VecExt,
+};
+
+
+/// Position and velocity of a point being integrated
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct State<V> {
+    pub pos: V,
+    pub vel: V,
+}
+
+impl<V> State<V> {
+    #[inline]
+    pub const fn new(pos: V, vel: V) -> State<V> {
+        State { pos, vel }
+    }
+}
+
+
+/// The integration schemes available through Integrator::integrate
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Integrator {
+    /// First order, one acceleration evaluation per step
+    /// Symplectic, so orbits and springs don't gain energy
+    #[default]
+    SemiImplicitEuler,
+    /// Second order, two acceleration evaluations per step
+    /// Symplectic when the acceleration does not depend on velocity
+    VelocityVerlet,
+    /// Fourth order, four acceleration evaluations per step
+    /// Most accurate for smooth forces, but slowly drifts in energy
+    Rk4,
+}
+
+impl Integrator {
+    /// Advances state by dt using accel(pos, vel) as the acceleration
+    #[inline]
+    pub fn integrate<V, const N: usize, T>(self, state: State<V>, accel: impl Fn(V, V) -> V, dt: T) -> State<V>
+    where
+        V: VecExt<N, T>,
+        T: Scalar,
+    {
+        match self {
+            Integrator::SemiImplicitEuler => semi_implicit_euler(state, accel, dt),
+            Integrator::VelocityVerlet => velocity_verlet(state, accel, dt),
+            Integrator::Rk4 => rk4(state, accel, dt),
+        }
+    }
+}
+
+
+/// Updates velocity first, then moves with the new velocity
+#[inline]
+pub fn semi_implicit_euler<V, const N: usize, T>(state: State<V>, accel: impl Fn(V, V) -> V, dt: T) -> State<V>
+where
+    V: VecExt<N, T>,
+    T: Scalar,
+{
+    let vel = state.vel + accel(state.pos, state.vel) * dt;
+    State::new(state.pos + vel * dt, vel)
+}
+
+/// Velocity Verlet, the velocity passed to accel for the second evaluation is a first order estimate
+#[inline]
+pub fn velocity_verlet<V, const N: usize, T>(state: State<V>, accel: impl Fn(V, V) -> V, dt: T) -> State<V>
+where
+    V: VecExt<N, T>,
+    T: Scalar,
+{
+    let a0 = accel(state.pos, state.vel);
+    let pos = state.pos + state.vel * dt + a0 * (T::HALF * dt * dt);
+    let a1 = accel(pos, state.vel + a0 * dt);
+    State::new(pos, state.vel + (a0 + a1) * (T::HALF * dt))
+}
+
+/// Classic fourth order Runge-Kutta
+#[inline]
+pub fn rk4<V, const N: usize, T>(state: State<V>, accel: impl Fn(V, V) -> V, dt: T) -> State<V>
+where
+    V: VecExt<N, T>,
+    T: Scalar,
+{
+    let half_dt = T::HALF * dt;
+    let (x0, v0) = (state.pos, state.vel);
+
+    let a1 = accel(x0, v0);
+    let (x2, v2) = (x0 + v0 * half_dt, v0 + a1 * half_dt);
+    let a2 = accel(x2, v2);
+    let (x3, v3) = (x0 + v2 * half_dt, v0 + a2 * half_dt);
+    let a3 = accel(x3, v3);
+    let (x4, v4) = (x0 + v3 * dt, v0 + a3 * dt);
+    let a4 = accel(x4, v4);
+
+    let sixth_dt = dt / (T::THREE * T::TWO);
+    State::new(
+        x0 + (v0 + (v2 + v3) * T::TWO + v4) * sixth_dt,
+        v0 + (a1 + (a2 + a3) * T::TWO + a4) * sixth_dt,
+    )
+}
@@ -0,0 +1,51 @@
+// Everything most users need with a single `use gdmx::prelude::*;`
+// Only F32Ext of the scalar traits, Scalar and F64Ext would make its methods ambiguous on f32 and literals
+
+
+pub use crate::{
+    Axis,
+    MathError,
+    F32Ext,
+    VecExt,
+    Vec2,
+    Vec3,
+    Vec3A,
+    Vec4,
+    Dir2,
+    Dir3,
+    Rot2,
+    Mat2,
+    Mat3,
+    Mat4,
+    Affine2,
+    Affine3,
+    Quat,
+    Transform,
+    Isometry3,
+    BVec2,
+    BVec3,
+    BVec4,
+    IVec2,
+    IVec3,
+    IVec4,
+    UVec3,
+    U8Vec4,
+    USizeVec2,
+    USizeVec3,
+    Finite,
+    NotNan,
+};
+
+pub use crate::vector::Vector;
+
+pub use crate::f32::consts::{
+    PI,
+    TAU,
+    FRAC_PI_2,
+    FRAC_PI_4,
+    DEG_TO_RAD,
+    RAD_TO_DEG,
+};
+
+#[cfg(feature = "derive")]
+pub use gdmx_derive::Vector;
@@ -0,0 +1,673 @@
+use std::{
+    cmp::{
+        Ordering,
+    },
+    fmt::{
+        Debug,
+        Display,
+    },
+    iter::{
+        Product,
+        Sum,
+    },
+    ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+        Mul,
+        MulAssign,
+        Div,
+        DivAssign,
+        Rem,
+        RemAssign,
+        Neg,
+    },
+};
+
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// The floating point types gdmx is generic over (f32 and f64)
+/// Provides the primitive operations the generic vector code is written against,
+/// plus the shared scalar helpers so f32 and f64 use a single implementation of each
+/// Sealed: it can not be implemented outside of gdmx
+pub trait Scalar:
+    sealed::Sealed
+    + Copy
+    + Clone
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+    + Sum
+    + Product
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+    const THREE: Self;
+    const HALF: Self;
+    const PI: Self;
+    const TAU: Self;
+    const EPSILON: Self;
+    /// The crate-wide default tolerance for approximate comparisons
+    /// 1e-5 for f32 and 1e-10 for f64, loose enough for a few chained operations on unit-scale values
+    const DEFAULT_EPS: Self;
+    const NAN: Self;
+    const INFINITY: Self;
+    /// The shortest smooth_time smooth_damp honors, shorter times are clamped to it to avoid dividing by 0
+    const SMOOTH_DAMP_MIN_TIME: Self;
+
+    /// The spring frequency and per-step decay shared by the scalar and vector smooth_damp
+    #[doc(hidden)]
+    fn smooth_damp_factors(smooth_time: Self, dt: Self) -> (Self, Self);
+
+    // primitive operations, forwarded to the inherent methods of f32/f64
+    fn sqrt(self) -> Self;
+    fn rsqrt(self) -> Self;
+    fn abs(self) -> Self;
+    fn min(self, rhs: Self) -> Self;
+    fn max(self, rhs: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn recip(self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn acos(self) -> Self;
+    fn asin(self) -> Self;
+    fn atan2(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn round_ties_even(self) -> Self;
+    fn to_radians(self) -> Self;
+    fn to_degrees(self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn is_sign_negative(self) -> bool;
+    fn total_cmp(&self, rhs: &Self) -> Ordering;
+
+    /// Saturating conversion to i32, NaN becomes 0
+    fn as_i32(self) -> i32;
+    fn from_i32(v: i32) -> Self;
+    /// The bit pattern sign extended to i64
+    /// Ordered the same as the values for floats of the same sign
+    fn to_signed_bits(self) -> i64;
+
+
+    /// Returns (sin, cos) of self computed together
+    #[inline]
+    fn sincos(self) -> (Self, Self) {
+        self.sin_cos()
+    }
+
+    /// Clamps self to [0, 1]
+    #[inline]
+    fn saturate(self) -> Self {
+        self.clamp(Self::ZERO, Self::ONE)
+    }
+
+    /// GLSL step, 0 if self is below edge and 1 otherwise
+    #[inline]
+    fn step(self, edge: Self) -> Self {
+        if self < edge { Self::ZERO } else { Self::ONE }
+    }
+
+    /// Hermite interpolation of self between edge0 and edge1, clamped to [0, 1]
+    /// Requires: edge0 != edge1
+    #[inline]
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = ((self - edge0) / (edge1 - edge0)).saturate();
+        t * t * (Self::THREE - Self::TWO * t)
+    }
+
+    /// Returns acos(self) with self clamped to [-1, 1] first
+    /// Avoids NaN when rounding error pushes a cosine slightly out of range
+    #[inline]
+    fn acos_clamped(self) -> Self {
+        self.clamp(-Self::ONE, Self::ONE).acos()
+    }
+
+    /// Returns asin(self) with self clamped to [-1, 1] first
+    /// Avoids NaN when rounding error pushes a sine slightly out of range
+    #[inline]
+    fn asin_clamped(self) -> Self {
+        self.clamp(-Self::ONE, Self::ONE).asin()
+    }
+
+    /// Wraps an angle in radians to the range (-pi, pi]
+    #[inline]
+    fn wrap_angle(self) -> Self {
+        let wrapped = (self + Self::PI).rem_euclid(Self::TAU) - Self::PI;
+        // rem_euclid returns [0, tau) so the lower bound needs to be flipped over to pi
+        if wrapped <= -Self::PI { Self::PI } else { wrapped }
+    }
+
+    /// Returns the shortest signed angle in radians to travel from self to `to`
+    /// The result lies in (-pi, pi]
+    #[inline]
+    fn delta_angle(self, to: Self) -> Self {
+        (to - self).wrap_angle()
+    }
+
+    /// Interpolates from self to `to` along the shortest arc
+    /// The result is not wrapped, use wrap_angle on it if needed
+    #[inline]
+    fn lerp_angle(self, to: Self, t: Self) -> Self {
+        self + self.delta_angle(to) * t
+    }
+
+    /// The t for which lerping from a to b gives self, the inverse of lerp
+    /// Not clamped, so values outside [a, b] give t outside [0, 1]
+    /// Requires: a != b
+    #[inline]
+    fn inverse_lerp(self, a: Self, b: Self) -> Self {
+        (self - a) / (b - a)
+    }
+
+    /// Maps self from the range [in_min, in_max] to [out_min, out_max], without clamping
+    /// Requires: in_min != in_max
+    #[inline]
+    fn remap(self, in_min: Self, in_max: Self, out_min: Self, out_max: Self) -> Self {
+        out_min + (out_max - out_min) * self.inverse_lerp(in_min, in_max)
+    }
+
+    /// Returns the absolute difference between self and rhs
+    #[inline]
+    fn abs_diff(self, rhs: Self) -> Self {
+        (self - rhs).abs()
+    }
+
+    /// Checks if self and rhs are within eps of each other
+    /// Equal infinities compare equal, NaN never does
+    #[inline]
+    fn approx_eq(self, rhs: Self, eps: Self) -> bool {
+        // inf - inf is NaN, so equal infinities need the exact check
+        self == rhs || self.abs_diff(rhs) <= eps
+    }
+
+    /// Checks if self and rhs are within eps of each other, or within max_relative times the larger magnitude
+    /// eps handles values near 0 where a relative tolerance shrinks to nothing
+    /// Equal infinities compare equal, NaN never does
+    #[inline]
+    fn relative_eq(self, rhs: Self, eps: Self, max_relative: Self) -> bool {
+        if self == rhs {
+            return true;
+        }
+        let diff = self.abs_diff(rhs);
+        diff <= eps || diff <= self.abs().max(rhs.abs()) * max_relative
+    }
+
+    /// Checks if self and rhs are at most ulps representable floats apart
+    /// NaN is never equal to anything, +0.0 and -0.0 are equal
+    #[inline]
+    fn approx_eq_ulps(self, rhs: Self, ulps: u32) -> bool {
+        if self.is_nan() || rhs.is_nan() {
+            return false;
+        }
+        if self == rhs {
+            return true;
+        }
+        // the bit patterns are only ordered for floats of the same sign
+        if self.is_sign_negative() != rhs.is_sign_negative() {
+            return false;
+        }
+        self.to_signed_bits().abs_diff(rhs.to_signed_bits()) <= ulps as u64
+    }
+
+    /// Moves self towards target by an amount that is independent of the frame rate
+    /// Equivalent to lerping with t = 1 - e^(-rate * dt)
+    #[inline]
+    fn exp_decay(self, target: Self, rate: Self, dt: Self) -> Self {
+        self + (target - self) * (Self::ONE - (-rate * dt).exp())
+    }
+
+    /// Moves self towards target like a critically damped spring, Unity's SmoothDamp
+    /// velocity carries between calls, start it at 0 and keep it alongside the value
+    /// smooth_time is roughly how long it takes to reach the target, never overshoots
+    #[inline]
+    fn smooth_damp(self, target: Self, velocity: &mut Self, smooth_time: Self, dt: Self) -> Self {
+        let (omega, decay) = Self::smooth_damp_factors(smooth_time, dt);
+        let change = self - target;
+        let temp = (*velocity + omega * change) * dt;
+        *velocity = (*velocity - omega * temp) * decay;
+        let out = target + (change + temp) * decay;
+        // Passed the target, so snap to it and stop
+        if (target - self > Self::ZERO) == (out > target) {
+            *velocity = Self::ZERO;
+            return target;
+        }
+        out
+    }
+
+    /// Returns the lesser of self and rhs according to total_cmp
+    /// -0.0 is less than +0.0, and NaNs sort by sign to either end
+    #[inline]
+    fn total_min(self, rhs: Self) -> Self {
+        if self.total_cmp(&rhs).is_le() { self } else { rhs }
+    }
+
+    /// Returns the greater of self and rhs according to total_cmp
+    /// -0.0 is less than +0.0, and NaNs sort by sign to either end
+    #[inline]
+    fn total_max(self, rhs: Self) -> Self {
+        if self.total_cmp(&rhs).is_ge() { self } else { rhs }
+    }
+
+    /// Returns the lesser of self and rhs, or NaN if either is NaN
+    #[inline]
+    fn min_nan_propagating(self, rhs: Self) -> Self {
+        if self.is_nan() || rhs.is_nan() { Self::NAN } else { self.min(rhs) }
+    }
+
+    /// Returns the greater of self and rhs, or NaN if either is NaN
+    #[inline]
+    fn max_nan_propagating(self, rhs: Self) -> Self {
+        if self.is_nan() || rhs.is_nan() { Self::NAN } else { self.max(rhs) }
+    }
+
+    /// Returns the lesser of self and rhs, or the other value if one of them is NaN
+    #[inline]
+    fn min_nan_ignoring(self, rhs: Self) -> Self {
+        self.min(rhs)
+    }
+
+    /// Returns the greater of self and rhs, or the other value if one of them is NaN
+    #[inline]
+    fn max_nan_ignoring(self, rhs: Self) -> Self {
+        self.max(rhs)
+    }
+
+    /// Replaces any NaN payload with the canonical quiet NAN
+    #[inline]
+    fn canonicalize_nan(self) -> Self {
+        if self.is_nan() { Self::NAN } else { self }
+    }
+
+    /// Converts to i32 rounding towards negative infinity
+    /// Out of range values saturate to i32::MIN/i32::MAX and NaN becomes 0, same as `as`
+    #[inline]
+    fn fast_floor_i32(self) -> i32 {
+        // truncate then correct negative non-integers, avoids the libm floor call
+        let t = self.as_i32();
+        t.saturating_sub((Self::from_i32(t) > self) as i32)
+    }
+
+    /// Converts to i32 rounding towards positive infinity
+    /// Out of range values saturate to i32::MIN/i32::MAX and NaN becomes 0, same as `as`
+    #[inline]
+    fn fast_ceil_i32(self) -> i32 {
+        let t = self.as_i32();
+        t.saturating_add((Self::from_i32(t) < self) as i32)
+    }
+
+    /// Converts to i32 rounding to the nearest integer, with ties going to the even integer
+    /// Out of range values saturate to i32::MIN/i32::MAX and NaN becomes 0, same as `as`
+    #[inline]
+    fn round_half_even_i32(self) -> i32 {
+        self.round_ties_even().as_i32()
+    }
+
+    /// Divides self by den, returning fallback if the quotient is not finite
+    /// This covers division by zero as well as NaN/inf operands
+    #[inline]
+    fn safe_div(self, den: Self, fallback: Self) -> Self {
+        let q = self / den;
+        if q.is_finite() { q } else { fallback }
+    }
+
+    /// Returns a if cond is true, otherwise b
+    #[inline]
+    fn select(cond: bool, a: Self, b: Self) -> Self {
+        if cond { a } else { b }
+    }
+}
+
+
+macro_rules! impl_scalar {
+    ($t:ident, $signed_bits:ty, $rsqrt:path, $default_eps:literal) => {
+        impl Scalar for $t {
+            const ZERO: $t = 0.0;
+            const ONE: $t = 1.0;
+            const TWO: $t = 2.0;
+            const THREE: $t = 3.0;
+            const HALF: $t = 0.5;
+            const PI: $t = std::$t::consts::PI;
+            const TAU: $t = std::$t::consts::TAU;
+            const EPSILON: $t = $t::EPSILON;
+            const DEFAULT_EPS: $t = $default_eps;
+            const NAN: $t = $t::NAN;
+            const INFINITY: $t = $t::INFINITY;
+            const SMOOTH_DAMP_MIN_TIME: $t = 1e-4;
+
+            #[inline]
+            fn smooth_damp_factors(smooth_time: $t, dt: $t) -> ($t, $t) {
+                let omega = 2.0 / smooth_time.max(Self::SMOOTH_DAMP_MIN_TIME);
+                let x = omega * dt;
+                // A cheap approximation of e^-x that's accurate for the small x seen per frame
+                (omega, 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x))
+            }
+
+            #[inline]
+            fn sqrt(self) -> $t {
+                $t::sqrt(self)
+            }
+
+            /// Returns 1 / sqrt(self)
+            #[inline]
+            fn rsqrt(self) -> $t {
+                $rsqrt(self)
+            }
+
+            #[inline]
+            fn abs(self) -> $t {
+                $t::abs(self)
+            }
+
+            #[inline]
+            fn min(self, rhs: $t) -> $t {
+                $t::min(self, rhs)
+            }
+
+            #[inline]
+            fn max(self, rhs: $t) -> $t {
+                $t::max(self, rhs)
+            }
+
+            #[inline]
+            fn clamp(self, min: $t, max: $t) -> $t {
+                $t::clamp(self, min, max)
+            }
+
+            #[inline]
+            fn recip(self) -> $t {
+                $t::recip(self)
+            }
+
+            #[inline]
+            fn exp(self) -> $t {
+                $t::exp(self)
+            }
+
+            #[inline]
+            fn ln(self) -> $t {
+                $t::ln(self)
+            }
+
+            #[inline]
+            fn mul_add(self, a: $t, b: $t) -> $t {
+                $t::mul_add(self, a, b)
+            }
+
+            #[inline]
+            fn powf(self, n: $t) -> $t {
+                $t::powf(self, n)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> $t {
+                $t::powi(self, n)
+            }
+
+            #[inline]
+            fn sin(self) -> $t {
+                $t::sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> $t {
+                $t::cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> $t {
+                $t::tan(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> ($t, $t) {
+                $t::sin_cos(self)
+            }
+
+            #[inline]
+            fn acos(self) -> $t {
+                $t::acos(self)
+            }
+
+            #[inline]
+            fn asin(self) -> $t {
+                $t::asin(self)
+            }
+
+            #[inline]
+            fn atan2(self, rhs: $t) -> $t {
+                $t::atan2(self, rhs)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: $t) -> $t {
+                $t::rem_euclid(self, rhs)
+            }
+
+            #[inline]
+            fn div_euclid(self, rhs: $t) -> $t {
+                $t::div_euclid(self, rhs)
+            }
+
+            #[inline]
+            fn round_ties_even(self) -> $t {
+                $t::round_ties_even(self)
+            }
+
+            /// Converts an angle in degrees to radians
+            #[inline]
+            fn to_radians(self) -> $t {
+                $t::to_radians(self)
+            }
+
+            /// Converts an angle in radians to degrees
+            #[inline]
+            fn to_degrees(self) -> $t {
+                $t::to_degrees(self)
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            #[inline]
+            fn is_sign_negative(self) -> bool {
+                $t::is_sign_negative(self)
+            }
+
+            #[inline]
+            fn total_cmp(&self, rhs: &$t) -> Ordering {
+                $t::total_cmp(self, rhs)
+            }
+
+            #[inline]
+            fn as_i32(self) -> i32 {
+                self as i32
+            }
+
+            #[inline]
+            fn from_i32(v: i32) -> $t {
+                v as $t
+            }
+
+            #[inline]
+            fn to_signed_bits(self) -> i64 {
+                self.to_bits() as $signed_bits as i64
+            }
+        }
+    };
+}
+
+impl_scalar!(f32, i32, rsqrt_f32, 1e-5);
+impl_scalar!(f64, i64, rsqrt_f64, 1e-10);
+
+
+// Declares a trait with the helper methods of Scalar, implemented for the one type $t by forwarding to Scalar
+// Having a single impl lets calls on unsuffixed literals like 0.5.saturate() infer $t,
+// with Scalar implemented for both f32 and f64 they would fall back to f64
+macro_rules! float_ext {
+    ($(#[$attr:meta])* $name:ident for $t:ty) => {
+        float_ext!(@trait $(#[$attr])* $name for $t {
+            fn rsqrt(self) -> Self;
+            fn sincos(self) -> (Self, Self);
+            fn saturate(self) -> Self;
+            fn step(self, edge: Self) -> Self;
+            fn smoothstep(self, edge0: Self, edge1: Self) -> Self;
+            fn to_radians(self) -> Self;
+            fn to_degrees(self) -> Self;
+            fn acos_clamped(self) -> Self;
+            fn asin_clamped(self) -> Self;
+            fn wrap_angle(self) -> Self;
+            fn delta_angle(self, to: Self) -> Self;
+            fn lerp_angle(self, to: Self, t: Self) -> Self;
+            fn inverse_lerp(self, a: Self, b: Self) -> Self;
+            fn remap(self, in_min: Self, in_max: Self, out_min: Self, out_max: Self) -> Self;
+            fn abs_diff(self, rhs: Self) -> Self;
+            fn approx_eq(self, rhs: Self, eps: Self) -> bool;
+            fn relative_eq(self, rhs: Self, eps: Self, max_relative: Self) -> bool;
+            fn approx_eq_ulps(self, rhs: Self, ulps: u32) -> bool;
+            fn exp_decay(self, target: Self, rate: Self, dt: Self) -> Self;
+            fn smooth_damp(self, target: Self, velocity: &mut Self, smooth_time: Self, dt: Self) -> Self;
+            fn total_min(self, rhs: Self) -> Self;
+            fn total_max(self, rhs: Self) -> Self;
+            fn min_nan_propagating(self, rhs: Self) -> Self;
+            fn max_nan_propagating(self, rhs: Self) -> Self;
+            fn min_nan_ignoring(self, rhs: Self) -> Self;
+            fn max_nan_ignoring(self, rhs: Self) -> Self;
+            fn canonicalize_nan(self) -> Self;
+            fn fast_floor_i32(self) -> i32;
+            fn fast_ceil_i32(self) -> i32;
+            fn round_half_even_i32(self) -> i32;
+            fn safe_div(self, den: Self, fallback: Self) -> Self;
+        });
+    };
+    (@trait $(#[$attr:meta])* $name:ident for $t:ty {
+        $(fn $method:ident(self $(, $arg:ident: $arg_ty:ty)*) -> $ret:ty;)*
+    }) => {
+        $(#[$attr])*
+        pub trait $name: Sized {
+            $(
+                #[doc = concat!("Same as Scalar::", stringify!($method))]
+                fn $method(self $(, $arg: $arg_ty)*) -> $ret;
+            )*
+
+            /// Same as Scalar::select
+            fn select(cond: bool, a: Self, b: Self) -> Self;
+        }
+
+        impl $name for $t {
+            $(
+                #[inline]
+                fn $method(self $(, $arg: $arg_ty)*) -> $ret {
+                    <$t as Scalar>::$method(self $(, $arg)*)
+                }
+            )*
+
+            #[inline]
+            fn select(cond: bool, a: $t, b: $t) -> $t {
+                <$t as Scalar>::select(cond, a, b)
+            }
+        }
+    };
+}
+
+float_ext!(
+    /// The scalar helpers of Scalar as methods of f32 alone, so they also work on unsuffixed literals
+    /// Generic code should use Scalar instead, with both in scope calls on an f32 are ambiguous
+    F32Ext for f32
+);
+float_ext!(
+    /// The scalar helpers of Scalar as methods of f64 alone, the f64 counterpart of F32Ext
+    F64Ext for f64
+);
+
+
+/// Returns 1 / sqrt(x)
+/// With the simd feature, uses the hardware reciprocal square root estimate plus refinement when the target has one,
+/// which is accurate to roughly 22 bits rather than correctly rounded
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))]
+#[inline]
+fn rsqrt_f32(x: f32) -> f32 {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::{_mm_cvtss_f32, _mm_rsqrt_ss, _mm_set_ss};
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::{_mm_cvtss_f32, _mm_rsqrt_ss, _mm_set_ss};
+
+    // rsqrtss treats subnormals as 0 and returns inf, which the refinement below turns into -inf
+    if x < f32::MIN_POSITIVE {
+        return 1.0 / x.sqrt();
+    }
+    // rsqrtss is only accurate to ~12 bits, one newton-raphson step brings it to ~22 bits
+    // sse is guaranteed by the cfg on this fn
+    let est = unsafe { _mm_cvtss_f32(_mm_rsqrt_ss(_mm_set_ss(x))) };
+    let refined = est * (1.5 - 0.5 * x * est * est);
+    // inf turns the refinement into NaN, the raw estimate of 0 is already exact for it
+    if refined.is_nan() { est } else { refined }
+}
+
+#[cfg(all(feature = "simd", target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn rsqrt_f32(x: f32) -> f32 {
+    use std::arch::aarch64::{vrsqrtes_f32, vrsqrtss_f32};
+
+    // frsqrte flushes subnormals to 0 like rsqrtss, so they take the exact path too
+    if x < f32::MIN_POSITIVE {
+        return 1.0 / x.sqrt();
+    }
+    // frsqrte is only accurate to ~8 bits, two newton-raphson steps bring it to ~23 bits
+    // frsqrts handles the 0 * inf case itself so no fix-up is needed
+    // neon is guaranteed by the cfg on this fn
+    unsafe {
+        let mut est = vrsqrtes_f32(x);
+        est *= vrsqrtss_f32(x * est, est);
+        est *= vrsqrtss_f32(x * est, est);
+        est
+    }
+}
+
+#[cfg(not(all(feature = "simd", any(
+    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+    all(target_arch = "aarch64", target_feature = "neon"),
+))))]
+#[inline]
+fn rsqrt_f32(x: f32) -> f32 {
+    1.0 / x.sqrt()
+}
+
+#[inline]
+fn rsqrt_f64(x: f64) -> f64 {
+    1.0 / x.sqrt()
+}
@@ -0,0 +1,78 @@
+// Per-vertex tangent frames for normal mapping
+// Follows the MikkTSpace conventions: tangents point along +u, w holds the handedness sign,
+// and the bitangent is reconstructed as w * cross(normal, tangent)
+// Not bit-exact with the reference MikkTSpace implementation, which also splits vertices
+
+use crate::{
+    MathError,
+    Vec2,
+    Vec3,
+    Vec4,
+    VecExt,
+};
+
+
+/// Computes a tangent for every vertex of an indexed triangle list
+/// Each tangent is orthogonalized against its normal and normalized, w is +1 or -1
+/// Triangles with degenerate uvs are skipped, vertices left without a tangent get an arbitrary one
+/// Fails if normals or uvs do not have one entry per position, or indices is not a multiple of 3
+/// Requires: every index should be in bounds of positions
+pub fn generate_tangents(positions: &[Vec3], normals: &[Vec3], uvs: &[Vec2], indices: &[u32]) -> Result<Vec<Vec4>, MathError> {
+    for len in [normals.len(), uvs.len()] {
+        if len != positions.len() {
+            return Err(MathError::SliceLength { expected: positions.len(), found: len });
+        }
+    }
+    if !indices.len().is_multiple_of(3) {
+        return Err(MathError::SliceLength { expected: indices.len() / 3 * 3, found: indices.len() });
+    }
+
+    // accumulate the unnormalized uv gradients of every triangle touching each vertex,
+    // larger triangles get proportionally more weight
+    let mut tangents = vec![Vec3::ZERO; positions.len()];
+    let mut bitangents = vec![Vec3::ZERO; positions.len()];
+    for tri in indices.chunks_exact(3) {
+        let [i0, i1, i2] = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+        let e1 = positions[i1] - positions[i0];
+        let e2 = positions[i2] - positions[i0];
+        let d1 = uvs[i1] - uvs[i0];
+        let d2 = uvs[i2] - uvs[i0];
+        let det = d1.x * d2.y - d2.x * d1.y;
+        if det == 0.0 || !det.is_finite() {
+            continue;
+        }
+        // the sign of det carries the uv winding, area weighting cancels the 1 / det magnitude
+        let sign = det.signum();
+        let t = (e1 * d2.y - e2 * d1.y) * sign;
+        let b = (e2 * d1.x - e1 * d2.x) * sign;
+        for i in [i0, i1, i2] {
+            tangents[i] += t;
+            bitangents[i] += b;
+        }
+    }
+
+    let frames = normals
+        .iter()
+        .zip(tangents.iter().zip(bitangents.iter()))
+        .map(|(&n, (&t, &b))| {
+            // gram-schmidt against the normal
+            let t = (t - n * n.dot(t)).try_normalize().unwrap_or_else(|_| arbitrary_tangent(n));
+            let w = if n.cross(t).dot(b) < 0.0 { -1.0 } else { 1.0 };
+            Vec4::new(t.x, t.y, t.z, w)
+        })
+        .collect();
+    Ok(frames)
+}
+
+/// Reconstructs the bitangent from a normal and a tangent produced by generate_tangents
+#[inline]
+pub fn bitangent(normal: Vec3, tangent: Vec4) -> Vec3 {
+    normal.cross(Vec3::new(tangent.x, tangent.y, tangent.z)) * tangent.w
+}
+
+/// Some unit vector perpendicular to n, used when a vertex has no usable uv gradient
+#[inline]
+fn arbitrary_tangent(n: Vec3) -> Vec3 {
+    let helper = if n.x.abs() < 0.9 { Vec3::X } else { Vec3::Y };
+    helper.cross(n).try_normalize().unwrap_or(Vec3::X)
+}
@@ -0,0 +1,182 @@
+// Approximate equality assertions for tests, enabled by the testing feature
+// The macros report every component that differs by more than the epsilon, not just the first
+
+use crate::{
+    Dir2,
+    Dir3,
+    Mat2,
+    Mat3,
+    Mat4,
+    Affine2,
+    Affine3,
+    Quat,
+    Scalar,
+    Vec2,
+    Vec3,
+    Vec3A,
+    Vec4,
+};
+use std::fmt::{
+    Debug,
+    Write,
+};
+
+
+/// The epsilon used when an assertion macro is not given one, the crate-wide Scalar::DEFAULT_EPS
+pub const DEFAULT_EPS: f32 = <f32 as Scalar>::DEFAULT_EPS;
+
+/// Types the assertion macros can compare, viewed as a flat list of f32 components
+pub trait ApproxEq: Debug {
+    fn components(&self) -> Vec<f32>;
+}
+
+impl ApproxEq for f32 {
+    fn components(&self) -> Vec<f32> {
+        vec![*self]
+    }
+}
+
+impl ApproxEq for [f32] {
+    fn components(&self) -> Vec<f32> {
+        self.to_vec()
+    }
+}
+
+macro_rules! impl_approx_eq_via_array {
+    ($($t:ty),+) => {
+        $(
+            impl ApproxEq for $t {
+                fn components(&self) -> Vec<f32> {
+                    <[f32; _]>::from(*self).to_vec()
+                }
+            }
+        )+
+    };
+}
+impl_approx_eq_via_array!(Vec2, Vec3, Vec3A, Vec4, Quat);
+
+impl ApproxEq for Dir2 {
+    fn components(&self) -> Vec<f32> {
+        self.as_vec2().components()
+    }
+}
+
+impl ApproxEq for Dir3 {
+    fn components(&self) -> Vec<f32> {
+        self.as_vec3().components()
+    }
+}
+
+impl ApproxEq for Mat2 {
+    fn components(&self) -> Vec<f32> {
+        self.to_cols_array().to_vec()
+    }
+}
+
+impl ApproxEq for Mat3 {
+    fn components(&self) -> Vec<f32> {
+        self.to_cols_array().to_vec()
+    }
+}
+
+impl ApproxEq for Mat4 {
+    fn components(&self) -> Vec<f32> {
+        <[[f32; 4]; 4]>::from(*self).concat()
+    }
+}
+
+impl ApproxEq for Affine2 {
+    fn components(&self) -> Vec<f32> {
+        let mut c = self.matrix2.to_cols_array().to_vec();
+        c.extend(self.translation.components());
+        c
+    }
+}
+
+impl ApproxEq for Affine3 {
+    fn components(&self) -> Vec<f32> {
+        self.to_mat4().components()
+    }
+}
+
+
+/// Describes every component of left and right that differs by more than eps,
+/// or returns None if they are approximately equal
+/// NaN components never compare equal, and a different number of components is itself a difference
+pub fn approx_eq_diff<A: ApproxEq + ?Sized>(left: &A, right: &A, eps: f32) -> Option<String> {
+    let (l, r) = (left.components(), right.components());
+    let mut diff = String::new();
+    if l.len() != r.len() {
+        let _ = writeln!(diff, "    length: {} vs {}", l.len(), r.len());
+    }
+    for (i, (a, b)) in l.iter().zip(&r).enumerate() {
+        let off = (a - b).abs();
+        if off > eps || off.is_nan() {
+            let _ = writeln!(diff, "    [{i}]: {a} vs {b} (off by {off})");
+        }
+    }
+    (!diff.is_empty()).then_some(diff)
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_approx_eq<A: ApproxEq + ?Sized>(left: &A, right: &A, eps: f32, left_expr: &str, right_expr: &str) {
+    if let Some(diff) = approx_eq_diff(left, right, eps) {
+        panic!(
+            "assertion `{left_expr} ≈ {right_expr}` failed (eps = {eps})\n  left: {left:?}\n right: {right:?}\ncomponents that differ:\n{diff}"
+        );
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_quat_approx_eq<A: ApproxEq + ?Sized>(left: &A, right: &A, eps: f32, left_expr: &str, right_expr: &str) {
+    // q and -q are the same rotation, so compare against whichever sign of right is closer
+    let l = left.components();
+    let r = right.components();
+    let neg_r: Vec<f32> = r.iter().map(|c| -c).collect();
+    let dot: f32 = l.iter().zip(&r).map(|(a, b)| a * b).sum();
+    let r = if dot < 0.0 { neg_r } else { r };
+    if let Some(diff) = approx_eq_diff(&l[..], &r[..], eps) {
+        panic!(
+            "assertion `{left_expr} ≈ ±{right_expr}` failed (eps = {eps})\n  left: {left:?}\n right: {right:?}\ncomponents that differ:\n{diff}"
+        );
+    }
+}
+
+
+/// Asserts two vectors (or scalars, or directions) are equal within an epsilon
+/// The epsilon defaults to testing::DEFAULT_EPS
+#[macro_export]
+macro_rules! assert_vec_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_vec_eq!($left, $right, $crate::testing::DEFAULT_EPS)
+    };
+    ($left:expr, $right:expr, $eps:expr $(,)?) => {
+        $crate::testing::assert_approx_eq(&$left, &$right, $eps, stringify!($left), stringify!($right))
+    };
+}
+
+/// Asserts two matrices are equal within an epsilon
+/// The epsilon defaults to testing::DEFAULT_EPS
+#[macro_export]
+macro_rules! assert_mat_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_mat_eq!($left, $right, $crate::testing::DEFAULT_EPS)
+    };
+    ($left:expr, $right:expr, $eps:expr $(,)?) => {
+        $crate::testing::assert_approx_eq(&$left, &$right, $eps, stringify!($left), stringify!($right))
+    };
+}
+
+/// Asserts two quaternions represent the same rotation within an epsilon, treating q and -q as equal
+/// Accepts a Quat or any 4 component value laid out as one, the epsilon defaults to testing::DEFAULT_EPS
+#[macro_export]
+macro_rules! assert_quat_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_quat_eq!($left, $right, $crate::testing::DEFAULT_EPS)
+    };
+    ($left:expr, $right:expr, $eps:expr $(,)?) => {
+        $crate::testing::assert_quat_approx_eq(&$left, &$right, $eps, stringify!($left), stringify!($right))
+    };
+}
@@ -0,0 +1,2 @@
+pub mod uvec3;
+pub use uvec3::*;
@@ -0,0 +1,100 @@
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+};
+
+
+/// An unsigned integer vector in 3-space
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct UVec3 {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+impl UVec3 {
+    /// The default UVec3 with all 0's
+    pub const ZERO: UVec3 = UVec3::splat(0);
+
+    /// The positive x-axis basis vector
+    pub const X: UVec3 = UVec3::new(1, 0, 0);
+
+    /// The positive y-axis basis vector
+    pub const Y: UVec3 = UVec3::new(0, 1, 0);
+
+    /// The positive z-axis basis vector
+    pub const Z: UVec3 = UVec3::new(0, 0, 1);
+
+
+    /// Standard constructor for <x y z>
+    #[inline]
+    pub const fn new(x: u32, y: u32, z: u32) -> UVec3 {
+        UVec3 { x, y, z }
+    }
+
+    #[inline]
+    pub const fn splat(v: u32) -> UVec3 {
+        UVec3::new(v, v, v)
+    }
+
+    /// Returns a copy of self with the x component replaced
+    #[inline]
+    pub const fn with_x(mut self, x: u32) -> UVec3 {
+        self.x = x;
+        self
+    }
+
+    /// Returns a copy of self with the y component replaced
+    #[inline]
+    pub const fn with_y(mut self, y: u32) -> UVec3 {
+        self.y = y;
+        self
+    }
+
+    /// Returns a copy of self with the z component replaced
+    #[inline]
+    pub const fn with_z(mut self, z: u32) -> UVec3 {
+        self.z = z;
+        self
+    }
+}
+
+
+impl Debug for UVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("UVec3")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .finish()
+    }
+}
+impl Display for UVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .entry(&self.z)
+            .finish()
+    }
+}
+
+
+impl From<[u32; 3]> for UVec3 {
+    #[inline]
+    fn from(arr: [u32; 3]) -> UVec3 {
+        UVec3::new(arr[0], arr[1], arr[2])
+    }
+}
+
+impl From<UVec3> for [u32; 3] {
+    #[inline]
+    fn from(v: UVec3) -> [u32; 3] {
+        [v.x, v.y, v.z]
+    }
+}
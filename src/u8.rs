@@ -0,0 +1,2 @@
+pub mod u8vec4;
+pub use u8vec4::*;
@@ -0,0 +1,142 @@
+use crate::{
+    Vec4,
+};
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+};
+
+
+/// A vector of 4 bytes, such as an RGBA8 color
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct U8Vec4 {
+    pub x: u8,
+    pub y: u8,
+    pub z: u8,
+    pub w: u8,
+}
+
+impl U8Vec4 {
+    /// The default U8Vec4 with all 0's
+    pub const ZERO: U8Vec4 = U8Vec4::splat(0);
+
+    /// The U8Vec4 with all 255's
+    pub const MAX: U8Vec4 = U8Vec4::splat(u8::MAX);
+
+
+    /// Standard constructor for <x y z w>
+    #[inline]
+    pub const fn new(x: u8, y: u8, z: u8, w: u8) -> U8Vec4 {
+        U8Vec4 { x, y, z, w }
+    }
+
+    #[inline]
+    pub const fn splat(v: u8) -> U8Vec4 {
+        U8Vec4::new(v, v, v, v)
+    }
+
+    /// Returns a copy of self with the x component replaced
+    #[inline]
+    pub const fn with_x(mut self, x: u8) -> U8Vec4 {
+        self.x = x;
+        self
+    }
+
+    /// Returns a copy of self with the y component replaced
+    #[inline]
+    pub const fn with_y(mut self, y: u8) -> U8Vec4 {
+        self.y = y;
+        self
+    }
+
+    /// Returns a copy of self with the z component replaced
+    #[inline]
+    pub const fn with_z(mut self, z: u8) -> U8Vec4 {
+        self.z = z;
+        self
+    }
+
+    /// Returns a copy of self with the w component replaced
+    #[inline]
+    pub const fn with_w(mut self, w: u8) -> U8Vec4 {
+        self.w = w;
+        self
+    }
+
+
+    /// Packs into a u32 with x in the lowest byte and w in the highest
+    /// Matches the in-memory byte order of RGBA8 pixels on little-endian targets
+    #[inline]
+    pub const fn pack_u32(self) -> u32 {
+        u32::from_le_bytes([self.x, self.y, self.z, self.w])
+    }
+
+    /// Unpacks a u32 packed by pack_u32
+    #[inline]
+    pub const fn unpack_u32(packed: u32) -> U8Vec4 {
+        let [x, y, z, w] = packed.to_le_bytes();
+        U8Vec4::new(x, y, z, w)
+    }
+
+    /// Converts from a normalized Vec4, mapping [0, 1] onto [0, 255]
+    /// Components are clamped to [0, 1] and rounded to nearest, NaN becomes 0
+    #[inline]
+    pub fn from_unorm(v: Vec4) -> U8Vec4 {
+        let b = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        U8Vec4::new(b(v.x), b(v.y), b(v.z), b(v.w))
+    }
+
+    /// Converts to a normalized Vec4, mapping [0, 255] onto [0, 1]
+    #[inline]
+    pub fn to_unorm(self) -> Vec4 {
+        self.as_vec4() * (1.0 / 255.0)
+    }
+
+    /// Converts each component to f32 without normalizing
+    #[inline]
+    pub fn as_vec4(self) -> Vec4 {
+        Vec4::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+}
+
+
+impl Debug for U8Vec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("U8Vec4")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .field(&self.w)
+            .finish()
+    }
+}
+impl Display for U8Vec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .entry(&self.z)
+            .entry(&self.w)
+            .finish()
+    }
+}
+
+
+impl From<[u8; 4]> for U8Vec4 {
+    #[inline]
+    fn from(arr: [u8; 4]) -> U8Vec4 {
+        U8Vec4::new(arr[0], arr[1], arr[2], arr[3])
+    }
+}
+
+impl From<U8Vec4> for [u8; 4] {
+    #[inline]
+    fn from(v: U8Vec4) -> [u8; 4] {
+        [v.x, v.y, v.z, v.w]
+    }
+}
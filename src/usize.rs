@@ -0,0 +1,5 @@
+pub mod usizevec2;
+pub use usizevec2::*;
+
+pub mod usizevec3;
+pub use usizevec3::*;
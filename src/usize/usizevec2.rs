@@ -0,0 +1,122 @@
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+};
+
+
+/// An index vector in 2-space, for addressing images and grids
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct USizeVec2 {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl USizeVec2 {
+    /// The default USizeVec2 with all 0's
+    pub const ZERO: USizeVec2 = USizeVec2::splat(0);
+
+
+    /// Standard constructor for <x y>
+    #[inline]
+    pub const fn new(x: usize, y: usize) -> USizeVec2 {
+        USizeVec2 { x, y }
+    }
+
+    #[inline]
+    pub const fn splat(v: usize) -> USizeVec2 {
+        USizeVec2::new(v, v)
+    }
+
+    /// Returns a copy of self with the x component replaced
+    #[inline]
+    pub const fn with_x(mut self, x: usize) -> USizeVec2 {
+        self.x = x;
+        self
+    }
+
+    /// Returns a copy of self with the y component replaced
+    #[inline]
+    pub const fn with_y(mut self, y: usize) -> USizeVec2 {
+        self.y = y;
+        self
+    }
+
+
+    /// The product of the components, the number of cells when self is an extent
+    #[inline]
+    pub const fn element_product(self) -> usize {
+        self.x * self.y
+    }
+
+    /// Checks if self is a valid index into extent
+    #[inline]
+    pub const fn is_inside(self, extent: USizeVec2) -> bool {
+        self.x < extent.x && self.y < extent.y
+    }
+
+    /// The linear index of self in a row-major buffer of size extent, where x, then y varies fastest
+    /// Requires: self should be inside extent
+    #[inline]
+    pub const fn to_index(self, extent: USizeVec2) -> usize {
+        self.x + self.y * extent.x
+    }
+
+    /// The inverse of to_index
+    /// Requires: index < extent.element_product()
+    #[inline]
+    pub const fn from_index(index: usize, extent: USizeVec2) -> USizeVec2 {
+        USizeVec2::new(index % extent.x, index / extent.x)
+    }
+
+    /// The linear index of self in a column-major buffer of size extent, where y, then x varies fastest
+    /// Requires: self should be inside extent
+    #[inline]
+    pub const fn to_index_col_major(self, extent: USizeVec2) -> usize {
+        self.y + self.x * extent.y
+    }
+
+    /// The inverse of to_index_col_major
+    /// Requires: index < extent.element_product()
+    #[inline]
+    pub const fn from_index_col_major(index: usize, extent: USizeVec2) -> USizeVec2 {
+        USizeVec2::new(index / extent.y, index % extent.y)
+    }
+}
+
+
+impl Debug for USizeVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("USizeVec2")
+            .field(&self.x)
+            .field(&self.y)
+            .finish()
+    }
+}
+impl Display for USizeVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .finish()
+    }
+}
+
+
+impl From<[usize; 2]> for USizeVec2 {
+    #[inline]
+    fn from(arr: [usize; 2]) -> USizeVec2 {
+        USizeVec2::new(arr[0], arr[1])
+    }
+}
+
+impl From<USizeVec2> for [usize; 2] {
+    #[inline]
+    fn from(v: USizeVec2) -> [usize; 2] {
+        [v.x, v.y]
+    }
+}
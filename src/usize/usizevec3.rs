@@ -0,0 +1,134 @@
+use std::{
+    fmt::{
+        self,
+        Debug,
+        Display
+    },
+};
+
+
+/// An index vector in 3-space, for addressing volumes and grids
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct USizeVec3 {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl USizeVec3 {
+    /// The default USizeVec3 with all 0's
+    pub const ZERO: USizeVec3 = USizeVec3::splat(0);
+
+
+    /// Standard constructor for <x y z>
+    #[inline]
+    pub const fn new(x: usize, y: usize, z: usize) -> USizeVec3 {
+        USizeVec3 { x, y, z }
+    }
+
+    #[inline]
+    pub const fn splat(v: usize) -> USizeVec3 {
+        USizeVec3::new(v, v, v)
+    }
+
+    /// Returns a copy of self with the x component replaced
+    #[inline]
+    pub const fn with_x(mut self, x: usize) -> USizeVec3 {
+        self.x = x;
+        self
+    }
+
+    /// Returns a copy of self with the y component replaced
+    #[inline]
+    pub const fn with_y(mut self, y: usize) -> USizeVec3 {
+        self.y = y;
+        self
+    }
+
+    /// Returns a copy of self with the z component replaced
+    #[inline]
+    pub const fn with_z(mut self, z: usize) -> USizeVec3 {
+        self.z = z;
+        self
+    }
+
+
+    /// The product of the components, the number of cells when self is an extent
+    #[inline]
+    pub const fn element_product(self) -> usize {
+        self.x * self.y * self.z
+    }
+
+    /// Checks if self is a valid index into extent
+    #[inline]
+    pub const fn is_inside(self, extent: USizeVec3) -> bool {
+        self.x < extent.x && self.y < extent.y && self.z < extent.z
+    }
+
+    /// The linear index of self in a row-major buffer of size extent, where x, then y, then z varies fastest
+    /// Requires: self should be inside extent
+    #[inline]
+    pub const fn to_index(self, extent: USizeVec3) -> usize {
+        self.x + extent.x * (self.y + extent.y * self.z)
+    }
+
+    /// The inverse of to_index
+    /// Requires: index < extent.element_product()
+    #[inline]
+    pub const fn from_index(index: usize, extent: USizeVec3) -> USizeVec3 {
+        let plane = extent.x * extent.y;
+        USizeVec3::new(index % extent.x, index % plane / extent.x, index / plane)
+    }
+
+    /// The linear index of self in a column-major buffer of size extent, where z, then y, then x varies fastest
+    /// Requires: self should be inside extent
+    #[inline]
+    pub const fn to_index_col_major(self, extent: USizeVec3) -> usize {
+        self.z + extent.z * (self.y + extent.y * self.x)
+    }
+
+    /// The inverse of to_index_col_major
+    /// Requires: index < extent.element_product()
+    #[inline]
+    pub const fn from_index_col_major(index: usize, extent: USizeVec3) -> USizeVec3 {
+        let plane = extent.z * extent.y;
+        USizeVec3::new(index / plane, index % plane / extent.z, index % extent.z)
+    }
+}
+
+
+impl Debug for USizeVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("USizeVec3")
+            .field(&self.x)
+            .field(&self.y)
+            .field(&self.z)
+            .finish()
+    }
+}
+impl Display for USizeVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entry(&self.x)
+            .entry(&self.y)
+            .entry(&self.z)
+            .finish()
+    }
+}
+
+
+impl From<[usize; 3]> for USizeVec3 {
+    #[inline]
+    fn from(arr: [usize; 3]) -> USizeVec3 {
+        USizeVec3::new(arr[0], arr[1], arr[2])
+    }
+}
+
+impl From<USizeVec3> for [usize; 3] {
+    #[inline]
+    fn from(v: USizeVec3) -> [usize; 3] {
+        [v.x, v.y, v.z]
+    }
+}
@@ -0,0 +1,29 @@
+// Finiteness checks for the `validate` and `nan_guard` features
+// Both macros expand to nothing when their feature is disabled
+
+
+/// Debug-asserts that every value is finite when the `validate` feature is enabled
+/// Each value only needs an is_finite method, so this works for scalars and vectors alike
+/// Expands to nothing without the feature
+macro_rules! validate_finite {
+    ($op:literal, $($v:expr),+ $(,)?) => {
+        #[cfg(feature = "validate")]
+        {
+            $(
+                debug_assert!($v.is_finite(), concat!("gdmx: ", $op, " produced or received a non-finite value"));
+            )+
+        }
+    };
+}
+pub(crate) use validate_finite;
+
+
+/// Checks the result of an operation against its operands when the nan_guard feature is enabled
+/// Expands to nothing without the feature
+macro_rules! nan_guard {
+    ($op:literal, $result:expr, $($operand:expr),+ $(,)?) => {
+        #[cfg(feature = "nan_guard")]
+        $crate::nan_guard::check($op, &$result, &[$(&$operand),+]);
+    };
+}
+pub(crate) use nan_guard;
@@ -0,0 +1,287 @@
+use crate::{
+    Scalar,
+    Vec2,
+    Vec3,
+    Vec4,
+    VecExt,
+};
+use std::{
+    array,
+    iter::{
+        Sum,
+        Product,
+    },
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+        Mul,
+        MulAssign,
+        Div,
+        DivAssign,
+        Neg,
+        Index,
+        IndexMut,
+    },
+};
+
+
+/// A vector of any length N backed by [T; N]
+/// Gets the whole VecExt API, so Vector<f32, 6> works like a Vec6
+/// Prefer the named Vec2/3/4 for everyday math, they convert to and from this for free
+/// N must be at least 1, the constructors fail to compile for Vector<T, 0>
+#[derive(Clone, Copy, PartialEq)]
+#[repr(transparent)]
+pub struct Vector<T: Scalar, const N: usize>(pub [T; N]);
+
+impl<T: Scalar, const N: usize> VecExt<N, T> for Vector<T, N> {}
+
+impl<T: Scalar, const N: usize> Vector<T, N> {
+    /// The vector with all 0's
+    pub const ZERO: Vector<T, N> = {
+        assert!(N > 0, "a Vector needs at least one component");
+        Vector([T::ZERO; N])
+    };
+
+
+    #[inline]
+    pub const fn new(components: [T; N]) -> Vector<T, N> {
+        const { assert!(N > 0, "a Vector needs at least one component") };
+        Vector(components)
+    }
+
+    #[inline]
+    pub const fn splat(v: T) -> Vector<T, N> {
+        const { assert!(N > 0, "a Vector needs at least one component") };
+        Vector([v; N])
+    }
+
+    /// The basis vector along axis i
+    /// Panics if i >= N
+    #[inline]
+    pub fn unit(i: usize) -> Vector<T, N> {
+        let mut v = Vector::ZERO;
+        v[i] = T::ONE;
+        v
+    }
+
+    /// Builds a vector by calling f with each index
+    #[inline]
+    pub fn from_fn(f: impl FnMut(usize) -> T) -> Vector<T, N> {
+        const { assert!(N > 0, "a Vector needs at least one component") };
+        Vector(array::from_fn(f))
+    }
+}
+
+impl<T: Scalar, const N: usize> Default for Vector<T, N> {
+    #[inline]
+    fn default() -> Vector<T, N> {
+        Vector::ZERO
+    }
+}
+
+
+impl<T: Scalar, const N: usize> Debug for Vector<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Vector").field(&self.0).finish()
+    }
+}
+impl<T: Scalar, const N: usize> Display for Vector<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0).finish()
+    }
+}
+
+
+// Vector + Vector
+impl<T: Scalar, const N: usize> Add for Vector<T, N> {
+    type Output = Vector<T, N>;
+    #[inline]
+    fn add(self, rhs: Vector<T, N>) -> Self::Output {
+        Vector::from_fn(|i| self.0[i] + rhs.0[i])
+    }
+}
+impl<T: Scalar, const N: usize> AddAssign for Vector<T, N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Vector<T, N>) {
+        *self = *self + rhs;
+    }
+}
+
+// Vector - Vector
+impl<T: Scalar, const N: usize> Sub for Vector<T, N> {
+    type Output = Vector<T, N>;
+    #[inline]
+    fn sub(self, rhs: Vector<T, N>) -> Self::Output {
+        Vector::from_fn(|i| self.0[i] - rhs.0[i])
+    }
+}
+impl<T: Scalar, const N: usize> SubAssign for Vector<T, N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Vector<T, N>) {
+        *self = *self - rhs;
+    }
+}
+
+// Vector * Vector
+impl<T: Scalar, const N: usize> Mul for Vector<T, N> {
+    type Output = Vector<T, N>;
+    #[inline]
+    fn mul(self, rhs: Vector<T, N>) -> Self::Output {
+        Vector::from_fn(|i| self.0[i] * rhs.0[i])
+    }
+}
+impl<T: Scalar, const N: usize> MulAssign for Vector<T, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Vector<T, N>) {
+        *self = *self * rhs;
+    }
+}
+
+// Vector * T
+impl<T: Scalar, const N: usize> Mul<T> for Vector<T, N> {
+    type Output = Vector<T, N>;
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Vector::from_fn(|i| self.0[i] * rhs)
+    }
+}
+impl<T: Scalar, const N: usize> MulAssign<T> for Vector<T, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+// Vector / Vector
+impl<T: Scalar, const N: usize> Div for Vector<T, N> {
+    type Output = Vector<T, N>;
+    #[inline]
+    fn div(self, rhs: Vector<T, N>) -> Self::Output {
+        Vector::from_fn(|i| self.0[i] / rhs.0[i])
+    }
+}
+impl<T: Scalar, const N: usize> DivAssign for Vector<T, N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Vector<T, N>) {
+        *self = *self / rhs;
+    }
+}
+
+// Vector / T
+impl<T: Scalar, const N: usize> Div<T> for Vector<T, N> {
+    type Output = Vector<T, N>;
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Vector::from_fn(|i| self.0[i] / rhs)
+    }
+}
+impl<T: Scalar, const N: usize> DivAssign<T> for Vector<T, N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+// -Vector
+impl<T: Scalar, const N: usize> Neg for Vector<T, N> {
+    type Output = Vector<T, N>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Vector::from_fn(|i| -self.0[i])
+    }
+}
+
+
+impl<T: Scalar, const N: usize> Index<usize> for Vector<T, N> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+impl<T: Scalar, const N: usize> IndexMut<usize> for Vector<T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+
+impl<T: Scalar, const N: usize> From<[T; N]> for Vector<T, N> {
+    #[inline]
+    fn from(arr: [T; N]) -> Vector<T, N> {
+        Vector::new(arr)
+    }
+}
+impl<T: Scalar, const N: usize> From<Vector<T, N>> for [T; N] {
+    #[inline]
+    fn from(v: Vector<T, N>) -> [T; N] {
+        v.0
+    }
+}
+
+impl<T: Scalar, const N: usize> AsRef<[T; N]> for Vector<T, N> {
+    #[inline]
+    fn as_ref(&self) -> &[T; N] {
+        &self.0
+    }
+}
+impl<T: Scalar, const N: usize> AsMut<[T; N]> for Vector<T, N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+}
+
+
+impl<T: Scalar, const N: usize> Sum for Vector<T, N> {
+    #[inline]
+    fn sum<I: Iterator<Item = Vector<T, N>>>(iter: I) -> Vector<T, N> {
+        iter.fold(Vector::ZERO, |acc, v| acc + v)
+    }
+}
+
+impl<T: Scalar, const N: usize> Product for Vector<T, N> {
+    #[inline]
+    fn product<I: Iterator<Item = Vector<T, N>>>(iter: I) -> Vector<T, N> {
+        iter.fold(Vector::splat(T::ONE), |acc, v| acc * v)
+    }
+}
+
+/// Takes the first N items as the components, ignoring any after them
+/// Panics if the iterator runs out first
+impl<T: Scalar, const N: usize> FromIterator<T> for Vector<T, N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Vector<T, N> {
+        let mut iter = iter.into_iter();
+        Vector::from_fn(|_| iter.next().expect("too few items to build a Vector"))
+    }
+}
+
+// Conversions to and from the named f32 vectors
+macro_rules! impl_named_conversions {
+    ($($vec:ident, $n:literal);+ $(;)?) => {
+        $(
+            impl From<$vec> for Vector<f32, $n> {
+                #[inline]
+                fn from(v: $vec) -> Vector<f32, $n> {
+                    Vector(v.into())
+                }
+            }
+            impl From<Vector<f32, $n>> for $vec {
+                #[inline]
+                fn from(v: Vector<f32, $n>) -> $vec {
+                    $vec::from(v.0)
+                }
+            }
+        )+
+    };
+}
+impl_named_conversions!(Vec2, 2; Vec3, 3; Vec4, 4);
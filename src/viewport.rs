@@ -0,0 +1,121 @@
+// Conversions between normalized device coordinates, screen pixels and world space
+// Screen space has its origin at the top left with y pointing down, like window and mouse coordinates
+
+use crate::{
+    Dir3,
+    Mat4,
+    Vec2,
+    Vec3,
+    Vec4,
+};
+
+
+/// The clip space conventions of a graphics API
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ClipConvention {
+    /// NDC y up, depth in [-1, 1]
+    #[default]
+    OpenGl,
+    /// NDC y down, depth in [0, 1]
+    Vulkan,
+    /// NDC y up, depth in [0, 1]
+    DirectX,
+}
+
+impl ClipConvention {
+    /// Whether NDC +y points up the screen
+    #[inline]
+    pub fn y_up(self) -> bool {
+        !matches!(self, ClipConvention::Vulkan)
+    }
+
+    /// The NDC depth of the near and far planes
+    #[inline]
+    pub fn ndc_depth_range(self) -> (f32, f32) {
+        match self {
+            ClipConvention::OpenGl => (-1.0, 1.0),
+            ClipConvention::Vulkan | ClipConvention::DirectX => (0.0, 1.0),
+        }
+    }
+}
+
+
+/// A rectangle of the screen that NDC maps onto, plus the depth range written to the depth buffer
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Viewport {
+    /// Top left corner in pixels
+    pub origin: Vec2,
+    /// Width and height in pixels
+    pub size: Vec2,
+    /// Window depth of the near and far planes, usually <0 1>
+    pub depth_range: Vec2,
+    pub convention: ClipConvention,
+}
+
+impl Viewport {
+    /// A viewport covering size pixels from the top left with the standard [0, 1] depth range
+    #[inline]
+    pub fn new(size: Vec2, convention: ClipConvention) -> Viewport {
+        Viewport { origin: Vec2::ZERO, size, depth_range: Vec2::new(0.0, 1.0), convention }
+    }
+
+    #[inline]
+    pub fn aspect_ratio(&self) -> f32 {
+        self.size.x / self.size.y
+    }
+
+    /// Maps NDC to screen pixels, z maps from the convention's NDC depth to depth_range
+    #[inline]
+    pub fn ndc_to_screen(&self, ndc: Vec3) -> Vec3 {
+        let y = if self.convention.y_up() { -ndc.y } else { ndc.y };
+        let (near, far) = self.convention.ndc_depth_range();
+        let t = (ndc.z - near) / (far - near);
+        Vec3::new(
+            self.origin.x + (ndc.x + 1.0) * 0.5 * self.size.x,
+            self.origin.y + (y + 1.0) * 0.5 * self.size.y,
+            self.depth_range.x + t * (self.depth_range.y - self.depth_range.x),
+        )
+    }
+
+    /// Maps screen pixels and window depth back to NDC
+    /// Requires: size components should be non-zero and depth_range should not be empty
+    #[inline]
+    pub fn screen_to_ndc(&self, screen: Vec3) -> Vec3 {
+        let x = (screen.x - self.origin.x) / self.size.x * 2.0 - 1.0;
+        let y = (screen.y - self.origin.y) / self.size.y * 2.0 - 1.0;
+        let t = (screen.z - self.depth_range.x) / (self.depth_range.y - self.depth_range.x);
+        let (near, far) = self.convention.ndc_depth_range();
+        Vec3::new(
+            x,
+            if self.convention.y_up() { -y } else { y },
+            near + t * (far - near),
+        )
+    }
+
+    /// Projects a world position to screen pixels and window depth
+    /// Returns None if the point is behind the camera
+    #[inline]
+    pub fn world_to_screen(&self, view_proj: Mat4, p: Vec3) -> Option<Vec3> {
+        let clip = view_proj * Vec4::new(p.x, p.y, p.z, 1.0);
+        if clip.w <= 0.0 {
+            return None;
+        }
+        let ndc = Vec3::new(clip.x, clip.y, clip.z) / clip.w;
+        Some(self.ndc_to_screen(ndc))
+    }
+
+    /// Returns the world space ray through a screen pixel as (point on the near plane, direction)
+    /// Takes the inverse of the view-projection matrix so it can be computed once per frame
+    /// Returns None if the matrix is degenerate
+    #[inline]
+    pub fn screen_to_ray(&self, inverse_view_proj: Mat4, pixel: Vec2) -> Option<(Vec3, Dir3)> {
+        let unproject = |depth: f32| {
+            let ndc = self.screen_to_ndc(Vec3::new(pixel.x, pixel.y, depth));
+            let world = inverse_view_proj * Vec4::new(ndc.x, ndc.y, ndc.z, 1.0);
+            Vec3::new(world.x, world.y, world.z) / world.w
+        };
+        let near = unproject(self.depth_range.x);
+        let far = unproject(self.depth_range.y);
+        Dir3::new(far - near).ok().map(|dir| (near, dir))
+    }
+}
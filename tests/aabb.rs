@@ -0,0 +1,70 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    geom::{
+        Aabb2,
+        Aabb3,
+    },
+    Affine2,
+    Affine3,
+    Dir3,
+    Mat3,
+    Vec2,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-5;
+
+#[test]
+fn aabb3_from_points_and_measures() {
+    let b = Aabb3::from_points(&[Vec3::new(1.0, -1.0, 2.0), Vec3::new(-1.0, 3.0, 0.0), Vec3::ZERO]).unwrap();
+    assert_eq!(b, Aabb3::new(Vec3::new(-1.0, -1.0, 0.0), Vec3::new(1.0, 3.0, 2.0)));
+    assert_eq!(b.center(), Vec3::new(0.0, 1.0, 1.0));
+    assert_eq!(b.size(), Vec3::new(2.0, 4.0, 2.0));
+    assert_eq!(b.volume(), 16.0);
+    assert_eq!(b.surface_area(), 2.0 * (8.0 + 8.0 + 4.0));
+    assert!(Aabb3::from_points(&[]).is_none());
+    assert!(Aabb3::EMPTY.is_empty() && !b.is_empty());
+    assert_eq!(Aabb3::EMPTY.expand_to_include(Vec3::ONE), Aabb3::new(Vec3::ONE, Vec3::ONE));
+    assert!(b.corners().iter().all(|c| b.contains_point(*c)));
+    assert_eq!(b.longest_axis(), gdmx::Axis::Y);
+}
+
+#[test]
+fn aabb3_overlap_and_distance() {
+    let a = Aabb3::from_center_half_extents(Vec3::ZERO, Vec3::ONE);
+    let touching = Aabb3::new(Vec3::new(1.0, -1.0, -1.0), Vec3::new(2.0, 1.0, 1.0));
+    let apart = Aabb3::from_center_size(Vec3::new(5.0, 0.0, 0.0), Vec3::ONE);
+    assert!(a.intersects(&touching) && !a.intersects(&apart));
+    assert_eq!(a.intersection(&apart), None);
+    assert_eq!(a.union(&apart).max, Vec3::new(5.5, 1.0, 1.0));
+    assert!(a.contains_aabb(&a.inflate(-0.5)) && !a.contains_aabb(&touching));
+    assert_eq!(a.clamp_point(Vec3::new(3.0, 0.5, -4.0)), Vec3::new(1.0, 0.5, -1.0));
+    assert_eq!(a.distance_to_point(Vec3::new(4.0, 0.0, 0.0)), 3.0);
+    assert_eq!(a.distance_2_to_point(Vec3::new(0.2, 0.3, 0.4)), 0.0);
+}
+
+#[test]
+fn aabb3_transform_bounds_every_corner() {
+    let b = Aabb3::new(Vec3::new(1.0, 0.0, -1.0), Vec3::new(2.0, 3.0, 1.0));
+    let m = Affine3::from_mat3_translation(Mat3::from_axis_angle(Dir3::new(Vec3::new(1.0, 2.0, 3.0)).unwrap(), 0.7), Vec3::new(4.0, 0.0, -2.0));
+    let moved = b.transform(&m);
+    let tight = Aabb3::from_points(&b.corners().map(|c| m.transform_point3(c))).unwrap();
+    assert!(moved.min.approx_eq(tight.min, EPS) && moved.max.approx_eq(tight.max, EPS));
+}
+
+#[test]
+fn aabb2_rects() {
+    let r = Aabb2::from_center_size(Vec2::new(10.0, 5.0), Vec2::new(4.0, 2.0));
+    assert_eq!(r, Aabb2::from_corners(Vec2::new(12.0, 6.0), Vec2::new(8.0, 4.0)));
+    assert_eq!(r.area(), 8.0);
+    assert_eq!(r.perimeter(), 12.0);
+    assert!(r.contains_point(Vec2::new(8.0, 6.0)) && !r.contains_point(Vec2::new(7.9, 5.0)));
+    assert_eq!(r.clamp_point(Vec2::new(0.0, 5.5)), Vec2::new(8.0, 5.5));
+    let other = Aabb2::new(Vec2::new(11.0, 0.0), Vec2::new(20.0, 4.5));
+    assert!(r.intersects(&other));
+    assert_eq!(r.intersection(&other), Some(Aabb2::new(Vec2::new(11.0, 4.0), Vec2::new(12.0, 4.5))));
+    let moved = r.transform(&Affine2::from_translation(Vec2::new(-10.0, -5.0)));
+    assert_eq!(moved.center(), Vec2::ZERO);
+}
@@ -0,0 +1,26 @@
+use gdmx::{
+    Affine2,
+    Vec2,
+    VecExt,
+};
+use std::f32::consts::FRAC_PI_2;
+
+const EPS: f32 = 1e-5;
+
+#[test]
+fn scale_angle_translation_order() {
+    let a = Affine2::from_scale_angle_translation(Vec2::splat(2.0), FRAC_PI_2, Vec2::new(1.0, 0.0));
+    assert!(a.transform_point2(Vec2::X).approx_eq(Vec2::new(1.0, 2.0), EPS));
+    assert!(a.transform_vector2(Vec2::X).approx_eq(Vec2::new(0.0, 2.0), EPS));
+}
+
+#[test]
+fn composition_and_inverse() {
+    let a = Affine2::from_angle_translation(0.5, Vec2::new(3.0, -1.0));
+    let b = Affine2::from_scale(Vec2::new(2.0, 4.0));
+    let p = Vec2::new(0.25, 0.75);
+    assert!((a * b).transform_point2(p).approx_eq(a.transform_point2(b.transform_point2(p)), EPS));
+    assert!((a * b).inverse().transform_point2((a * b).transform_point2(p)).approx_eq(p, EPS));
+    assert!(a.inverse_rigid().approx_eq(a.inverse(), EPS));
+    assert!(Affine2::from_scale(Vec2::ZERO).try_inverse().is_err());
+}
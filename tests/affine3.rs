@@ -0,0 +1,43 @@
+use gdmx::{
+    Affine3,
+    Dir3,
+    Mat3,
+    Mat4,
+    Vec3,
+    VecExt,
+};
+use std::f32::consts::FRAC_PI_2;
+
+const EPS: f32 = 1e-5;
+
+#[test]
+fn scale_rotation_translation_order() {
+    let rot = Mat3::from_axis_angle(Dir3::Y, FRAC_PI_2);
+    let a = Affine3::from_scale_rotation_translation(Vec3::splat(2.0), rot, Vec3::new(0.0, 1.0, 0.0));
+    let p = Vec3::X;
+    let expected = rot * (p * 2.0) + Vec3::Y;
+    assert!(a.transform_point3(p).approx_eq(expected, EPS));
+    assert!(a.transform_vector3(p).approx_eq(rot * (p * 2.0), EPS));
+    assert!(Mat4::from(a).transform_point3(p).approx_eq(expected, EPS));
+    assert!(Affine3::from_mat4(a.to_mat4()).approx_eq(a, EPS));
+}
+
+#[test]
+fn inverses_undo_the_transform() {
+    let rot = Mat3::from_axis_angle(Dir3::new(Vec3::new(1.0, 2.0, 3.0)).unwrap(), 0.7);
+    let rigid = Affine3::from_mat3_translation(rot, Vec3::new(4.0, -1.0, 2.0));
+    assert!((rigid * rigid.inverse_rigid()).approx_eq(Affine3::IDENTITY, EPS));
+    assert!(rigid.inverse_rigid().approx_eq(rigid.inverse(), EPS));
+
+    let scaled = rigid * Affine3::from_scale(Vec3::new(2.0, 0.5, 3.0));
+    let p = Vec3::new(0.3, -0.2, 1.5);
+    assert!(scaled.inverse().transform_point3(scaled.transform_point3(p)).approx_eq(p, EPS));
+    assert!(Affine3::from_scale(Vec3::new(1.0, 0.0, 1.0)).try_inverse().is_err());
+}
+
+#[test]
+fn composition_matches_mat4() {
+    let a = Affine3::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    let b = Affine3::from_mat3(Mat3::from_axis_angle(Dir3::Z, 0.4));
+    assert!((a * b).to_mat4().approx_eq(a.to_mat4() * b.to_mat4(), EPS));
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "physics")]
+
+use gdmx::{
+    ballistics,
+    Vec3,
+    VecExt,
+};
+
+const G: Vec3 = Vec3::new(0.0, -10.0, 0.0);
+
+#[test]
+fn both_arcs_hit_the_target() {
+    let from = Vec3::new(1.0, 2.0, 3.0);
+    let to = from + Vec3::new(20.0, 5.0, -10.0);
+    let (low, high) = ballistics::launch_velocity(from, to, 25.0, G).unwrap();
+    for v in [low, high] {
+        assert!((v.length() - 25.0).abs() < 1e-3);
+        // time to cover the horizontal distance at the horizontal speed
+        let flat = Vec3::new(v.x, 0.0, v.z);
+        let t = (to - from).with_y(0.0).length() / flat.length();
+        assert!(ballistics::position_at(from, v, G, t).approx_eq(to, 1e-3));
+    }
+    assert!(high.y > low.y);
+    assert!(ballistics::launch_velocity(from, from + Vec3::X * 1000.0, 25.0, G).is_none());
+}
+
+#[test]
+fn apex_and_time_of_flight() {
+    let v = Vec3::new(3.0, 20.0, 0.0);
+    assert!((ballistics::time_of_flight(v, G) - 4.0).abs() < 1e-5);
+    assert!(ballistics::apex(Vec3::ZERO, v, G).approx_eq(Vec3::new(6.0, 20.0, 0.0), 1e-4));
+    let points: Vec<Vec3> = ballistics::trajectory_points(Vec3::ZERO, v, G, 1.0).take(5).collect();
+    assert!(points[4].approx_eq(Vec3::new(12.0, 0.0, 0.0), 1e-4));
+}
+
+#[test]
+fn intercept_meets_moving_target() {
+    let target = Vec3::new(10.0, 0.0, 0.0);
+    let target_vel = Vec3::new(0.0, 3.0, 0.0);
+    let (v, t) = ballistics::intercept(Vec3::ZERO, 5.0, target, target_vel).unwrap();
+    assert!((v.length() - 5.0).abs() < 1e-4);
+    assert!((v * t).approx_eq(target + target_vel * t, 1e-3));
+    assert!(ballistics::intercept(Vec3::ZERO, 1.0, target, Vec3::new(5.0, 0.0, 0.0)).is_none());
+}
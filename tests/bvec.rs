@@ -0,0 +1,38 @@
+use gdmx::{
+    BVec2,
+    BVec3,
+    BVec4,
+    Vec3,
+    Vec4,
+    VecExt,
+};
+
+#[test]
+fn masks_reduce_and_combine() {
+    let m = BVec3::new(true, false, true);
+    assert!(m.any() && !m.all());
+    assert!(BVec2::TRUE.all() && !BVec2::FALSE.any());
+    assert_eq!(m.bitmask(), 0b101);
+    assert_eq!(BVec4::new(false, true, true, true).bitmask(), 0b1110);
+    assert_eq!(!m, BVec3::new(false, true, false));
+    assert_eq!(m & BVec3::new(true, true, false), BVec3::new(true, false, false));
+    assert_eq!(m | BVec3::new(false, true, false), BVec3::TRUE);
+    assert_eq!(m ^ BVec3::TRUE, !m);
+    assert!(m[2] && !m[1]);
+}
+
+#[test]
+fn comparisons_feed_select() {
+    let a = Vec3::new(1.0, 5.0, -2.0);
+    let b = Vec3::new(3.0, 5.0, -4.0);
+    let lt = a.cmplt(b);
+    assert_eq!(lt, BVec3::new(true, false, false));
+    assert_eq!(a.cmpge(b), !lt);
+    assert_eq!(a.cmpeq(b), BVec3::new(false, true, false));
+    assert!(!a.cmple(b).all() && a.cmpne(b).any());
+    // the generic VecExt versions still return arrays
+    assert_eq!(VecExt::cmplt(a, b), [true, false, false]);
+    // branchless componentwise min
+    assert_eq!(Vec3::select(lt, a, b), Vec3::new(1.0, 5.0, -4.0));
+    assert_eq!(Vec4::select(a.extend(0.0).cmpgt(Vec4::ZERO), Vec4::ONE, Vec4::ZERO), Vec4::new(1.0, 1.0, 0.0, 0.0));
+}
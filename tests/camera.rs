@@ -0,0 +1,49 @@
+#![cfg(feature = "camera")]
+
+use gdmx::{
+    camera::{
+        arcball_point,
+        arcball_rotation,
+        FlyCamera,
+        OrbitCamera,
+    },
+    Vec2,
+    Vec3,
+    Vec4,
+    VecExt,
+};
+
+const EPS: f32 = 1e-5;
+
+#[test]
+fn orbit_camera_looks_at_its_target() {
+    let target = Vec3::new(1.0, 2.0, 3.0);
+    let mut cam = OrbitCamera::new(target, 0.3, -0.4, 5.0);
+    cam.orbit(1.0, 10.0);
+    assert!(cam.pitch < std::f32::consts::FRAC_PI_2);
+    assert!((cam.eye().distance(target) - 5.0).abs() < EPS);
+    // the target sits straight ahead in view space
+    let v = cam.view_matrix() * Vec4::new(target.x, target.y, target.z, 1.0);
+    assert!(v.approx_eq(Vec4::new(0.0, 0.0, 5.0, 1.0), 1e-4));
+}
+
+#[test]
+fn fly_camera_moves_in_its_own_basis() {
+    let mut cam = FlyCamera::new(Vec3::ZERO, 0.0, 0.0);
+    let (right, up, forward) = cam.basis();
+    assert!(right.approx_eq(Vec3::X, EPS) && up.approx_eq(Vec3::Y, EPS) && forward.approx_eq(Vec3::Z, EPS));
+    cam.look(std::f32::consts::FRAC_PI_2, 0.5);
+    cam.move_planar(Vec3::new(0.0, 0.0, 2.0));
+    assert!(cam.position.approx_eq(Vec3::new(2.0, 0.0, 0.0), EPS));
+    let eye_space = cam.view_matrix().transform_point3(cam.position + cam.basis().2);
+    assert!(eye_space.approx_eq(Vec3::Z, EPS));
+}
+
+#[test]
+fn arcball_rotates_the_grabbed_point_under_the_cursor() {
+    let from = Vec2::new(0.1, -0.2);
+    let to = Vec2::new(0.4, 0.3);
+    let r = arcball_rotation(from, to);
+    assert!((r * arcball_point(from)).approx_eq(arcball_point(to), EPS));
+    assert_eq!(arcball_rotation(from, from), gdmx::Mat3::IDENTITY);
+}
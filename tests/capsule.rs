@@ -0,0 +1,40 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    geom::{
+        Aabb3,
+        Capsule,
+        Segment3,
+        Sphere,
+    },
+    Vec3,
+};
+
+const EPS: f32 = 1e-5;
+
+#[test]
+fn capsule_overlaps() {
+    let upright = Capsule::from_points(Vec3::ZERO, Vec3::new(0.0, 2.0, 0.0), 0.5);
+    assert!(upright.contains_point(Vec3::new(0.5, 1.0, 0.0)) && !upright.contains_point(Vec3::new(0.0, 2.6, 0.0)));
+    let beside = Capsule::from_points(Vec3::new(1.0, -1.0, 0.0), Vec3::new(1.0, 5.0, 0.0), 0.5);
+    let away = Capsule::from_points(Vec3::new(1.1, -1.0, 0.0), Vec3::new(1.1, 5.0, 0.0), 0.5);
+    assert!(upright.intersects_capsule(&beside) && !upright.intersects_capsule(&away));
+    assert!(upright.intersects_sphere(&Sphere::new(Vec3::new(0.0, 3.0, 0.0), 0.5)));
+    assert!(!upright.intersects_sphere(&Sphere::new(Vec3::new(0.0, 3.0, 0.0), 0.4)));
+    assert_eq!(upright.aabb(), Aabb3::new(Vec3::new(-0.5, -0.5, -0.5), Vec3::new(0.5, 2.5, 0.5)));
+}
+
+#[test]
+fn capsule_against_boxes() {
+    let b = Aabb3::new(Vec3::ZERO, Vec3::ONE);
+    // diagonal past the corner, closest in the middle of the segment rather than at an end
+    let diagonal = Capsule::from_points(Vec3::new(3.0, 0.0, 0.5), Vec3::new(0.0, 3.0, 0.5), 0.75);
+    assert!(diagonal.intersects_aabb(&b));
+    assert!(!Capsule::new(diagonal.segment, 0.65).intersects_aabb(&b));
+    let through = Capsule::from_points(Vec3::new(-2.0, 0.5, 0.5), Vec3::new(2.0, 0.5, 0.5), 0.0);
+    assert!(through.intersects_aabb(&b));
+    let seg = Segment3::new(Vec3::new(3.0, 0.0, 0.5), Vec3::new(0.0, 3.0, 0.5));
+    // the box corner is 1 / sqrt(2) from the line x + y = 3
+    let exact = 0.5;
+    assert!((seg.distance_2_to_aabb(&b) - exact).abs() < EPS);
+}
@@ -0,0 +1,5 @@
+// Checks that misuse which should be rejected at compile time actually is
+#[test]
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/compile_fail/*.rs");
+}
@@ -0,0 +1,8 @@
+use gdmx::Vector;
+
+// a const item so the check runs under cargo check, a runtime call only fails at codegen
+const EMPTY: Vector<f32, 0> = Vector::splat(1.0);
+
+fn main() {
+    let _ = EMPTY;
+}
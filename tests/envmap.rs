@@ -0,0 +1,46 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    envmap::{
+        self,
+        CubeFace,
+    },
+    Vec2,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-5;
+
+#[test]
+fn equirect_round_trips() {
+    let dir = Vec3::new(0.3, -0.4, -0.8).normalize();
+    let uv = envmap::dir_to_equirect(dir);
+    assert!(envmap::equirect_to_dir(uv).approx_eq(dir, EPS));
+    assert!(envmap::dir_to_equirect(Vec3::Z).approx_eq(Vec2::new(0.5, 0.5), EPS));
+    assert!((envmap::dir_to_equirect(Vec3::Y).y).abs() < EPS);
+}
+
+#[test]
+fn cube_faces_round_trip() {
+    for face in CubeFace::ALL {
+        let uv = Vec2::new(0.2, 0.7);
+        let dir = envmap::cube_to_dir(face, uv);
+        let (back, back_uv) = envmap::dir_to_cube(dir * 3.0);
+        assert_eq!(back, face);
+        assert!(back_uv.approx_eq(uv, EPS));
+    }
+    // +z face center, u towards +x and v towards -y
+    let (face, uv) = envmap::dir_to_cube(Vec3::new(0.5, 0.5, 1.0));
+    assert_eq!(face, CubeFace::PosZ);
+    assert!(uv.approx_eq(Vec2::new(0.75, 0.25), EPS));
+}
+
+#[test]
+fn stereographic_round_trips() {
+    let dir = Vec3::new(0.6, 0.0, 0.8);
+    let p = envmap::dir_to_stereographic(dir);
+    assert!((p.length() - 1.0).abs() < EPS);
+    assert!(envmap::stereographic_to_dir(p).approx_eq(dir, EPS));
+    assert!(envmap::stereographic_to_dir(Vec2::ZERO).approx_eq(Vec3::Y, EPS));
+}
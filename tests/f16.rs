@@ -0,0 +1,39 @@
+#![cfg(feature = "f16")]
+
+use gdmx::{
+    F16,
+    F16Vec3,
+    Vec3,
+};
+
+#[test]
+fn exact_values_round_trip() {
+    for v in [0.0, -0.0, 1.0, -2.5, 0.099975586, 65504.0, 6.1035156e-5, 5.9604645e-8] {
+        assert_eq!(F16::from_f32(v).to_f32(), v);
+    }
+    assert_eq!(F16::from_f32(1.0), F16::ONE);
+    assert_eq!(F16::MAX.to_f32(), 65504.0);
+    assert_eq!(F16::EPSILON.to_f32(), 1.0 / 1024.0);
+}
+
+#[test]
+fn rounds_to_nearest_even() {
+    // halfway between 1 and 1 + 2^-10 goes to the even mantissa
+    assert_eq!(F16::from_f32(1.0 + 1.0 / 2048.0), F16::ONE);
+    assert_eq!(F16::from_f32(1.0 + 3.0 / 2048.0).to_f32(), 1.0 + 2.0 / 1024.0);
+    assert_eq!(F16::from_f32(65520.0), F16::INFINITY);
+    assert_eq!(F16::from_f32(65519.0), F16::MAX);
+    assert_eq!(F16::from_f32(1e-8).to_bits(), 0);
+    assert!(F16::from_f32(f32::NAN).is_nan());
+    assert!(!F16::INFINITY.is_finite());
+}
+
+#[test]
+fn vectors_convert_to_f32() {
+    let v = Vec3::new(0.5, -3.0, 1024.0);
+    let h = F16Vec3::from(v);
+    assert_eq!(Vec3::from(h), v);
+    assert_eq!(h.to_bits(), [0x3800, 0xc200, 0x6400]);
+    assert_eq!(F16Vec3::from_bits(h.to_bits()), h);
+    assert_eq!(std::mem::size_of::<F16Vec3>(), 6);
+}
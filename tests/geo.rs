@@ -0,0 +1,33 @@
+#![cfg(feature = "geo")]
+
+use gdmx::{
+    geo,
+    Vec3,
+    VecExt,
+};
+use std::f32::consts::{
+    FRAC_PI_2,
+    PI,
+};
+
+#[test]
+fn lat_lon_round_trips() {
+    let p: Vec3 = geo::from_lat_lon_alt(0.5, -2.0, 10.0, 100.0);
+    let (lat, lon, alt) = geo::to_lat_lon_alt(p, 100.0);
+    assert!((lat - 0.5).abs() < 1e-5 && (lon + 2.0).abs() < 1e-5 && (alt - 10.0).abs() < 1e-3);
+    let north: Vec3 = geo::from_lat_lon(FRAC_PI_2, 0.0, 2.0);
+    assert!(north.approx_eq(Vec3::new(0.0, 2.0, 0.0), 1e-6));
+    let east: Vec3 = geo::from_lat_lon(0.0, FRAC_PI_2, 1.0);
+    assert!(east.approx_eq(Vec3::X, 1e-6));
+}
+
+#[test]
+fn great_circle_distance_and_bearing() {
+    // a quarter of the equator
+    assert!((geo::great_circle_distance(0.0, 0.0, 0.0, FRAC_PI_2, 1.0f32) - FRAC_PI_2).abs() < 1e-6);
+    // works in f64 too
+    let d = geo::great_circle_distance(0.0f64, 0.0, std::f64::consts::FRAC_PI_2, 0.0, 6371.0);
+    assert!((d - 6371.0 * std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    assert!((geo::initial_bearing(0.0f32, 0.0, 0.0, 1.0) - FRAC_PI_2).abs() < 1e-6);
+    assert!((geo::initial_bearing(0.0f32, 0.0, -0.5, 0.0).abs() - PI).abs() < 1e-6);
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "grid")]
+
+use gdmx::{
+    Grid2,
+    Grid3,
+    IVec2,
+    IVec3,
+    Vec2,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn grid2_samples_bilinearly_and_clamps() {
+    let heights = vec![0.0, 1.0, 2.0, 3.0];
+    let grid = Grid2::from_vec(IVec2::new(2, 2), Vec2::new(10.0, 0.0), 2.0, heights).unwrap();
+    assert_eq!(grid[IVec2::new(1, 1)], 3.0);
+    assert!(grid.get(IVec2::new(2, 0)).is_none());
+    assert!((grid.sample(Vec2::new(11.0, 1.0)) - 1.5).abs() < EPS);
+    assert!((grid.sample(Vec2::new(-50.0, 50.0)) - 2.0).abs() < EPS);
+    assert!(Grid2::from_vec(IVec2::new(2, 2), Vec2::ZERO, 1.0, vec![0.0; 3]).is_err());
+}
+
+#[test]
+fn grid3_samples_vectors_trilinearly() {
+    let mut flow = Grid3::new(IVec3::new(2, 1, 2), Vec3::ZERO, 1.0, Vec3::ZERO);
+    flow[IVec3::new(1, 0, 1)] = Vec3::new(4.0, 0.0, 0.0);
+    let v = flow.sample(Vec3::new(0.5, 0.0, 0.5));
+    assert!(v.approx_eq(Vec3::new(1.0, 0.0, 0.0), EPS));
+    assert_eq!(flow.cell_to_world(IVec3::new(1, 0, 1)), Vec3::new(1.0, 0.0, 1.0));
+}
+
+#[test]
+fn indexing_grids_past_i32_samples() {
+    // 1300^3 is more than 2^31 samples, and zero-sized samples keep it from allocating
+    let grid = Grid3::new(IVec3::splat(1300), Vec3::ZERO, 1.0, ());
+    assert_eq!(grid.get(IVec3::splat(1299)), Some(&()));
+    let grid = Grid2::new(IVec2::splat(50_000), Vec2::ZERO, 1.0, ());
+    assert_eq!(grid.get(IVec2::splat(49_999)), Some(&()));
+}
@@ -0,0 +1,26 @@
+use gdmx::int_math::{
+    next_power_of_two,
+    ilog2,
+    div_ceil,
+    align_to,
+    mip_level_count,
+};
+
+#[test]
+fn rounding_helpers() {
+    assert_eq!(next_power_of_two(0), 1);
+    assert_eq!(next_power_of_two(17), 32);
+    assert_eq!(ilog2(1), 0);
+    assert_eq!(ilog2(1023), 9);
+    assert_eq!(div_ceil(10, 4), 3);
+    assert_eq!(align_to(13, 8), 16);
+    assert_eq!(align_to(16, 8), 16);
+}
+
+#[test]
+fn mip_chain_covers_largest_dimension() {
+    assert_eq!(mip_level_count(1, 1), 1);
+    assert_eq!(mip_level_count(256, 64), 9);
+    assert_eq!(mip_level_count(300, 1), 9);
+    assert_eq!(mip_level_count(0, 8), 0);
+}
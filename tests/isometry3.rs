@@ -0,0 +1,43 @@
+use gdmx::{
+    Affine3,
+    Dir3,
+    Isometry3,
+    Mat4,
+    Quat,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-5;
+
+fn body() -> Isometry3 {
+    Isometry3::new(
+        Quat::from_axis_angle(Dir3::new(Vec3::new(0.3, 1.0, -0.2)).unwrap(), 1.3),
+        Vec3::new(4.0, -2.0, 0.5),
+    )
+}
+
+#[test]
+fn inverse_is_exact() {
+    let iso = body();
+    assert!((iso * iso.inverse()).approx_eq(&Isometry3::IDENTITY, EPS));
+    let p = Vec3::new(1.0, 2.0, 3.0);
+    assert!(iso.inverse().transform_point(iso * p).approx_eq(p, EPS));
+    assert!(Affine3::from(iso.inverse()).approx_eq(iso.to_affine3().inverse_rigid(), EPS));
+}
+
+#[test]
+fn composition_matches_matrices() {
+    let a = body();
+    let b = Isometry3::from_rotation(Quat::from_axis_angle(Dir3::Z, 0.5)) * Isometry3::from_translation(Vec3::X);
+    assert!(Mat4::from(a * b).approx_eq(a.to_mat4() * b.to_mat4(), EPS));
+    assert!((a * b).to_transform().to_mat4().approx_eq((a * b).to_mat4(), EPS));
+    assert!(a.transform_vector(Vec3::Y).approx_eq(a.rotation * Vec3::Y, EPS));
+}
+
+#[test]
+fn isometry_with_setters_replace_one_part() {
+    let q = Quat::from_axis_angle(Dir3::Y, 0.5);
+    let iso = Isometry3::IDENTITY.with_rotation(q).with_translation(Vec3::new(1.0, 0.0, -1.0));
+    assert_eq!(iso, Isometry3::new(q, Vec3::new(1.0, 0.0, -1.0)));
+}
@@ -0,0 +1,55 @@
+use gdmx::{
+    IVec2,
+    IVec3,
+    IVec4,
+    Vec2,
+    Vec3,
+    Vec4,
+};
+
+#[test]
+fn arithmetic_is_componentwise() {
+    let a = IVec3::new(1, -2, 3);
+    let b = IVec3::new(4, 5, -6);
+    assert_eq!(a + b, IVec3::new(5, 3, -3));
+    assert_eq!(a - b, IVec3::new(-3, -7, 9));
+    assert_eq!(a * 2, IVec3::new(2, -4, 6));
+    assert_eq!(b / IVec3::splat(2), IVec3::new(2, 2, -3));
+    assert_eq!(-a, IVec3::new(-1, 2, -3));
+    let mut c = IVec2::X;
+    c += IVec2::Y * 3;
+    assert_eq!(c, IVec2::new(1, 3));
+    assert_eq!(a.dot(b), 4 - 10 - 18);
+    assert_eq!(a.manhattan_distance(b), 3 + 7 + 9);
+}
+
+#[test]
+fn wrapping_and_saturating() {
+    let big = IVec2::new(i32::MAX, i32::MIN);
+    assert_eq!(big.wrapping_add(IVec2::ONE), IVec2::new(i32::MIN, i32::MIN + 1));
+    assert_eq!(big.saturating_add(IVec2::ONE), IVec2::new(i32::MAX, i32::MIN + 1));
+    assert_eq!(big.saturating_sub(IVec2::ONE), IVec2::new(i32::MAX - 1, i32::MIN));
+    assert_eq!(big.saturating_mul(IVec2::splat(2)), big);
+}
+
+#[test]
+fn min_max_and_clamp() {
+    let a = IVec4::new(1, 5, -3, 0);
+    let b = IVec4::new(2, 4, -4, 0);
+    assert_eq!(a.min(b), IVec4::new(1, 4, -4, 0));
+    assert_eq!(a.max(b), IVec4::new(2, 5, -3, 0));
+    assert_eq!(a.clamp(IVec4::ZERO, IVec4::splat(2)), IVec4::new(1, 2, 0, 0));
+    assert_eq!(a.min_element(), -3);
+    assert_eq!(a.max_element(), 5);
+}
+
+#[test]
+fn float_conversions_use_the_named_rounding() {
+    let v = Vec3::new(-1.5, 0.5, 2.25);
+    assert_eq!(IVec3::from_floor(v), IVec3::new(-2, 0, 2));
+    assert_eq!(IVec3::from_round(v), IVec3::new(-2, 0, 2));
+    assert_eq!(IVec3::from_ceil(v), IVec3::new(-1, 1, 3));
+    assert_eq!(v.as_ivec3_ceil(), IVec3::from_ceil(v));
+    assert_eq!(IVec2::from_ceil(Vec2::new(0.1, -0.1)), IVec2::new(1, 0));
+    assert_eq!(IVec4::new(1, -2, 3, 4).as_vec4(), Vec4::new(1.0, -2.0, 3.0, 4.0));
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    MassProperties,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn cube_mesh_matches_cuboid() {
+    // unit cube offset from the origin, counter-clockwise seen from outside
+    let v: Vec<Vec3> = (0..8)
+        .map(|i| Vec3::new((i & 1) as f32, ((i >> 1) & 1) as f32, ((i >> 2) & 1) as f32) + Vec3::splat(2.0))
+        .collect();
+    let tris = [
+        [0, 2, 3], [0, 3, 1], // -z
+        [4, 5, 7], [4, 7, 6], // +z
+        [0, 1, 5], [0, 5, 4], // -y
+        [2, 6, 7], [2, 7, 3], // +y
+        [0, 4, 6], [0, 6, 2], // -x
+        [1, 3, 7], [1, 7, 5], // +x
+    ];
+    let mesh = MassProperties::convex_mesh(&v, &tris, 2.0).unwrap();
+    let cuboid = MassProperties::cuboid(Vec3::splat(0.5), 2.0);
+    assert!((mesh.mass - cuboid.mass).abs() < 1e-5);
+    assert!(mesh.center_of_mass.approx_eq(Vec3::splat(2.5), 1e-5));
+    assert!(mesh.inertia.approx_eq(cuboid.inertia, 1e-5));
+}
+
+#[test]
+fn capsule_reduces_to_sphere_and_shifts_by_parallel_axis() {
+    let capsule = MassProperties::capsule(0.0, 1.5, 1.0);
+    let sphere = MassProperties::sphere(1.5, 1.0);
+    assert!((capsule.mass - sphere.mass).abs() < EPS * sphere.mass);
+    assert!(capsule.inertia.approx_eq(sphere.inertia, 1e-5));
+
+    let m = MassProperties::sphere(1.0, 1.0);
+    let shifted = m.inertia_about(Vec3::new(0.0, 2.0, 0.0));
+    assert!((shifted.x_axis.x - (m.inertia.x_axis.x + 4.0 * m.mass)).abs() < 1e-5);
+    assert!((shifted.y_axis.y - m.inertia.y_axis.y).abs() < 1e-5);
+}
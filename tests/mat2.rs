@@ -0,0 +1,25 @@
+use gdmx::{
+    Mat2,
+    Vec2,
+    VecExt,
+};
+use std::f32::consts::FRAC_PI_2;
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn rotation_scale_and_shear() {
+    assert!((Mat2::from_angle(FRAC_PI_2) * Vec2::X).approx_eq(Vec2::Y, EPS));
+    let m = Mat2::from_scale_angle(Vec2::new(2.0, 3.0), FRAC_PI_2);
+    assert!((m * Vec2::new(1.0, 1.0)).approx_eq(Vec2::new(-3.0, 2.0), EPS));
+    assert!((m.determinant() - 6.0).abs() < EPS);
+    assert_eq!(Mat2::from_shear(Vec2::new(0.5, 0.0)) * Vec2::new(0.0, 2.0), Vec2::new(1.0, 2.0));
+}
+
+#[test]
+fn inverse_undoes_transform() {
+    let m = Mat2::from_scale_angle(Vec2::new(2.0, 0.5), 0.3) * Mat2::from_shear(Vec2::new(0.2, -0.1));
+    assert!((m * m.inverse()).approx_eq(Mat2::IDENTITY, EPS));
+    assert!(Mat2::from_diagonal(Vec2::new(1.0, 0.0)).try_inverse().is_err());
+    assert_eq!(m.transpose().transpose(), m);
+}
@@ -0,0 +1,36 @@
+use gdmx::{
+    Mat3,
+    MathError,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn inverse_undoes_multiplication() {
+    let m = Mat3::from_cols(
+        Vec3::new(2.0, 0.0, 1.0),
+        Vec3::new(0.0, 3.0, 0.0),
+        Vec3::new(1.0, 0.0, 1.0),
+    );
+    assert!((m.determinant() - 3.0).abs() < EPS);
+    assert!((m * m.inverse()).approx_eq(Mat3::IDENTITY, EPS));
+    assert!((m.try_inverse().unwrap() * m).approx_eq(Mat3::IDENTITY, EPS));
+    assert_eq!(Mat3::from_outer(Vec3::X, Vec3::Y).try_inverse(), Err(MathError::Degenerate));
+}
+
+#[test]
+fn columns_transform_column_vectors() {
+    let m = Mat3::from_rows(
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(4.0, 5.0, 6.0),
+        Vec3::new(7.0, 8.0, 9.0),
+    );
+    assert_eq!(m.col(0), Vec3::new(1.0, 4.0, 7.0));
+    assert_eq!(m * Vec3::X, m.x_axis);
+    assert_eq!(m.transpose().row(0), m.col(0));
+    assert_eq!(m.trace(), 15.0);
+    let scaled = Mat3::from_diagonal(Vec3::new(1.0, 2.0, 3.0)) * m;
+    assert!((scaled * Vec3::Z).approx_eq(Vec3::new(3.0, 12.0, 27.0), EPS));
+}
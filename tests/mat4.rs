@@ -0,0 +1,42 @@
+use gdmx::{
+    Mat3,
+    Mat4,
+    Vec3,
+    Vec4,
+    VecExt,
+};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn affine_constructors_compose() {
+    let t = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    let s = Mat4::from_scale(Vec3::splat(2.0));
+    let p = Vec3::new(1.0, 1.0, 1.0);
+    assert!((t * s).transform_point3(p).approx_eq(Vec3::new(3.0, 4.0, 5.0), EPS));
+    assert!((s * t).transform_point3(p).approx_eq(Vec3::new(4.0, 6.0, 8.0), EPS));
+    assert!((t * s).transform_vector3(p).approx_eq(Vec3::splat(2.0), EPS));
+    let m = Mat4::from_mat3_translation(Mat3::from_diagonal(Vec3::new(1.0, 2.0, 3.0)), Vec3::X);
+    assert_eq!(m.to_mat3(), Mat3::from_diagonal(Vec3::new(1.0, 2.0, 3.0)));
+    assert_eq!(m.w_axis, Vec4::new(1.0, 0.0, 0.0, 1.0));
+    let r = Mat4::from_rotation(gdmx::Axis::Z, std::f32::consts::FRAC_PI_2);
+    assert!(r.transform_point3(Vec3::X).approx_eq(Vec3::Y, EPS));
+    assert!(r.to_mat3().approx_eq(Mat3::from_axis_angle(gdmx::Dir3::Z, std::f32::consts::FRAC_PI_2), EPS));
+}
+
+#[test]
+fn arithmetic_and_array_conversions() {
+    let a: [f32; 16] = std::array::from_fn(|i| i as f32);
+    let m = Mat4::from_cols_array(a);
+    assert_eq!(<[f32; 16]>::from(m), a);
+    assert_eq!(m.col(1), Vec4::new(4.0, 5.0, 6.0, 7.0));
+    assert_eq!(m.row(1), Vec4::new(1.0, 5.0, 9.0, 13.0));
+    assert_eq!(Mat4::from_rows(m.row(0), m.row(1), m.row(2), m.row(3)), m);
+    assert_eq!(m + m, m * 2.0);
+    assert_eq!(m - m, Mat4::ZERO);
+    assert_eq!(-m, -1.0 * m);
+    assert_eq!(Mat4::IDENTITY * Vec4::new(1.0, 2.0, 3.0, 4.0), Vec4::new(1.0, 2.0, 3.0, 4.0));
+    let inv = (Mat4::from_scale(Vec3::splat(4.0)) * Mat4::from_translation(Vec3::Y)).inverse();
+    assert!(inv.transform_point3(Vec3::new(0.0, 4.0, 0.0)).approx_eq(Vec3::ZERO, EPS));
+    assert!(Mat4::ZERO.try_inverse().is_err());
+}
@@ -0,0 +1,207 @@
+use gdmx::{
+    F32Ext,
+    sincos_slice,
+};
+use std::f32::consts::{
+    PI,
+    FRAC_PI_2,
+};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn wrap_angle_stays_in_half_open_range() {
+    assert!(((3.0 * PI).wrap_angle() - PI).abs() < 1e-5);
+    assert!(((-PI).wrap_angle() - PI).abs() < EPS);
+    assert!(((5.0 * FRAC_PI_2).wrap_angle() - FRAC_PI_2).abs() < EPS);
+    assert!(((-5.0 * FRAC_PI_2).wrap_angle() + FRAC_PI_2).abs() < EPS);
+}
+
+#[test]
+fn delta_angle_takes_the_short_way_around() {
+    let from = PI - 0.1;
+    let to = -PI + 0.1;
+    assert!((from.delta_angle(to) - 0.2).abs() < 1e-5);
+    assert!((to.delta_angle(from) + 0.2).abs() < 1e-5);
+}
+
+#[test]
+fn lerp_angle_crosses_the_seam() {
+    let from = PI - 0.1;
+    let to = -PI + 0.1;
+    let mid = from.lerp_angle(to, 0.5).wrap_angle();
+    assert!((mid.abs() - PI).abs() < 1e-5);
+}
+
+#[test]
+fn approx_eq_ulps_counts_representable_steps() {
+    let one_up = f32::from_bits(1.0f32.to_bits() + 2);
+    assert!(1.0.approx_eq_ulps(one_up, 2));
+    assert!(!1.0.approx_eq_ulps(one_up, 1));
+    assert!(0.0.approx_eq_ulps(-0.0, 0));
+    assert!(!f32::NAN.approx_eq_ulps(f32::NAN, u32::MAX));
+}
+
+#[test]
+fn rsqrt_is_close_to_the_exact_value() {
+    // 1e-40 is subnormal, which the hardware estimates flush to 0
+    for x in [1e-40, 1e-6, 0.25, 1.0, 2.0, 1234.5, 1e12] {
+        let exact = 1.0 / f32::sqrt(x);
+        assert!((x.rsqrt() - exact).abs() <= exact * 1e-6);
+    }
+    assert_eq!(0.0.rsqrt(), f32::INFINITY);
+    assert_eq!(f32::INFINITY.rsqrt(), 0.0);
+}
+
+#[test]
+fn sincos_slice_matches_scalar_sincos() {
+    let angles = [0.0, 0.5, PI, -FRAC_PI_2];
+    let mut sin = [0.0; 4];
+    let mut cos = [0.0; 4];
+    sincos_slice(&angles, &mut sin, &mut cos);
+    for i in 0..4 {
+        assert_eq!((sin[i], cos[i]), angles[i].sincos());
+    }
+}
+
+#[test]
+fn safe_div_falls_back_on_non_finite_quotients() {
+    assert_eq!(1.0.safe_div(2.0, -1.0), 0.5);
+    assert_eq!(1.0.safe_div(0.0, -1.0), -1.0);
+    assert_eq!(0.0.safe_div(0.0, -1.0), -1.0);
+    assert_eq!(f32::MAX.safe_div(0.5, -1.0), -1.0);
+}
+
+#[test]
+fn saturate_clamps_to_unit_interval() {
+    assert_eq!((-0.5).saturate(), 0.0);
+    assert_eq!(0.25.saturate(), 0.25);
+    assert_eq!(3.0.saturate(), 1.0);
+}
+
+#[test]
+fn degree_radian_multipliers_round_trip() {
+    use gdmx::consts::{DEG_TO_RAD, RAD_TO_DEG};
+    const RIGHT_ANGLE: f32 = 90.0 * DEG_TO_RAD;
+    assert!((RIGHT_ANGLE - FRAC_PI_2).abs() < EPS);
+    assert!((RIGHT_ANGLE * RAD_TO_DEG - 90.0).abs() < 1e-4);
+    assert!((F32Ext::to_radians(180.0) - PI).abs() < EPS);
+}
+
+#[test]
+fn horner_helpers_agree_with_poly_eval() {
+    const Q: f32 = gdmx::horner3(2.0, [1.0, -3.0, 0.5]);
+    assert_eq!(Q, 1.0 - 6.0 + 2.0);
+    let c = [0.5, -1.0, 2.0, 0.25, -0.125];
+    assert!((gdmx::horner5(1.5, c) - gdmx::poly_eval(1.5, &c)).abs() < EPS);
+    assert_eq!(gdmx::poly_eval(3.0, &[]), 0.0);
+}
+
+#[test]
+fn exp_decay_is_frame_rate_independent() {
+    let one_step = 0.0.exp_decay(10.0, 3.0, 0.5);
+    let mut many_steps = 0.0;
+    for _ in 0..50 {
+        many_steps = many_steps.exp_decay(10.0, 3.0, 0.01);
+    }
+    assert!((one_step - many_steps).abs() < 1e-4);
+}
+
+#[test]
+fn smooth_damp_converges_without_overshoot() {
+    let run = |dt: f32| {
+        let (mut x, mut v) = (0.0f32, 0.0);
+        for _ in 0..(1.0 / dt) as usize {
+            x = x.smooth_damp(10.0, &mut v, 0.3, dt);
+            assert!(x <= 10.0);
+        }
+        x
+    };
+    let (slow, fast) = (run(1.0 / 30.0), run(1.0 / 120.0));
+    assert!((slow - 10.0).abs() < 0.2);
+    assert!((slow - fast).abs() < 0.05);
+}
+
+#[test]
+fn smoothstep_has_flat_ends() {
+    assert_eq!((-1.0).smoothstep(0.0, 2.0), 0.0);
+    assert_eq!(1.0.smoothstep(0.0, 2.0), 0.5);
+    assert_eq!(3.0.smoothstep(0.0, 2.0), 1.0);
+}
+
+#[test]
+fn step_is_one_at_the_edge() {
+    assert_eq!(0.4.step(0.5), 0.0);
+    assert_eq!(0.5.step(0.5), 1.0);
+}
+
+#[test]
+fn inverse_lerp_and_remap_are_unclamped() {
+    assert_eq!(15.0.inverse_lerp(10.0, 20.0), 0.5);
+    assert_eq!(25.0.inverse_lerp(10.0, 20.0), 1.5);
+    assert_eq!(0.5.remap(0.0, 1.0, -1.0, 1.0), 0.0);
+    assert_eq!(50.0.remap(0.0, 100.0, 1.0, 0.0), 0.5);
+}
+
+#[test]
+fn f64_helpers_mirror_f32() {
+    use gdmx::F64Ext;
+    assert!((3.0 * std::f64::consts::PI).wrap_angle().approx_eq(std::f64::consts::PI, 1e-12));
+    assert!(4.0f64.rsqrt().approx_eq_ulps(0.5, 1));
+    assert_eq!(1.0f64.smoothstep(0.0, 2.0), 0.5);
+}
+
+#[test]
+fn nan_aware_min_max_are_explicit() {
+    assert!(1.0.min_nan_propagating(f32::NAN).is_nan());
+    assert_eq!(1.0.min_nan_ignoring(f32::NAN), 1.0);
+    assert_eq!(0.0.total_min(-0.0).to_bits(), (-0.0f32).to_bits());
+    assert_eq!(0.0.total_max(f32::NAN).to_bits(), f32::NAN.to_bits());
+    assert_eq!(f32::from_bits(0x7fc0_0001).canonicalize_nan().to_bits(), f32::NAN.to_bits());
+}
+
+#[test]
+fn float_to_int_rounding_modes() {
+    assert_eq!((-0.5).fast_floor_i32(), -1);
+    assert_eq!((-1.0).fast_floor_i32(), -1);
+    assert_eq!(2.7.fast_floor_i32(), 2);
+    assert_eq!(f32::NAN.fast_floor_i32(), 0);
+    assert_eq!(1e20.fast_floor_i32(), i32::MAX);
+    assert_eq!((-1e20).fast_floor_i32(), i32::MIN);
+    assert_eq!(2.5.round_half_even_i32(), 2);
+    assert_eq!(3.5.round_half_even_i32(), 4);
+    assert_eq!((-2.5).round_half_even_i32(), -2);
+}
+
+#[test]
+#[cfg(feature = "curves")]
+fn interpolation_hits_corners_and_centers() {
+    use gdmx::{bicubic, bilerp, trilerp, Vec2, Vec3};
+    assert!((bilerp(0.0, 1.0, 2.0, 3.0, Vec2::new(0.5, 0.5)) - 1.5).abs() < EPS);
+    assert_eq!(bilerp(0.0, 1.0, 2.0, 3.0, Vec2::new(1.0, 0.0)), 1.0);
+    let c = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+    assert!((trilerp(c, Vec3::splat(0.5)) - 3.5).abs() < EPS);
+    // a linear ramp is reproduced exactly by Catmull-Rom
+    let ramp = [[0.0, 1.0, 2.0, 3.0]; 4];
+    assert!((bicubic(ramp, Vec2::new(0.25, 0.75)) - 1.25).abs() < EPS);
+    assert!((bicubic(ramp, Vec2::ZERO) - 1.0).abs() < EPS);
+}
+
+#[test]
+fn relative_eq_scales_with_magnitude() {
+    use gdmx::{Vec3, VecExt};
+    const DEFAULT_EPS: f32 = <f32 as gdmx::Scalar>::DEFAULT_EPS;
+    assert!(1.0e6f32.relative_eq(1.0e6 + 50.0, 1e-5, 1e-4));
+    assert!(!1.0e6f32.approx_eq(1.0e6 + 50.0, 1e-5));
+    assert!(0.0f32.relative_eq(1e-7, 1e-6, 1e-4));
+    assert!(!1.0f32.relative_eq(1.1, 1e-6, 1e-4));
+    let a = Vec3::new(1000.0, 0.0, -2000.0);
+    assert!(a.relative_eq(a * 1.00001, DEFAULT_EPS, 1e-4));
+    assert_eq!(DEFAULT_EPS, 1e-5);
+    assert!(a.abs_diff_eq(a + Vec3::splat(1e-6), 1e-5));
+    // inf - inf is NaN, but equal infinities are still equal
+    assert!(f32::INFINITY.approx_eq(f32::INFINITY, 1e-5));
+    assert!(f32::NEG_INFINITY.relative_eq(f32::NEG_INFINITY, 1e-5, 1e-4));
+    assert!(!f32::INFINITY.approx_eq(f32::NEG_INFINITY, 1e-5));
+    assert!(!f32::NAN.approx_eq(f32::NAN, 1e-5));
+}
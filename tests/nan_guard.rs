@@ -0,0 +1,31 @@
+// validate would panic before the hook sees anything
+#![cfg(all(feature = "nan_guard", not(feature = "validate")))]
+
+use gdmx::{
+    nan_guard,
+    Vec3,
+};
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+#[test]
+fn hook_reports_only_the_origin() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let sink = seen.clone();
+    nan_guard::set_nan_hook(move |report| sink.lock().unwrap().push(report.to_string()));
+
+    let a = Vec3::new(1.0, 0.0, 2.0) / 0.0;
+    let _ = a + Vec3::X;
+    assert!(nan_guard::take_nan_hook().is_some());
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 1);
+    assert!(seen[0].starts_with("Vec3 / f32"));
+
+    // in the same test since the hook is global, a hook may replace itself without deadlocking
+    nan_guard::set_nan_hook(|_| nan_guard::set_nan_hook(|_| {}));
+    let _ = Vec3::X / 0.0;
+    assert!(nan_guard::take_nan_hook().is_some());
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "physics")]
+
+use gdmx::{
+    ode::{
+        Integrator,
+        State,
+    },
+    Vec2,
+    VecExt,
+};
+
+#[test]
+fn integrators_follow_a_projectile() {
+    let g = Vec2::new(0.0, -10.0);
+    let start = State::new(Vec2::ZERO, Vec2::new(3.0, 10.0));
+    let exact = Vec2::new(3.0, 10.0 - 5.0);
+    for integrator in [Integrator::VelocityVerlet, Integrator::Rk4] {
+        let mut s = start;
+        for _ in 0..10 {
+            s = integrator.integrate(s, |_, _| g, 0.1);
+        }
+        assert!(s.pos.approx_eq(exact, 1e-4), "{integrator:?} ended at {:?}", s.pos);
+        assert!(s.vel.approx_eq(Vec2::new(3.0, 0.0), 1e-4));
+    }
+    let euler = Integrator::SemiImplicitEuler.integrate(start, |_, _| g, 1.0);
+    assert_eq!(euler.pos, Vec2::new(3.0, 0.0));
+}
+
+#[test]
+fn rk4_is_accurate_for_a_spring() {
+    // x'' = -x starting at rest at x = 1 is cos(t)
+    let mut s = State::new(Vec2::X, Vec2::ZERO);
+    let dt = 0.01;
+    for _ in 0..100 {
+        s = gdmx::ode::rk4(s, |x, _| -x, dt);
+    }
+    assert!((s.pos.x - 1.0f32.cos()).abs() < 1e-5);
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    geom::Plane,
+    Dir3,
+    MathError,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-5;
+
+#[test]
+fn signed_distance_and_projection() {
+    let floor = Plane::from_point_normal(Vec3::new(0.0, 2.0, 0.0), Dir3::Y);
+    assert_eq!(floor.signed_distance(Vec3::new(3.0, 5.0, -1.0)), 3.0);
+    assert_eq!(floor.signed_distance(Vec3::ZERO), -2.0);
+    assert_eq!(floor.project_point(Vec3::new(3.0, 5.0, -1.0)), Vec3::new(3.0, 2.0, -1.0));
+    assert_eq!(floor.point(), Vec3::new(0.0, 2.0, 0.0));
+    assert_eq!(floor.flip().signed_distance(Vec3::ZERO), 2.0);
+}
+
+#[test]
+fn from_points_faces_along_the_cross_product() {
+    let p = Plane::from_points(Vec3::ZERO, Vec3::X, Vec3::Y).unwrap();
+    assert!(p.normal.approx_eq(Vec3::Z, EPS));
+    assert_eq!(p.d, 0.0);
+    assert_eq!(Plane::from_points(Vec3::ZERO, Vec3::X, Vec3::X * 2.0), Err(MathError::Degenerate));
+}
+
+#[test]
+fn normalize_keeps_the_plane_in_place() {
+    let p = Plane::new(Vec3::new(0.0, 0.0, 4.0), -8.0).normalize();
+    assert!(p.normal.approx_eq(Vec3::Z, EPS));
+    assert!((p.d + 2.0).abs() < EPS);
+    assert!(p.signed_distance(Vec3::new(1.0, 1.0, 2.0)).abs() < EPS);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    geom::PluckerLine,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn side_and_distance_between_lines() {
+    let x_axis = PluckerLine::from_points(Vec3::ZERO, Vec3::X);
+    let above = PluckerLine::from_point_dir(Vec3::new(0.0, 2.0, 0.0), Vec3::Z);
+    let below = PluckerLine::from_point_dir(Vec3::new(0.0, -2.0, 0.0), Vec3::Z);
+    assert!(x_axis.side(&above) * x_axis.side(&below) < 0.0);
+    assert!((x_axis.distance(&above) - 2.0).abs() < EPS);
+    // crossing lines have side 0
+    assert_eq!(x_axis.side(&PluckerLine::from_point_dir(Vec3::X, Vec3::Y)), 0.0);
+    // parallel lines
+    let parallel = PluckerLine::from_point_dir(Vec3::new(5.0, 3.0, 4.0), Vec3::X * 3.0);
+    assert!((x_axis.distance(&parallel) - 5.0).abs() < 1e-5);
+    assert!(x_axis.closest_point_to_origin().approx_eq(Vec3::ZERO, EPS));
+}
+
+#[test]
+fn line_through_quad_and_triangle() {
+    let quad = [
+        Vec3::new(-1.0, -1.0, 5.0),
+        Vec3::new(1.0, -1.0, 5.0),
+        Vec3::new(1.0, 1.0, 5.0),
+        Vec3::new(-1.0, 1.0, 5.0),
+    ];
+    let hit = PluckerLine::from_point_dir(Vec3::new(0.5, 0.5, 0.0), Vec3::Z);
+    let miss = PluckerLine::from_point_dir(Vec3::new(1.5, 0.0, 0.0), Vec3::Z);
+    assert!(hit.intersects_quad(quad));
+    assert!(!miss.intersects_quad(quad));
+    let mut reversed = quad;
+    reversed.reverse();
+    assert!(hit.intersects_quad(reversed));
+    // below the diagonal, so only inside the first half of the quad
+    let low = PluckerLine::from_point_dir(Vec3::new(0.5, -0.5, 0.0), Vec3::Z);
+    assert!(low.intersects_triangle([quad[0], quad[1], quad[2]]));
+    assert!(!low.intersects_triangle([quad[0], quad[2], quad[3]]));
+    // too few vertices to be a polygon
+    assert!(!hit.intersects_polygon(&[]));
+    assert!(!hit.intersects_polygon(&quad[..2]));
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    geom::polygon2,
+    Vec2,
+};
+
+#[test]
+fn polygon_contains_point_either_winding() {
+    // an L shape, so the notch is outside
+    let mut l = vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ];
+    for _ in 0..2 {
+        assert!(polygon2::contains_point(&l, Vec2::new(0.5, 0.5)));
+        assert!(polygon2::contains_point(&l, Vec2::new(0.5, 1.5)));
+        assert!(!polygon2::contains_point(&l, Vec2::new(1.5, 1.5)));
+        assert!(!polygon2::contains_point(&l, Vec2::new(-0.5, 1.0)));
+        // level with a vertex, on either side of it
+        assert!(polygon2::contains_point(&l, Vec2::new(0.5, 1.0)));
+        assert!(!polygon2::contains_point(&l, Vec2::new(3.0, 1.0)));
+        l.reverse();
+    }
+}
+
+#[test]
+fn polygon_boundary_counts_as_inside() {
+    let tri = [Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0), Vec2::new(0.0, 4.0)];
+    for p in [Vec2::new(2.0, 0.0), Vec2::new(2.0, 2.0), Vec2::new(0.0, 3.0), Vec2::new(4.0, 0.0), Vec2::ZERO] {
+        assert!(polygon2::contains_point(&tri, p));
+    }
+    assert!(!polygon2::contains_point(&tri, Vec2::new(5.0, 0.0)));
+    assert!(!polygon2::contains_point(&tri, Vec2::new(2.0, 2.1)));
+    assert!(!polygon2::contains_point(&[], Vec2::ZERO));
+}
@@ -0,0 +1,13 @@
+use gdmx::prelude::*;
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn prelude_covers_common_usage() {
+    let v = Vec3::new(0.0, 3.0, 4.0);
+    let up = Dir3::new(v).unwrap();
+    assert!((up.length() - 1.0).abs() < EPS);
+    assert!((FRAC_PI_2 * 2.0 - PI).abs() < EPS);
+    assert!((PI.wrap_angle() - PI).abs() < EPS);
+    assert_eq!(v[Axis::Z], 4.0);
+}
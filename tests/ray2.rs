@@ -0,0 +1,45 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    geom::{
+        Aabb2,
+        Ray2,
+        Segment2,
+    },
+    Affine2,
+    Vec2,
+    VecExt,
+};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn ray2_segment_hits() {
+    let ray = Ray2::new(Vec2::ZERO, Vec2::X);
+    assert_eq!(ray.intersect_segment(&Segment2::new(Vec2::new(3.0, -1.0), Vec2::new(3.0, 1.0))), Some(3.0));
+    // past the end of the segment, behind the ray, and parallel
+    assert_eq!(ray.intersect_segment(&Segment2::new(Vec2::new(3.0, 1.0), Vec2::new(3.0, 2.0))), None);
+    assert_eq!(ray.intersect_segment(&Segment2::new(Vec2::new(-3.0, -1.0), Vec2::new(-3.0, 1.0))), None);
+    assert_eq!(ray.intersect_segment(&Segment2::new(Vec2::new(1.0, 1.0), Vec2::new(4.0, 1.0))), None);
+}
+
+#[test]
+fn ray2_circle_and_rect_hits() {
+    let ray = Ray2::from_points(Vec2::new(-4.0, 0.0), Vec2::new(-3.0, 0.0));
+    assert_eq!(ray.intersect_circle(Vec2::ZERO, 2.0), Some((2.0, 6.0)));
+    assert_eq!(ray.intersect_circle(Vec2::new(0.0, 3.0), 2.0), None);
+    assert_eq!(ray.intersect_circle(Vec2::new(-8.0, 0.0), 1.0), None);
+    let rect = Aabb2::new(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 2.0));
+    assert_eq!(ray.intersect_aabb(&rect), Some((3.0, 5.0)));
+    assert_eq!(Ray2::new(Vec2::new(-4.0, 2.0), Vec2::X).intersect_aabb(&rect), Some((3.0, 5.0)));
+    assert_eq!(Ray2::new(Vec2::new(-4.0, 3.0), Vec2::X).intersect_aabb(&rect), None);
+}
+
+#[test]
+fn ray2_transform_keeps_t() {
+    let m = Affine2::from_angle_translation(0.5, Vec2::new(1.0, -2.0));
+    let ray = Ray2::new(Vec2::new(1.0, 1.0), Vec2::new(2.0, 0.5));
+    let moved = ray.transform(&m);
+    assert!(moved.at(1.5).approx_eq(m.transform_point2(ray.at(1.5)), 1e-5));
+    assert!(moved.origin.approx_eq(m.transform_point2(ray.origin), EPS));
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    geom::Ray3,
+    Affine3,
+    Mat3,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn rays_evaluate_parametrically() {
+    let ray = Ray3::from_points(Vec3::new(1.0, 2.0, 3.0), Vec3::new(1.0, 4.0, 3.0));
+    assert_eq!(ray.at(0.0), ray.origin);
+    assert_eq!(ray.at(1.0), Vec3::new(1.0, 4.0, 3.0));
+    assert_eq!(ray.at(-0.5), Vec3::new(1.0, 1.0, 3.0));
+    // axis-aligned directions get infinite reciprocals for the slab tests
+    let inv = ray.inverse_dir();
+    assert_eq!((inv.x, inv.y, inv.z), (f32::INFINITY, 0.5, f32::INFINITY));
+}
+
+#[test]
+fn set_dir_updates_the_cached_inverse() {
+    let mut ray = Ray3::new(Vec3::ZERO, Vec3::X);
+    ray.set_dir(Vec3::new(2.0, -4.0, 0.5));
+    assert_eq!(ray.dir(), Vec3::new(2.0, -4.0, 0.5));
+    assert_eq!(ray.inverse_dir(), Vec3::new(0.5, -0.25, 2.0));
+}
+
+#[test]
+fn transformed_rays_keep_their_parameter() {
+    let m = Affine3::from_mat3_translation(Mat3::from_diagonal(Vec3::new(2.0, 1.0, 3.0)), Vec3::new(0.0, 5.0, 0.0));
+    let ray = Ray3::new(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 1.0));
+    let moved = ray.transform(&m);
+    for t in [0.0, 0.5, 2.0] {
+        assert!(moved.at(t).approx_eq(m.transform_point3(ray.at(t)), EPS));
+    }
+}
+
+#[test]
+fn slab_intersection_with_boxes() {
+    use gdmx::geom::Aabb3;
+    let b = Aabb3::new(Vec3::ONE, Vec3::splat(3.0));
+    let ray = Ray3::new(Vec3::new(0.0, 2.0, 2.0), Vec3::X);
+    assert_eq!(ray.intersect_aabb(&b), Some((1.0, 3.0)));
+    // starting inside clamps the entry to 0
+    assert_eq!(Ray3::new(Vec3::splat(2.0), Vec3::NEG_Y).intersect_aabb(&b), Some((0.0, 1.0)));
+    // pointing away, and parallel to a slab but outside it
+    assert_eq!(Ray3::new(Vec3::new(0.0, 2.0, 2.0), Vec3::NEG_X).intersect_aabb(&b), None);
+    assert_eq!(Ray3::new(Vec3::new(0.0, 5.0, 2.0), Vec3::X).intersect_aabb(&b), None);
+    // lying exactly in the y = 1 face plane, which makes 0 * inf
+    assert_eq!(Ray3::new(Vec3::new(0.0, 1.0, 2.0), Vec3::X).intersect_aabb(&b), Some((1.0, 3.0)));
+    assert_eq!(Ray3::new(Vec3::new(0.0, 3.0, 2.0), Vec3::new(1.0, -0.0, 0.0)).intersect_aabb(&b), Some((1.0, 3.0)));
+    let diag = Ray3::from_points(Vec3::ZERO, Vec3::ONE).intersect_aabb(&b).unwrap();
+    assert!((diag.0 - 1.0).abs() < EPS && (diag.1 - 3.0).abs() < EPS);
+}
+
+#[test]
+fn sphere_hits_report_both_crossings() {
+    use gdmx::geom::Sphere;
+    let s = Sphere::new(Vec3::new(5.0, 0.0, 0.0), 2.0);
+    let hit = Ray3::new(Vec3::ZERO, Vec3::X).intersect_sphere(&s).unwrap();
+    assert_eq!((hit.t_enter, hit.t_exit), (3.0, 7.0));
+    assert_eq!((hit.normal_enter, hit.normal_exit), (-Vec3::X, Vec3::X));
+    // dir scales t, and starting inside gives a negative entry
+    let inside = Ray3::new(Vec3::new(5.0, 0.0, 0.0), Vec3::new(0.0, 4.0, 0.0)).intersect_sphere(&s).unwrap();
+    assert_eq!((inside.t_enter, inside.t_exit), (-0.5, 0.5));
+    assert!(Ray3::new(Vec3::ZERO, Vec3::NEG_X).intersect_sphere(&s).is_none());
+    assert!(Ray3::new(Vec3::new(0.0, 2.5, 0.0), Vec3::X).intersect_sphere(&s).is_none());
+}
+
+#[test]
+fn capsule_hits_on_body_and_caps() {
+    use gdmx::geom::Capsule;
+    let c = Capsule::from_points(Vec3::ZERO, Vec3::new(0.0, 4.0, 0.0), 1.0);
+    // across the body
+    let body = Ray3::new(Vec3::new(-5.0, 2.0, 0.0), Vec3::X).intersect_capsule(&c).unwrap();
+    assert!((body.t_enter - 4.0).abs() < EPS && (body.t_exit - 6.0).abs() < EPS);
+    assert!(body.normal_enter.approx_eq(-Vec3::X, EPS) && body.normal_exit.approx_eq(Vec3::X, EPS));
+    // down the axis, entering the top cap and leaving the bottom one
+    let axis = Ray3::new(Vec3::new(0.0, 10.0, 0.0), Vec3::NEG_Y).intersect_capsule(&c).unwrap();
+    assert!((axis.t_enter - 5.0).abs() < EPS && (axis.t_exit - 11.0).abs() < EPS);
+    assert!(axis.normal_enter.approx_eq(Vec3::Y, EPS) && axis.normal_exit.approx_eq(Vec3::NEG_Y, EPS));
+    // grazing past a cap
+    let cap = Ray3::new(Vec3::new(-5.0, 4.5, 0.0), Vec3::X).intersect_capsule(&c).unwrap();
+    let half_chord = 0.75f32.sqrt();
+    assert!((cap.t_enter - (5.0 - half_chord)).abs() < EPS);
+    assert!(Ray3::new(Vec3::new(-5.0, 5.5, 0.0), Vec3::X).intersect_capsule(&c).is_none());
+    assert!(Ray3::new(Vec3::new(-5.0, 2.0, 0.0), Vec3::NEG_X).intersect_capsule(&c).is_none());
+}
+
+#[test]
+fn plane_intersection_hits_either_side() {
+    use gdmx::{geom::Plane, Dir3};
+    let floor = Plane::from_point_normal(Vec3::new(0.0, 1.0, 0.0), Dir3::Y);
+    assert_eq!(Ray3::new(Vec3::new(2.0, 5.0, 0.0), Vec3::new(0.0, -2.0, 0.0)).intersect_plane(&floor), Some(2.0));
+    assert_eq!(Ray3::new(Vec3::new(2.0, -1.0, 0.0), Vec3::Y).intersect_plane(&floor), Some(2.0));
+    assert_eq!(Ray3::new(Vec3::new(2.0, 5.0, 0.0), Vec3::Y).intersect_plane(&floor), None);
+    assert_eq!(Ray3::new(Vec3::new(2.0, 5.0, 0.0), Vec3::X).intersect_plane(&floor), None);
+}
@@ -0,0 +1,51 @@
+use gdmx::{
+    Mat2,
+    Rot2,
+    Vec2,
+    VecExt,
+};
+use std::f32::consts::{FRAC_PI_2, PI};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn rotates_counter_clockwise() {
+    let r = Rot2::from_angle(FRAC_PI_2);
+    assert!((r * Vec2::X).approx_eq(Vec2::Y, EPS));
+    assert!((r.angle() - FRAC_PI_2).abs() < EPS);
+    let v = Vec2::new(2.0, -1.0);
+    assert!((r * v).approx_eq(Mat2::from(r) * v, EPS));
+}
+
+#[test]
+fn composition_adds_angles() {
+    let a = Rot2::from_angle(0.3);
+    let b = Rot2::from_angle(1.1);
+    assert!((a * b).approx_eq(Rot2::from_angle(1.4), EPS));
+    assert!((a * a.inverse()).approx_eq(Rot2::IDENTITY, EPS));
+    assert!(a.slerp(b, 0.5).approx_eq(Rot2::from_angle(0.7), EPS));
+    // takes the short way across the +-PI seam
+    let s = Rot2::from_angle(PI - 0.1).slerp(Rot2::from_angle(-PI + 0.1), 0.5);
+    assert!((s.angle().abs() - PI).abs() < 1e-5);
+}
+
+#[test]
+fn rotation_arc_and_normalize() {
+    let r = Rot2::from_rotation_arc(Vec2::new(2.0, 0.0), Vec2::new(0.0, -3.0));
+    assert!(r.approx_eq(Rot2::from_angle(-FRAC_PI_2), 1e-5));
+    let mut drift = Rot2::from_angle(0.01);
+    for _ in 0..1000 {
+        drift *= Rot2::from_angle(0.01);
+    }
+    assert!(drift.normalize().is_normalized(1e-5));
+}
+
+#[test]
+fn is_normalized_agrees_with_vectors_and_quats() {
+    // length 1.0008 is within 1e-3 of 1, though its squared length is not
+    let (c, s) = (0.6 * 1.0008, 0.8 * 1.0008);
+    assert!(Vec2::new(c, s).is_normalized(1e-3));
+    assert!(Rot2 { cos: c, sin: s }.is_normalized(1e-3));
+    assert!(gdmx::Quat::from_xyzw(0.0, s, 0.0, c).is_normalized(1e-3));
+    assert!(!Rot2 { cos: c, sin: s }.is_normalized(1e-4));
+}
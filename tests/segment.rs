@@ -0,0 +1,48 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    geom::{
+        Segment2,
+        Segment3,
+    },
+    Vec2,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-5;
+
+#[test]
+fn closest_point_to_a_point() {
+    let s = Segment3::new(Vec3::ZERO, Vec3::new(4.0, 0.0, 0.0));
+    assert_eq!(s.length(), 4.0);
+    assert_eq!(s.closest_point(Vec3::new(1.0, 3.0, 0.0)), Vec3::new(1.0, 0.0, 0.0));
+    assert_eq!(s.closest_point(Vec3::new(-2.0, 1.0, 0.0)), s.a);
+    assert_eq!(s.closest_point(Vec3::new(9.0, 0.0, 1.0)), s.b);
+    assert_eq!(s.distance_to_point(Vec3::new(2.0, 0.0, -3.0)), 3.0);
+    let point = Segment3::new(Vec3::ONE, Vec3::ONE);
+    assert_eq!(point.closest_point(Vec3::ZERO), Vec3::ONE);
+}
+
+#[test]
+fn closest_points_between_skew_and_parallel_segments() {
+    let s = Segment3::new(Vec3::new(-1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    let crossing = Segment3::new(Vec3::new(0.5, -1.0, 2.0), Vec3::new(0.5, 1.0, 2.0));
+    let (p, q) = s.closest_points_between_segments(&crossing);
+    assert!(p.approx_eq(Vec3::new(0.5, 0.0, 0.0), EPS) && q.approx_eq(Vec3::new(0.5, 0.0, 2.0), EPS));
+    // other's closest point is clamped to an end, so self's must be recomputed
+    let off_end = Segment3::new(Vec3::new(3.0, 1.0, 0.0), Vec3::new(3.0, 5.0, 0.0));
+    let (p, q) = s.closest_points_between_segments(&off_end);
+    assert!(p.approx_eq(s.b, EPS) && q.approx_eq(off_end.a, EPS));
+    let parallel = Segment3::new(Vec3::new(0.0, 2.0, 0.0), Vec3::new(5.0, 2.0, 0.0));
+    assert!((s.distance_to_segment(&parallel) - 2.0).abs() < EPS);
+}
+
+#[test]
+fn segment2_matches_3d() {
+    let s = Segment2::new(Vec2::ZERO, Vec2::new(0.0, 2.0));
+    assert_eq!(s.closest_point(Vec2::new(3.0, 1.0)), Vec2::new(0.0, 1.0));
+    let other = Segment2::new(Vec2::new(1.0, 3.0), Vec2::new(2.0, 4.0));
+    let (p, q) = s.closest_points_between_segments(&other);
+    assert!(p.approx_eq(Vec2::new(0.0, 2.0), EPS) && q.approx_eq(Vec2::new(1.0, 3.0), EPS));
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    geom::{
+        Aabb3,
+        Sphere,
+    },
+    Dir3,
+    Quat,
+    Transform,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-5;
+
+#[test]
+fn containment_and_overlap() {
+    let s = Sphere::new(Vec3::new(1.0, 0.0, 0.0), 2.0);
+    assert!(s.contains_point(Vec3::new(3.0, 0.0, 0.0)) && !s.contains_point(Vec3::new(2.5, 1.5, 0.0)));
+    assert!(s.intersects_sphere(&Sphere::new(Vec3::new(4.0, 0.0, 0.0), 1.0)));
+    assert!(!s.intersects_sphere(&Sphere::new(Vec3::new(4.0, 0.0, 0.0), 0.9)));
+    let b = Aabb3::new(Vec3::new(3.0, 0.0, 0.0), Vec3::new(4.0, 1.0, 1.0));
+    assert!(s.intersects_aabb(&b));
+    // near the box corner but outside the sphere
+    assert!(!Sphere::new(Vec3::new(5.0, 2.0, 2.0), 1.5).intersects_aabb(&b));
+    assert_eq!(s.aabb(), Aabb3::new(Vec3::new(-1.0, -2.0, -2.0), Vec3::new(3.0, 2.0, 2.0)));
+}
+
+#[test]
+fn transformed_uses_the_largest_scale() {
+    let s = Sphere::new(Vec3::X, 1.0);
+    let t = Transform::from_scale_rotation_translation(
+        Vec3::new(2.0, -3.0, 1.0),
+        Quat::from_axis_angle(Dir3::Z, std::f32::consts::FRAC_PI_2),
+        Vec3::new(0.0, 0.0, 5.0),
+    );
+    let moved = s.transformed(&t);
+    assert!(moved.center.approx_eq(Vec3::new(0.0, 2.0, 5.0), EPS));
+    assert_eq!(moved.radius, 3.0);
+}
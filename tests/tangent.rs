@@ -0,0 +1,43 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    tangent,
+    Vec2,
+    Vec3,
+    Vec4,
+    VecExt,
+};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn quad_tangents_follow_u_and_handedness_follows_v() {
+    // a quad in the xz-plane facing +y, u along +x
+    let positions = [Vec3::ZERO, Vec3::X, Vec3::new(1.0, 0.0, 1.0), Vec3::Z];
+    let normals = [Vec3::Y; 4];
+    let indices = [0, 2, 1, 0, 3, 2];
+
+    let uvs = [Vec2::ZERO, Vec2::X, Vec2::new(1.0, 1.0), Vec2::Y];
+    let frames = tangent::generate_tangents(&positions, &normals, &uvs, &indices).unwrap();
+    for (f, n) in frames.iter().zip(normals) {
+        assert!(f.approx_eq(Vec4::new(1.0, 0.0, 0.0, -1.0), EPS));
+        assert!(tangent::bitangent(n, *f).approx_eq(Vec3::Z, EPS));
+    }
+
+    // mirroring v flips the handedness
+    let mirrored = uvs.map(|uv| Vec2::new(uv.x, 1.0 - uv.y));
+    let frames = tangent::generate_tangents(&positions, &normals, &mirrored, &indices).unwrap();
+    assert!(frames.iter().all(|f| f.w == 1.0));
+    assert!(tangent::bitangent(Vec3::Y, frames[0]).approx_eq(-Vec3::Z, EPS));
+}
+
+#[test]
+fn mismatched_inputs_are_rejected() {
+    let positions = [Vec3::ZERO; 3];
+    assert!(tangent::generate_tangents(&positions, &[Vec3::Y; 2], &[Vec2::ZERO; 3], &[0, 1, 2]).is_err());
+    assert!(tangent::generate_tangents(&positions, &[Vec3::Y; 3], &[Vec2::ZERO; 3], &[0, 1]).is_err());
+    // degenerate uvs still produce a unit tangent perpendicular to the normal
+    let frames = tangent::generate_tangents(&positions, &[Vec3::Y; 3], &[Vec2::ZERO; 3], &[0, 1, 2]).unwrap();
+    let t = Vec3::new(frames[0].x, frames[0].y, frames[0].z);
+    assert!((t.length() - 1.0).abs() < EPS && t.dot(Vec3::Y).abs() < EPS);
+}
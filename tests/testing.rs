@@ -0,0 +1,42 @@
+#![cfg(feature = "testing")]
+
+use gdmx::{
+    assert_mat_eq,
+    assert_quat_eq,
+    assert_vec_eq,
+    testing,
+    Mat3,
+    Vec3,
+    Vec4,
+};
+
+#[test]
+fn assertions_accept_close_values() {
+    assert_vec_eq!(Vec3::new(1.0, 2.0, 3.0), Vec3::new(1.0, 2.0 + 1e-6, 3.0));
+    assert_vec_eq!(1.0f32, 1.05, 0.1);
+    assert_mat_eq!(Mat3::IDENTITY * 2.0, Mat3::from_diagonal(Vec3::splat(2.0)));
+    // a quaternion and its negation are the same rotation
+    assert_quat_eq!(Vec4::new(0.0, 0.6, 0.0, 0.8), Vec4::new(0.0, -0.6, 0.0, -0.8));
+}
+
+#[test]
+#[cfg_attr(feature = "validate", ignore = "constructs non-finite vectors")]
+fn diff_lists_only_differing_components() {
+    let diff = testing::approx_eq_diff(&Vec3::new(1.0, 2.0, 3.0), &Vec3::new(1.0, 2.5, f32::NAN), 0.1).unwrap();
+    assert!(!diff.contains("[0]"));
+    assert!(diff.contains("[1]: 2 vs 2.5"));
+    assert!(diff.contains("[2]"));
+}
+
+#[test]
+#[should_panic(expected = "components that differ")]
+fn assertion_panics_with_diff() {
+    assert_vec_eq!(Vec3::X, Vec3::Y);
+}
+
+#[test]
+fn diff_reports_a_length_mismatch() {
+    let (a, b) = ([1.0f32, 2.0, 3.0], [1.0f32, 2.0]);
+    let diff = testing::approx_eq_diff(&a[..], &b[..], 0.1).unwrap();
+    assert!(diff.contains("length: 3 vs 2"));
+}
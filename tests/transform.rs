@@ -0,0 +1,98 @@
+use gdmx::{
+    Affine3,
+    Dir3,
+    Mat3,
+    Mat4,
+    Quat,
+    Transform,
+    Vec3,
+    VecExt,
+};
+use std::f32::consts::FRAC_PI_2;
+
+const EPS: f32 = 1e-5;
+
+#[test]
+fn quat_matches_matrix_rotation() {
+    let axis = Dir3::new(Vec3::new(1.0, 2.0, -0.5)).unwrap();
+    let q = Quat::from_axis_angle(axis, 0.8);
+    let m = Mat3::from_axis_angle(axis, 0.8);
+    let v = Vec3::new(0.3, -1.0, 2.0);
+    assert!((q * v).approx_eq(m * v, EPS));
+    assert!(q.to_mat3().approx_eq(m, EPS));
+    assert!(Quat::from_mat3(m).approx_eq(q, EPS));
+    assert!((q * q.inverse()).approx_eq(Quat::IDENTITY, EPS));
+    // q and -q are the same rotation
+    assert!(Quat::from_mat3(m).approx_eq(-q, EPS));
+}
+
+#[test]
+fn quat_slerp_halves_the_angle() {
+    let a = Quat::IDENTITY;
+    let b = Quat::from_axis_angle(Dir3::Y, FRAC_PI_2);
+    assert!(a.slerp(b, 0.5).approx_eq(Quat::from_axis_angle(Dir3::Y, FRAC_PI_2 * 0.5), EPS));
+    assert!(a.slerp(-b, 0.5).approx_eq(Quat::from_axis_angle(Dir3::Y, FRAC_PI_2 * 0.5), EPS));
+}
+
+#[test]
+fn quat_try_from_slice_checks_the_length() {
+    use gdmx::MathError;
+    assert_eq!(Quat::try_from_slice(&[0.0, 0.0, 0.0, 1.0]), Ok(Quat::IDENTITY));
+    assert_eq!(
+        Quat::try_from_slice(&[0.0, 0.0, 1.0]),
+        Err(MathError::SliceLength { expected: 4, found: 3 }),
+    );
+}
+
+#[test]
+fn trs_applies_scale_then_rotation_then_translation() {
+    let t = Transform::from_scale_rotation_translation(
+        Vec3::splat(2.0),
+        Quat::from_axis_angle(Dir3::Y, FRAC_PI_2),
+        Vec3::new(0.0, 1.0, 0.0),
+    );
+    let p = t.transform_point(Vec3::X);
+    assert!(p.approx_eq(Vec3::new(0.0, 1.0, -2.0), EPS));
+    assert!(Mat4::from(t).transform_point3(Vec3::X).approx_eq(p, EPS));
+    assert!(t.inverse().transform_point(p).approx_eq(Vec3::X, EPS));
+}
+
+#[test]
+fn hierarchy_composition() {
+    let parent = Transform::from_scale_rotation_translation(
+        Vec3::splat(3.0),
+        Quat::from_axis_angle(Dir3::Z, 0.4),
+        Vec3::new(1.0, 2.0, 3.0),
+    );
+    let child = Transform::from_scale_rotation_translation(
+        Vec3::new(1.0, 2.0, 0.5),
+        Quat::from_axis_angle(Dir3::X, -0.9),
+        Vec3::new(-1.0, 0.0, 4.0),
+    );
+    let p = Vec3::new(0.2, 0.7, -1.3);
+    let world = parent * child;
+    assert!(world.transform_point(p).approx_eq(parent.transform_point(child.transform_point(p)), 1e-4));
+    assert!(world.to_mat4().approx_eq(parent.to_mat4() * child.to_mat4(), 1e-4));
+}
+
+#[test]
+fn decomposes_from_affine3() {
+    let t = Transform::from_scale_rotation_translation(
+        Vec3::new(-1.0, 2.0, 0.5),
+        Quat::from_axis_angle(Dir3::new(Vec3::new(1.0, 1.0, 0.0)).unwrap(), 1.2),
+        Vec3::new(5.0, -2.0, 1.0),
+    );
+    let back = Transform::from(Affine3::from(t));
+    assert!(back.to_affine3().approx_eq(t.to_affine3(), EPS));
+    assert!(Transform::from_mat4(t.to_mat4()).approx_eq(&back, EPS));
+}
+
+#[test]
+fn transform_with_setters_replace_one_part() {
+    let q = Quat::from_axis_angle(Dir3::Z, 0.5);
+    let t = Transform::IDENTITY
+        .with_translation(Vec3::new(1.0, 2.0, 3.0))
+        .with_rotation(q)
+        .with_scale(Vec3::splat(2.0));
+    assert_eq!(t, Transform::from_scale_rotation_translation(Vec3::splat(2.0), q, Vec3::new(1.0, 2.0, 3.0)));
+}
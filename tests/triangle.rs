@@ -0,0 +1,41 @@
+#![cfg(feature = "geometry")]
+
+use gdmx::{
+    geom::Triangle,
+    Vec3,
+    VecExt,
+};
+
+const EPS: f32 = 1e-5;
+
+#[test]
+fn normal_area_and_centroid() {
+    let t = Triangle::new(Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.0, 2.0, 0.0));
+    assert!(t.normal().approx_eq(Vec3::Z, EPS));
+    assert_eq!(t.area(), 2.0);
+    assert!(t.centroid().approx_eq(Vec3::new(2.0 / 3.0, 2.0 / 3.0, 0.0), EPS));
+    assert!(t.barycentric(t.centroid()).approx_eq(Vec3::splat(1.0 / 3.0), EPS));
+}
+
+#[test]
+fn projected_containment() {
+    let t = Triangle::new(Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.0, 2.0, 0.0));
+    assert!(t.contains_point_projected(Vec3::new(0.5, 0.5, 10.0)));
+    assert!(t.contains_point_projected(Vec3::new(1.0, 1.0, -3.0)));
+    assert!(!t.contains_point_projected(Vec3::new(1.5, 1.5, 0.0)));
+}
+
+#[test]
+fn closest_point_covers_every_region() {
+    let t = Triangle::new(Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.0, 2.0, 0.0));
+    // face
+    assert!(t.closest_point(Vec3::new(0.5, 0.5, 3.0)).approx_eq(Vec3::new(0.5, 0.5, 0.0), EPS));
+    // vertices
+    assert_eq!(t.closest_point(Vec3::new(-1.0, -1.0, 0.0)), t.a);
+    assert_eq!(t.closest_point(Vec3::new(3.0, -1.0, 1.0)), t.b);
+    assert_eq!(t.closest_point(Vec3::new(-1.0, 3.0, 0.0)), t.c);
+    // edges
+    assert!(t.closest_point(Vec3::new(1.0, -2.0, 0.0)).approx_eq(Vec3::new(1.0, 0.0, 0.0), EPS));
+    assert!(t.closest_point(Vec3::new(-2.0, 1.0, 0.0)).approx_eq(Vec3::new(0.0, 1.0, 0.0), EPS));
+    assert!(t.closest_point(Vec3::new(2.0, 2.0, 0.0)).approx_eq(Vec3::new(1.0, 1.0, 0.0), EPS));
+}
@@ -0,0 +1,24 @@
+use gdmx::{
+    U8Vec4,
+    Vec4,
+    VecExt,
+};
+
+#[test]
+fn pack_is_little_endian_rgba() {
+    let c = U8Vec4::new(0x11, 0x22, 0x33, 0x44);
+    assert_eq!(c.pack_u32(), 0x4433_2211);
+    assert_eq!(U8Vec4::unpack_u32(c.pack_u32()), c);
+    assert_eq!(c.pack_u32().to_le_bytes(), <[u8; 4]>::from(c));
+}
+
+#[test]
+fn unorm_conversions_clamp_and_round() {
+    let c = U8Vec4::from_unorm(Vec4::new(0.0, 0.5, 1.5, -1.0));
+    assert_eq!(c, U8Vec4::new(0, 128, 255, 0));
+    assert!(U8Vec4::MAX.to_unorm().approx_eq(Vec4::splat(1.0), 1e-6));
+    for b in 0..=255u8 {
+        let c = U8Vec4::splat(b);
+        assert_eq!(U8Vec4::from_unorm(c.to_unorm()), c);
+    }
+}
@@ -0,0 +1,36 @@
+use gdmx::{
+    USizeVec2,
+    USizeVec3,
+};
+
+#[test]
+fn row_major_index_round_trips() {
+    let extent = USizeVec2::new(4, 3);
+    assert_eq!(USizeVec2::new(1, 2).to_index(extent), 9);
+    for i in 0..extent.element_product() {
+        let p = USizeVec2::from_index(i, extent);
+        assert!(p.is_inside(extent));
+        assert_eq!(p.to_index(extent), i);
+        assert_eq!(USizeVec2::from_index_col_major(p.to_index_col_major(extent), extent), p);
+    }
+    assert_eq!(USizeVec2::new(1, 2).to_index_col_major(extent), 5);
+}
+
+#[test]
+fn volume_indexing_covers_every_cell_once() {
+    let extent = USizeVec3::new(2, 3, 4);
+    let mut seen = vec![false; extent.element_product()];
+    for z in 0..4 {
+        for y in 0..3 {
+            for x in 0..2 {
+                let p = USizeVec3::new(x, y, z);
+                let i = p.to_index(extent);
+                assert!(!seen[i]);
+                seen[i] = true;
+                assert_eq!(USizeVec3::from_index(i, extent), p);
+                assert_eq!(USizeVec3::from_index_col_major(p.to_index_col_major(extent), extent), p);
+            }
+        }
+    }
+    assert!(!USizeVec3::new(2, 0, 0).is_inside(extent));
+}
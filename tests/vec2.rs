@@ -0,0 +1,79 @@
+use gdmx::{
+    VecExt,
+    Vec2,
+};
+use std::f32::consts::FRAC_PI_2;
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn from_angle_is_counter_clockwise_from_x() {
+    assert!(Vec2::from_angle(0.0).approx_eq(Vec2::X, EPS));
+    assert!(Vec2::from_angle(FRAC_PI_2).approx_eq(Vec2::Y, EPS));
+    assert!(Vec2::from_angle(-FRAC_PI_2).approx_eq(Vec2::NEG_Y, EPS));
+}
+
+#[test]
+fn polar_round_trip() {
+    let v = Vec2::new(-3.0, 4.0);
+    let (r, angle) = v.to_polar();
+    assert!((r - 5.0).abs() < EPS);
+    assert!(Vec2::from_polar(r, angle).approx_eq(v, 1e-5));
+}
+
+#[test]
+fn perp_is_a_quarter_turn() {
+    let v = Vec2::new(3.0, 1.0);
+    assert_eq!(v.perp(), Vec2::new(-1.0, 3.0));
+    assert_eq!(v.perp().dot(v), 0.0);
+    assert_eq!(Vec2::X.perp_dot(Vec2::Y), 1.0);
+    assert_eq!(Vec2::Y.perp_dot(Vec2::X), -1.0);
+    assert_eq!(v.perp_dot(Vec2::new(5.0, 2.0)), v.perp().dot(Vec2::new(5.0, 2.0)));
+}
+
+#[test]
+fn to_angle_and_rotate() {
+    use gdmx::Rot2;
+    assert!((Vec2::from_angle(2.0).to_angle() - 2.0).abs() < EPS);
+    assert!(Vec2::new(2.0, 0.0).rotate(FRAC_PI_2).approx_eq(Vec2::new(0.0, 2.0), EPS));
+    let r = Rot2::from_angle(-0.4);
+    let v = Vec2::new(1.0, -3.0);
+    assert!(v.rotate_by(r).approx_eq(v.rotate(-0.4), EPS));
+    assert_eq!(Vec2::ZERO.to_angle(), 0.0);
+}
+
+#[test]
+fn rotate_towards_takes_the_short_way() {
+    let from = Vec2::from_angle(3.0);
+    let to = Vec2::from_angle(-3.0);
+    let step = from.rotate_towards(to, 0.1);
+    assert!(step.approx_eq(Vec2::from_angle(3.1), 1e-5));
+    assert_eq!(from.rotate_towards(to, 1.0), to);
+}
+
+#[test]
+fn signed_angle_between_is_counter_clockwise() {
+    use std::f32::consts::PI;
+    assert!((Vec2::X.signed_angle_between(Vec2::Y) - FRAC_PI_2).abs() < EPS);
+    assert!((Vec2::Y.signed_angle_between(Vec2::X) + FRAC_PI_2).abs() < EPS);
+    assert_eq!(Vec2::X.signed_angle_between(Vec2::new(-1.0, -0.0)), PI);
+}
+
+#[test]
+fn reflect_and_refract_in_2d() {
+    use gdmx::Dir2;
+    let v = Vec2::new(1.0, -1.0);
+    assert!(v.reflect(Dir2::Y).approx_eq(Vec2::new(1.0, 1.0), EPS));
+    let d = v.normalize();
+    assert!(d.refract(Dir2::Y, 1.0).approx_eq(d, EPS));
+    // grazing ray leaving a dense medium is totally internally reflected
+    assert_eq!(Vec2::new(0.99, -0.141).normalize().refract(Dir2::Y, 1.5), Vec2::ZERO);
+    assert!((Vec2::X.angle_between(Vec2::new(-3.0, 0.0)) - std::f32::consts::PI).abs() < EPS);
+    assert!((Vec2::X.cos_angle_between(Vec2::new(0.0, -2.0))).abs() < EPS);
+}
+
+#[test]
+fn rotate_around_a_pivot() {
+    let r = Vec2::new(3.0, 2.0).rotate_around(Vec2::new(2.0, 2.0), FRAC_PI_2);
+    assert!(r.approx_eq(Vec2::new(2.0, 3.0), 1e-6));
+}
@@ -12,3 +12,493 @@ fn unit_vectors_have_equal_length() {
     let len_diff = x_len - y_len;
     assert!(len_diff.abs() < EPS);
 }
+
+#[test]
+fn approx_eq_is_componentwise() {
+    let a = Vec3::new(1.0, 2.0, 3.0);
+    let b = a + Vec3::new(0.0, 1e-7, -1e-7);
+    assert!(a.approx_eq(b, EPS));
+    assert!(!a.approx_eq(b + Vec3::Z, EPS));
+    assert!(a.abs_diff(b + Vec3::Z).approx_eq(Vec3::Z, EPS));
+}
+
+#[test]
+fn safe_div_and_select_are_componentwise() {
+    let q = Vec3::new(1.0, 1.0, 0.0).safe_div(Vec3::new(2.0, 0.0, 0.0), Vec3::splat(7.0));
+    assert_eq!(q, Vec3::new(0.5, 7.0, 7.0));
+    let s = <Vec3 as VecExt<3>>::select([true, false, true], Vec3::X, Vec3::NEG_Y);
+    assert_eq!(s, Vec3::new(1.0, -1.0, 0.0));
+}
+
+#[test]
+fn ivec3_casts_use_the_named_rounding() {
+    let v = Vec3::new(-1.5, 0.5, 2.5);
+    assert_eq!(v.as_ivec3_floor(), gdmx::IVec3::new(-2, 0, 2));
+    assert_eq!(v.as_ivec3_round(), gdmx::IVec3::new(-2, 0, 2));
+    assert_eq!(v.as_ivec3_trunc(), gdmx::IVec3::new(-1, 0, 2));
+}
+
+#[test]
+fn angle_between_nearly_parallel_is_not_nan() {
+    let a = Vec3::new(0.1, 0.2, 0.3);
+    let b = a * 3.0;
+    assert!(a.angle_between(b).abs() < 1e-3);
+    assert!(!a.sin_angle_between(b).is_nan());
+}
+
+#[test]
+fn component_views_mutate_in_place() {
+    let mut v = Vec3::new(1.0, 2.0, 3.0);
+    v.xy_mut()[1] = 5.0;
+    let (x, z) = v.xz_mut();
+    *x = -1.0;
+    *z *= 2.0;
+    assert_eq!(v, Vec3::new(-1.0, 5.0, 6.0));
+}
+
+#[test]
+fn axis_indexing_matches_components() {
+    use gdmx::Axis;
+    let mut v = Vec3::new(1.0, 2.0, 3.0);
+    v[Axis::Y] = 7.0;
+    assert_eq!(v[Axis::X], 1.0);
+    assert_eq!(v.y, 7.0);
+    assert_eq!(Vec3::axis(Axis::Z), Vec3::Z);
+}
+
+#[test]
+#[cfg_attr(feature = "validate", ignore = "constructs non-finite vectors")]
+fn fallible_constructors_report_errors() {
+    use gdmx::MathError;
+    assert_eq!(Vec3::ZERO.try_normalize(), Err(MathError::ZeroLength));
+    assert_eq!(Vec3::splat(f32::NAN).try_normalize(), Err(MathError::NonFinite));
+    assert!(Vec3::new(3.0, 0.0, 4.0).try_normalize().unwrap().approx_eq(Vec3::new(0.6, 0.0, 0.8), EPS));
+    assert_eq!(Vec3::try_from_slice(&[1.0, 2.0, 3.0]), Ok(Vec3::new(1.0, 2.0, 3.0)));
+    assert_eq!(
+        Vec3::try_from_slice(&[1.0, 2.0]),
+        Err(MathError::SliceLength { expected: 3, found: 2 }),
+    );
+}
+
+#[test]
+fn dir3_is_always_normalized() {
+    use gdmx::Dir3;
+    assert!(Dir3::new(Vec3::ZERO).is_err());
+    let up = Dir3::new(Vec3::new(0.0, 5.0, 0.0)).unwrap();
+    assert_eq!(up, Dir3::Y);
+    let bounced = Vec3::new(1.0, -1.0, 0.0).reflect(up);
+    assert!(bounced.approx_eq(Vec3::new(1.0, 1.0, 0.0), EPS));
+    assert!(Vec3::ZERO.move_along(Dir3::NEG_Z, 2.0).approx_eq(Vec3::new(0.0, 0.0, -2.0), EPS));
+}
+
+#[test]
+#[cfg_attr(feature = "validate", ignore = "constructs non-finite vectors")]
+fn finite_vectors_can_be_hashed_and_sorted() {
+    use gdmx::{Finite, NotNan};
+    use std::collections::HashSet;
+    assert!(Finite::<Vec3>::new(Vec3::new(0.0, f32::INFINITY, 0.0)).is_err());
+    assert!(NotNan::<Vec3>::new(Vec3::new(0.0, f32::INFINITY, 0.0)).is_ok());
+    assert_eq!(NotNan::<Vec3>::new(Vec3::splat(f32::NAN)), Err(gdmx::MathError::NaN));
+    assert_eq!(Finite::<Vec3>::new(Vec3::splat(f32::NAN)), Err(gdmx::MathError::NonFinite));
+
+    let a = Finite::<Vec3>::new(Vec3::new(0.0, 1.0, 2.0)).unwrap();
+    let b = Finite::<Vec3>::new(Vec3::new(-0.0, 1.0, 2.0)).unwrap();
+    let c = Finite::<Vec3>::new(Vec3::new(0.0, 1.0, 3.0)).unwrap();
+    let set: HashSet<_> = [a, b, c].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(a < c);
+}
+
+#[test]
+fn with_setters_replace_one_component() {
+    let v = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.with_y(0.0), Vec3::new(1.0, 0.0, 3.0));
+    assert_eq!(v.with_x(-1.0).with_z(5.0), Vec3::new(-1.0, 2.0, 5.0));
+}
+
+#[test]
+fn combinators_work_componentwise() {
+    let v = Vec3::new(1.0, -2.0, 3.0);
+    assert_eq!(v.map(|c| c * 2.0), Vec3::new(2.0, -4.0, 6.0));
+    assert_eq!(v.zip_with(Vec3::splat(1.0), f32::max), Vec3::new(1.0, 1.0, 3.0));
+    assert_eq!(v.fold(0.0, |acc, c| acc + c.abs()), 6.0);
+}
+
+#[test]
+fn get_does_not_panic_out_of_range() {
+    let mut v = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.get(2), Some(&3.0));
+    assert_eq!(v.get(3), None);
+    *v.get_mut(0).unwrap() = 4.0;
+    assert_eq!(v.x, 4.0);
+    assert!(v.get_mut(7).is_none());
+}
+
+#[test]
+fn iterate_by_reference() {
+    let mut v = Vec3::new(1.0, 2.0, 3.0);
+    for c in &mut v {
+        *c *= 2.0;
+    }
+    assert_eq!(v, Vec3::new(2.0, 4.0, 6.0));
+    assert_eq!((&v).into_iter().copied().fold(0.0, f32::max), 6.0);
+}
+
+#[test]
+#[cfg_attr(feature = "validate", ignore = "constructs non-finite vectors")]
+fn integer_casts_saturate_and_clamp() {
+    use gdmx::{IVec3, UVec3};
+    let v = Vec3::new(-1.5, f32::NAN, 1e12);
+    assert_eq!(v.as_ivec3_saturating(), IVec3::new(-1, 0, i32::MAX));
+    assert_eq!(v.as_uvec3_clamped(), UVec3::new(0, 0, u32::MAX));
+    assert_eq!(IVec3::new(-3, 0, 7).as_vec3(), Vec3::new(-3.0, 0.0, 7.0));
+}
+
+#[test]
+fn const_arithmetic() {
+    const DIAG: Vec3 = Vec3::X.const_add(Vec3::Y).const_sub(Vec3::Z).const_mul(2.0);
+    const TABLE: [Vec3; 2] = [DIAG, DIAG.const_neg()];
+    const D: f32 = DIAG.const_dot(Vec3::splat(1.0));
+    assert_eq!(TABLE[0], Vec3::new(2.0, 2.0, -2.0));
+    assert_eq!(TABLE[1], -DIAG);
+    assert_eq!(DIAG.const_mul_vec(Vec3::new(1.0, 2.0, 3.0)), Vec3::new(2.0, 4.0, -6.0));
+    assert_eq!(D, 2.0);
+}
+
+#[test]
+#[cfg_attr(feature = "validate", ignore = "constructs non-finite vectors")]
+fn finiteness_queries() {
+    assert!(Vec3::new(1.0, -2.0, 3.0).is_finite());
+    assert!(!Vec3::new(1.0, f32::INFINITY, 3.0).is_finite());
+    assert!(Vec3::new(f32::NAN, 0.0, 0.0).is_nan());
+}
+
+#[cfg(feature = "validate")]
+#[test]
+#[should_panic(expected = "non-finite")]
+fn validate_catches_nan_at_construction() {
+    let _ = Vec3::new(0.0, f32::NAN, 0.0);
+}
+
+#[test]
+fn spherical_and_cylindrical_round_trip() {
+    let v = Vec3::new(1.0, -2.0, 3.0);
+    let (r, theta, phi) = v.to_spherical();
+    assert!(Vec3::from_spherical(r, theta, phi).approx_eq(v, 1e-5));
+    let (rho, phi, h) = v.to_cylindrical();
+    assert!(Vec3::from_cylindrical(rho, phi, h).approx_eq(v, 1e-5));
+    assert!(Vec3::from_spherical(2.0, 0.0, 1.0).approx_eq(Vec3::new(0.0, 2.0, 0.0), EPS));
+    assert_eq!(Vec3::ZERO.to_spherical(), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn swizzles_reorder_and_resize() {
+    use gdmx::{Vec2, Vec3A, Vec4};
+    let v = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.xz(), Vec2::new(1.0, 3.0));
+    assert_eq!(v.zyx(), Vec3::new(3.0, 2.0, 1.0));
+    assert_eq!(v.xxyy(), Vec4::new(1.0, 1.0, 2.0, 2.0));
+    assert_eq!(Vec2::new(1.0, 2.0).yxy(), Vec3::new(2.0, 1.0, 2.0));
+    assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0).wzy(), Vec3::new(4.0, 3.0, 2.0));
+    assert_eq!(Vec3A::from(v).yzx(), Vec3A::new(2.0, 3.0, 1.0));
+}
+
+#[test]
+fn extend_and_truncate_move_between_dimensions() {
+    use gdmx::{Vec2, Vec4};
+    let v = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.extend(1.0), Vec4::new(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(v.extend(0.0).truncate(), v);
+    assert_eq!(v.truncate(), Vec2::new(1.0, 2.0));
+    assert_eq!(v.truncate().extend(3.0), v);
+}
+
+#[test]
+fn rotations_match_axis_angle_matrix() {
+    use gdmx::{Dir3, Mat3};
+    let v = Vec3::new(0.5, -1.0, 2.0);
+    let axis = Dir3::new(Vec3::new(1.0, -2.0, 0.5)).unwrap();
+    assert!(v.rotate_around_axis(axis, 0.9).approx_eq(Mat3::from_axis_angle(axis, 0.9) * v, 1e-5));
+    assert!(v.rotate_x(0.3).approx_eq(Mat3::from_axis_angle(Dir3::X, 0.3) * v, 1e-5));
+    assert!(v.rotate_y(0.3).approx_eq(Mat3::from_axis_angle(Dir3::Y, 0.3) * v, 1e-5));
+    assert!(v.rotate_z(0.3).approx_eq(Mat3::from_axis_angle(Dir3::Z, 0.3) * v, 1e-5));
+    assert!(Vec3::X.rotate_y(std::f32::consts::FRAC_PI_2).approx_eq(Vec3::NEG_Z, EPS));
+}
+
+#[test]
+fn is_normalized_checks_unit_length() {
+    assert!(Vec3::new(0.6, 0.0, 0.8).is_normalized(1e-6));
+    assert!(!Vec3::new(0.6, 0.1, 0.8).is_normalized(1e-3));
+    assert!(Vec3::new(1.0, 2.0, 3.0).normalize().is_normalized(1e-6));
+    assert!(!Vec3::ZERO.is_normalized(0.1));
+}
+
+#[test]
+fn horizontal_min_max() {
+    let v = Vec3::new(2.0, -5.0, 7.0);
+    assert_eq!(v.min_element(), -5.0);
+    assert_eq!(v.max_element(), 7.0);
+    assert_eq!(v.min_position(), 1);
+    assert_eq!(v.max_position(), 2);
+    assert_eq!(Vec3::new(0.0, -4.0, 4.0).abs().max_position(), 1);
+    assert_eq!(Vec3::splat(1.0).min_position(), 0);
+}
+
+#[test]
+fn product_multiplies_components() {
+    assert_eq!(Vec3::new(2.0, 3.0, 4.0).product(), 24.0);
+    assert_eq!(gdmx::Vec2::new(-1.5, 2.0).product(), -3.0);
+}
+
+#[test]
+fn componentwise_powers_and_exponentials() {
+    let v = Vec3::new(1.0, 4.0, 9.0);
+    assert_eq!(v.sqrt(), Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(v.powi(2), Vec3::new(1.0, 16.0, 81.0));
+    assert!(v.powf(0.5).approx_eq(v.sqrt(), EPS));
+    assert!(v.ln().exp().approx_eq(v, 1e-5));
+    // gamma correction round trip
+    let linear = Vec3::new(0.2, 0.5, 0.8);
+    assert!(linear.powf(1.0 / 2.2).powf(2.2).approx_eq(linear, 1e-5));
+}
+
+#[test]
+fn componentwise_trig() {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+    let v = Vec3::new(0.0, FRAC_PI_2, FRAC_PI_4);
+    let (s, c) = v.sin_cos();
+    assert!(s.approx_eq(v.sin(), EPS));
+    assert!(c.approx_eq(v.cos(), EPS));
+    assert!(s.approx_eq(Vec3::new(0.0, 1.0, std::f32::consts::FRAC_1_SQRT_2), EPS));
+    assert!((v.tan().z - 1.0).abs() < EPS);
+}
+
+#[test]
+fn angle_unit_conversion() {
+    let euler = Vec3::new(90.0, -45.0, 180.0);
+    let rad = euler.to_radians();
+    assert!(rad.approx_eq(Vec3::new(std::f32::consts::FRAC_PI_2, -std::f32::consts::FRAC_PI_4, std::f32::consts::PI), EPS));
+    assert!(rad.to_degrees().approx_eq(euler, 1e-4));
+}
+
+#[test]
+fn rotate_towards_is_capped() {
+    let step = Vec3::X.rotate_towards(Vec3::Z, 0.1);
+    assert!((step.angle_between(Vec3::X) - 0.1).abs() < 1e-5);
+    assert!((step.length() - 1.0).abs() < 1e-5);
+    assert!(step.y.abs() < EPS);
+    assert_eq!(Vec3::X.rotate_towards(Vec3::Z, 2.0), Vec3::Z);
+    let flipped = Vec3::X.rotate_towards(Vec3::NEG_X, 0.5);
+    assert!((flipped.angle_between(Vec3::X) - 0.5).abs() < 1e-5);
+}
+
+#[test]
+fn smooth_damp_reaches_target_and_stops() {
+    let target = Vec3::new(4.0, -2.0, 1.0);
+    let (mut p, mut v) = (Vec3::ZERO, Vec3::ZERO);
+    for _ in 0..120 {
+        p = p.smooth_damp(target, &mut v, 0.2, 1.0 / 60.0);
+        assert!(p.distance(Vec3::ZERO) <= target.length() + EPS);
+    }
+    assert!(p.distance(target) < 1e-3);
+    assert!(v.length() < 1e-2);
+}
+
+#[test]
+fn slerp_keeps_unit_length_and_handles_opposites() {
+    let mid = Vec3::X.slerp(Vec3::Y, 0.5);
+    assert!(mid.approx_eq(Vec3::new(1.0, 1.0, 0.0).normalize(), 1e-5));
+    let third = Vec3::X.slerp(Vec3::Y, 1.0 / 3.0);
+    assert!((third.angle_between(Vec3::X) - std::f32::consts::FRAC_PI_6).abs() < 1e-5);
+
+    let half_turn = Vec3::X.slerp(-Vec3::X, 0.5);
+    assert!((half_turn.length() - 1.0).abs() < 1e-5);
+    assert!(half_turn.dot(Vec3::X).abs() < 1e-5);
+    assert!((Vec3::X.nlerp(-Vec3::X, 0.5).length() - 1.0).abs() < 1e-5);
+
+    let n = Vec3::X.nlerp(Vec3::Z, 0.25);
+    assert!((n.length() - 1.0).abs() < 1e-5);
+    assert!(Vec3::Y.slerp(Vec3::Y, 0.7).approx_eq(Vec3::Y, EPS));
+    // short parallel vectors break the precondition, but must not send slerp and nlerp into each other forever
+    let short = Vec3::new(1e-5, 0.0, 0.0);
+    assert!(short.slerp(short, 0.5).is_finite());
+    assert!(short.nlerp(short, 0.5).is_finite());
+    assert!(gdmx::Vec3A::from(short).slerp(gdmx::Vec3A::from(short), 0.5).is_finite());
+}
+
+#[test]
+fn orthonormal_basis_is_right_handed() {
+    for n in [Vec3::X, -Vec3::Z, Vec3::new(0.3, -0.5, 0.8).normalize(), Vec3::new(-0.6, 0.0, -0.8)] {
+        let (a, b) = n.orthonormal_basis();
+        assert!((a.length() - 1.0).abs() < 1e-5 && (b.length() - 1.0).abs() < 1e-5);
+        assert!(a.dot(n).abs() < 1e-5 && b.dot(n).abs() < 1e-5 && a.dot(b).abs() < 1e-5);
+        assert!(a.cross(b).approx_eq(n, 1e-5));
+        assert!(n.any_orthonormal().dot(n).abs() < 1e-5);
+        assert!((n * 3.0).any_orthogonal().dot(n).abs() < 1e-5);
+        assert!((n * 3.0).any_orthogonal().length() > 0.0);
+    }
+}
+
+#[test]
+fn signed_angle_between_follows_the_axis() {
+    use gdmx::Dir3;
+    use std::f32::consts::{FRAC_PI_2, PI};
+    assert!((Vec3::X.signed_angle_between(Vec3::Y, Dir3::Z) - FRAC_PI_2).abs() < 1e-6);
+    assert!((Vec3::X.signed_angle_between(Vec3::Y, -Dir3::Z) + FRAC_PI_2).abs() < 1e-6);
+    assert!((Vec3::X.signed_angle_between(Vec3::new(1.0, -1.0, 0.0), Dir3::Z) + PI / 4.0).abs() < 1e-6);
+    assert_eq!(Vec3::X.signed_angle_between(-Vec3::X, Dir3::Z), PI);
+}
+
+#[test]
+fn iterator_sum_product_and_collect() {
+    let points = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(-1.0, 0.5, 2.0), Vec3::splat(2.0)];
+    assert_eq!(points.iter().copied().sum::<Vec3>(), Vec3::new(2.0, 4.5, 7.0));
+    assert_eq!(points.iter().sum::<Vec3>(), Vec3::new(2.0, 4.5, 7.0));
+    assert_eq!(points.iter().product::<Vec3>(), Vec3::new(-2.0, 2.0, 12.0));
+    assert_eq!(std::iter::empty::<Vec3>().sum::<Vec3>(), Vec3::ZERO);
+    assert_eq!((1..=4).map(|i| i as f32).collect::<Vec3>(), Vec3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+#[should_panic(expected = "too few items")]
+fn collect_panics_when_short() {
+    let _: Vec3 = [1.0, 2.0].into_iter().collect();
+}
+
+#[test]
+fn splat_constants_seed_bounds() {
+    let points = [Vec3::new(1.0, -2.0, 3.0), Vec3::new(-4.0, 5.0, 0.5)];
+    let lo = points.iter().fold(Vec3::INFINITY, |acc, p| acc.min_vec(*p));
+    let hi = points.iter().fold(Vec3::NEG_INFINITY, |acc, p| acc.max_vec(*p));
+    assert_eq!(lo, Vec3::new(-4.0, -2.0, 0.5));
+    assert_eq!(hi, Vec3::new(1.0, 5.0, 3.0));
+    assert_eq!(Vec3::ONE + Vec3::NEG_ONE, Vec3::ZERO);
+    assert!(Vec3::MIN.is_finite() && Vec3::MAX.is_finite());
+    assert!(Vec3::NAN.is_nan());
+    assert_eq!(Vec3::AXES.iter().sum::<Vec3>(), Vec3::ONE);
+}
+
+#[test]
+fn shader_style_step_and_smoothstep() {
+    let v = Vec3::new(-1.0, 0.5, 2.0);
+    assert_eq!(v.step(Vec3::splat(0.5)), Vec3::new(0.0, 1.0, 1.0));
+    assert_eq!(v.smoothstep(Vec3::ZERO, Vec3::ONE), Vec3::new(0.0, 0.5, 1.0));
+    assert_eq!(v.saturate(), Vec3::new(0.0, 0.5, 1.0));
+}
+
+#[test]
+fn inverse_lerp_and_remap_per_component() {
+    let a = Vec3::new(0.0, 10.0, -1.0);
+    let b = Vec3::new(2.0, 20.0, 1.0);
+    let t = Vec3::new(0.25, 0.5, 1.0);
+    assert!(a.lerp(b, 0.5).inverse_lerp(a, b).approx_eq(Vec3::splat(0.5), EPS));
+    assert!(a.lerp(b, 0.75).remap(a, b, Vec3::ZERO, Vec3::splat(4.0)).approx_eq(Vec3::splat(3.0), EPS));
+    assert!(Vec3::new(0.5, 15.0, 1.0).inverse_lerp(a, b).approx_eq(t, EPS));
+}
+
+#[test]
+fn lerp_variants_hit_the_endpoints() {
+    let a = Vec3::new(0.1, -7.3, 1e6);
+    let b = Vec3::new(0.3, 2.9, 1e-3);
+    assert_eq!(a.lerp_exact(b, 0.0), a);
+    assert_eq!(a.lerp_exact(b, 1.0), b);
+    assert!(a.lerp_exact(b, 0.25).approx_eq(a.lerp(b, 0.25), 1e-1));
+    assert_eq!(a.lerp_clamped(b, 1.5), a.lerp(b, 1.0));
+    assert_eq!(a.lerp_clamped(b, -2.0), a);
+}
+
+#[test]
+fn mul_add_rounds_once() {
+    let v = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.mul_add(Vec3::splat(2.0), Vec3::ONE), Vec3::new(3.0, 5.0, 7.0));
+    // (1 + e)(1 - e) - 1 is -e^2 exactly with a fused multiply-add, but 0 when rounded in between
+    let e = f32::EPSILON;
+    let fused = Vec3::splat(1.0 + e).mul_add(Vec3::splat(1.0 - e), Vec3::NEG_ONE);
+    assert_eq!(fused, Vec3::splat(-e * e));
+}
+
+#[test]
+fn plane_projection_and_slide() {
+    use gdmx::Dir3;
+    let v = Vec3::new(3.0, -2.0, 1.0);
+    assert_eq!(v.project_onto_plane(Dir3::Y), Vec3::new(3.0, 0.0, 1.0));
+    assert_eq!(v.slide(Dir3::Y), Vec3::new(3.0, 0.0, 1.0));
+    // moving away from the floor is left alone
+    assert_eq!((-v).slide(Dir3::Y), -v);
+    let n = Dir3::new(Vec3::new(1.0, 1.0, 0.0)).unwrap();
+    assert!(v.project_onto_plane(n).dot(*n).abs() < EPS);
+}
+
+#[test]
+fn rotate_around_a_pivot() {
+    use gdmx::Dir3;
+    let pivot = Vec3::new(1.0, 0.0, 5.0);
+    let p = Vec3::new(2.0, 0.0, 5.0);
+    let r = p.rotate_around(pivot, Dir3::Z, std::f32::consts::FRAC_PI_2);
+    assert!(r.approx_eq(Vec3::new(1.0, 1.0, 5.0), 1e-6));
+    assert!(pivot.rotate_around(pivot, Dir3::X, 1.0).approx_eq(pivot, EPS));
+}
+
+#[test]
+fn centroid_and_weighted_average() {
+    use gdmx::MathError;
+    let far = Vec3::splat(1e6);
+    let points = [far + Vec3::X, far - Vec3::X, far + Vec3::Y, far - Vec3::Y];
+    assert_eq!(Vec3::centroid(&points), Some(far));
+    assert_eq!(Vec3::centroid(&[]), None);
+    let w = Vec3::weighted_average(&[Vec3::ZERO, Vec3::splat(4.0)], &[3.0, 1.0]).unwrap();
+    assert!(w.approx_eq(Vec3::ONE, EPS));
+    assert_eq!(Vec3::weighted_average(&[Vec3::X], &[0.0]), Err(MathError::Degenerate));
+    // the partial sum of weights passes through 0 after the second point
+    let (a, b, c) = (Vec3::X, Vec3::Y, Vec3::new(0.0, 0.0, 2.0));
+    let w = Vec3::weighted_average(&[a, b, c], &[1.0, -1.0, 1.0]).unwrap();
+    assert!(w.approx_eq(a - b + c, EPS));
+    // the small points vanish next to 1e8 in a plain f32 sum, and the large ones cancel
+    let mut points = vec![Vec3::splat(1e8)];
+    points.extend(std::iter::repeat_n(Vec3::ONE, 10_000));
+    points.push(Vec3::splat(-1e8));
+    let w = Vec3::weighted_average(&points, &vec![1.0; points.len()]).unwrap();
+    assert!(w.approx_eq(Vec3::splat(10_000.0 / 10_002.0), 1e-4));
+    assert!(matches!(Vec3::weighted_average(&[Vec3::X], &[]), Err(MathError::SliceLength { .. })));
+}
+
+#[test]
+fn triple_products() {
+    assert_eq!(Vec3::X.scalar_triple(Vec3::Y, Vec3::Z), 1.0);
+    assert_eq!(Vec3::Y.scalar_triple(Vec3::X, Vec3::Z), -1.0);
+    assert_eq!(Vec3::X.scalar_triple(Vec3::Y, Vec3::new(1.0, 1.0, 0.0)), 0.0);
+    let (a, b, c) = (Vec3::new(1.0, 2.0, -1.0), Vec3::new(0.5, -3.0, 2.0), Vec3::new(4.0, 0.0, 1.0));
+    assert!(a.vector_triple(b, c).approx_eq(a.cross(b.cross(c)), 1e-5));
+}
+
+#[test]
+fn axis_access_and_dominant_axis() {
+    use gdmx::Axis;
+    let mut v = Vec3::new(0.5, -3.0, 2.0);
+    assert_eq!(v.dominant_axis(), Axis::Y);
+    assert_eq!(v.get_axis(Axis::Z), 2.0);
+    v.set_axis(Axis::X, 7.0);
+    assert_eq!(v, Vec3::new(7.0, -3.0, 2.0));
+    assert_eq!(v.dominant_axis(), Axis::X);
+    assert_eq!(Vec3::ONE.dominant_axis(), Axis::X);
+}
+
+#[test]
+fn normalize_handles_a_subnormal_length_2() {
+    let v = Vec3::new(1e-19, 0.0, 0.0);
+    assert!(v.length_2() < f32::MIN_POSITIVE);
+    assert!(v.normalize().approx_eq(Vec3::X, EPS));
+}
+
+#[test]
+fn try_normalize_rescales_extreme_lengths() {
+    use gdmx::Dir3;
+    // length_2 overflows to inf for the first and underflows to 0 for the second
+    for v in [Vec3::new(1e20, -1e20, 0.0), Vec3::new(0.0, 3e-30, 4e-30), Vec3::new(1e-19, 0.0, 0.0)] {
+        let n = v.try_normalize().unwrap();
+        assert!(n.is_finite());
+        assert!((n.length() - 1.0).abs() <= EPS * 2.0);
+        assert!(Dir3::new(v).is_ok());
+    }
+    assert!(Vec3::new(0.0, 3e-30, 4e-30).try_normalize().unwrap().approx_eq(Vec3::new(0.0, 0.6, 0.8), EPS));
+}
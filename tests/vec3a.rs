@@ -0,0 +1,35 @@
+use gdmx::{
+    Dir3,
+    Vec3,
+    Vec3A,
+    VecExt,
+};
+use std::mem::{align_of, size_of};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn layout_is_16_byte_aligned() {
+    assert_eq!(size_of::<Vec3A>(), 16);
+    assert_eq!(align_of::<Vec3A>(), 16);
+    assert_eq!(size_of::<[Vec3A; 4]>(), 64);
+}
+
+#[test]
+fn converts_losslessly_to_and_from_vec3() {
+    let v = Vec3::new(1.0, -2.0, 3.5);
+    assert_eq!(Vec3::from(Vec3A::from(v)), v);
+    assert_eq!(Vec3A::from_vec3(v).to_vec3(), v);
+}
+
+#[test]
+fn matches_vec3_api() {
+    let a = Vec3A::new(1.0, 2.0, 3.0);
+    let b = Vec3A::new(-2.0, 0.5, 1.0);
+    let (va, vb) = (a.to_vec3(), b.to_vec3());
+    assert!((a.cross(b)).to_vec3().approx_eq(va.cross(vb), EPS));
+    assert!((a.dot(b) - va.dot(vb)).abs() < EPS);
+    assert!(a.normalize().to_vec3().approx_eq(va.normalize(), EPS));
+    assert!(a.reflect(Dir3::Y).to_vec3().approx_eq(va.reflect(Dir3::Y), EPS));
+    assert_eq!((a + b * 2.0).to_array(), (va + vb * 2.0).to_array());
+}
@@ -0,0 +1,33 @@
+use gdmx::{
+    Vec3,
+    Vector,
+    VecExt,
+};
+
+const EPS: f32 = 1e-6;
+
+#[test]
+fn longer_vectors_get_the_full_api() {
+    let a = Vector::<f32, 6>::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let b = Vector::<f32, 6>::splat(1.0);
+    assert_eq!((a + b)[5], 7.0);
+    assert_eq!(a.dot(b), 21.0);
+    assert!((a.normalize().length() - 1.0).abs() < EPS);
+    assert_eq!(Vector::<f32, 5>::unit(3).to_array(), [0.0, 0.0, 0.0, 1.0, 0.0]);
+    assert_eq!(Vector::<f64, 5>::default(), Vector::ZERO);
+}
+
+#[test]
+fn converts_to_named_vectors() {
+    let v = Vec3::new(1.0, -2.0, 3.0);
+    let g: Vector<f32, 3> = v.into();
+    assert_eq!(g.0, [1.0, -2.0, 3.0]);
+    assert_eq!(Vec3::from(g * 2.0), v * 2.0);
+}
+
+#[test]
+fn vectors_sum_and_collect() {
+    let v: Vector<f32, 5> = (0..5).map(|i| i as f32).collect();
+    let total: Vector<f32, 5> = [v, v, v].into_iter().sum();
+    assert_eq!(total, v * 3.0);
+}
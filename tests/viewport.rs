@@ -0,0 +1,54 @@
+#![cfg(feature = "camera")]
+
+use gdmx::{
+    ClipConvention,
+    Mat4,
+    Vec2,
+    Vec3,
+    Vec4,
+    VecExt,
+    Viewport,
+};
+
+const EPS: f32 = 1e-4;
+
+/// Left-handed perspective projection with [0, 1] depth
+fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Mat4 {
+    let f = 1.0 / (fov_y * 0.5).tan();
+    let r = far / (far - near);
+    Mat4::from_cols(
+        Vec4::new(f / aspect, 0.0, 0.0, 0.0),
+        Vec4::new(0.0, f, 0.0, 0.0),
+        Vec4::new(0.0, 0.0, r, 1.0),
+        Vec4::new(0.0, 0.0, -near * r, 0.0),
+    )
+}
+
+#[test]
+fn ndc_round_trips_for_every_convention() {
+    for convention in [ClipConvention::OpenGl, ClipConvention::Vulkan, ClipConvention::DirectX] {
+        let mut vp = Viewport::new(Vec2::new(800.0, 600.0), convention);
+        vp.origin = Vec2::new(10.0, 20.0);
+        let ndc = Vec3::new(0.25, 0.5, 0.75);
+        assert!(vp.screen_to_ndc(vp.ndc_to_screen(ndc)).approx_eq(ndc, 1e-6));
+    }
+    let gl = Viewport::new(Vec2::new(800.0, 600.0), ClipConvention::OpenGl);
+    assert_eq!(gl.ndc_to_screen(Vec3::new(-1.0, 1.0, -1.0)), Vec3::ZERO);
+    let vk = Viewport::new(Vec2::new(800.0, 600.0), ClipConvention::Vulkan);
+    assert_eq!(vk.ndc_to_screen(Vec3::new(-1.0, -1.0, 0.0)), Vec3::ZERO);
+}
+
+#[test]
+fn screen_ray_passes_through_the_projected_point() {
+    let vp = Viewport::new(Vec2::new(640.0, 480.0), ClipConvention::DirectX);
+    let view = Mat4::look_at_lh(Vec3::new(0.0, 2.0, -5.0), Vec3::ZERO, Vec3::Y);
+    let view_proj = perspective(1.0, vp.aspect_ratio(), 0.1, 100.0) * view;
+    assert!((view_proj * view_proj.inverse()).approx_eq(Mat4::IDENTITY, 1e-5));
+
+    let p = Vec3::new(1.0, 0.5, 2.0);
+    let screen = vp.world_to_screen(view_proj, p).unwrap();
+    let (origin, dir) = vp.screen_to_ray(view_proj.inverse(), Vec2::new(screen.x, screen.y)).unwrap();
+    let to_p = p - origin;
+    assert!((to_p - *dir * to_p.dot(*dir)).length() < EPS);
+    assert!(vp.world_to_screen(view_proj, Vec3::new(0.0, 2.0, -10.0)).is_none());
+}